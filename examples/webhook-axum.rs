@@ -12,23 +12,42 @@
 //! stripe trigger checkout.session.completed
 //! ```
 
-use axum::{
-    async_trait,
-    body::Body,
-    extract::FromRequest,
-    http::{Request, StatusCode},
-    response::{IntoResponse, Response},
-    routing::post,
-    Error, Router,
-};
-use stripe::{Event, EventObject, EventType};
+use std::sync::Arc;
 
-pub type Result<T, E = Error> = std::result::Result<T, E>;
+use axum::{extract::FromRef, extract::State, http::StatusCode, routing::post, Router};
+use stripe::{axum::StripeEvent, EventObject, EventRouter, EventType, WebhookVerifier};
+
+#[derive(Clone, FromRef)]
+struct AppState {
+    verifier: WebhookVerifier,
+    events: Arc<EventRouter>,
+}
 
 #[tokio::main]
 async fn main() {
-    // build our application with a route
-    let app = Router::new().route("/stripe_webhooks", post(handle_webhook));
+    let events = EventRouter::new()
+        .on(EventType::CheckoutSessionCompleted, |event| async move {
+            if let EventObject::CheckoutSession(session) = event.data.object {
+                println!("Received checkout session completed webhook with id: {:?}", session.id);
+            }
+            Ok(())
+        })
+        .on(EventType::AccountUpdated, |event| async move {
+            if let EventObject::Account(account) = event.data.object {
+                println!("Received account updated webhook for account: {:?}", account.id);
+            }
+            Ok(())
+        })
+        .fallback(|event| async move {
+            println!("Unknown event encountered in webhook: {:?}", event.type_);
+            Ok(())
+        });
+
+    // build our application with a route, the secret used to verify webhooks,
+    // and the handlers for each type of event
+    let state =
+        AppState { verifier: WebhookVerifier::new("whsec_xxxxx"), events: Arc::new(events) };
+    let app = Router::new().route("/stripe_webhooks", post(handle_webhook)).with_state(state);
 
     let listener = tokio::net::TcpListener::bind("127.0.0.1:4242").await.unwrap();
 
@@ -37,46 +56,20 @@ async fn main() {
     axum::serve(listener, app).await.unwrap();
 }
 
-struct StripeEvent(Event);
-
-#[async_trait]
-impl<S> FromRequest<S> for StripeEvent
-where
-    String: FromRequest<S>,
-    S: Send + Sync,
-{
-    type Rejection = Response;
-
-    async fn from_request(req: Request<Body>, state: &S) -> Result<Self, Self::Rejection> {
-        let signature = if let Some(sig) = req.headers().get("stripe-signature") {
-            sig.to_owned()
-        } else {
-            return Err(StatusCode::BAD_REQUEST.into_response());
-        };
-
-        let payload =
-            String::from_request(req, state).await.map_err(IntoResponse::into_response)?;
-
-        Ok(Self(
-            stripe::Webhook::construct_event(&payload, signature.to_str().unwrap(), "whsec_xxxxx")
-                .map_err(|_| StatusCode::BAD_REQUEST.into_response())?,
-        ))
+#[axum::debug_handler(state = AppState)]
+async fn handle_webhook(
+    State(events): State<Arc<EventRouter>>,
+    StripeEvent(event): StripeEvent,
+) -> StatusCode {
+    let report = events.dispatch(event).await;
+    for err in report.errors() {
+        println!("Failed to handle {}: {}", report.event_id, err);
     }
-}
 
-#[axum::debug_handler]
-async fn handle_webhook(StripeEvent(event): StripeEvent) {
-    match event.type_ {
-        EventType::CheckoutSessionCompleted => {
-            if let EventObject::CheckoutSession(session) = event.data.object {
-                println!("Received checkout session completed webhook with id: {:?}", session.id);
-            }
-        }
-        EventType::AccountUpdated => {
-            if let EventObject::Account(account) = event.data.object {
-                println!("Received account updated webhook for account: {:?}", account.id);
-            }
-        }
-        _ => println!("Unknown event encountered in webhook: {:?}", event.type_),
+    // ask stripe to retry events that failed
+    if report.is_ok() {
+        StatusCode::OK
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
     }
 }
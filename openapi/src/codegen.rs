@@ -1386,7 +1386,7 @@ pub fn gen_impl_requests(
 
                 let query_path = segments.join("/");
                 writedoc!(&mut out, r#"
-                    pub fn list(client: &Client, params: &{params_name}<'_>) -> Response<List<{rust_struct}>> {{
+                    pub fn list<C: StripeClient>(client: &C, params: &{params_name}<'_>) -> C::Response<List<{rust_struct}>> {{
                        client.get_query("/{query_path}", &params)
                     }}
                 "#).unwrap();
@@ -1404,19 +1404,19 @@ pub fn gen_impl_requests(
                     let mut out = String::new();
                     out.push('\n');
                     print_doc_comment(&mut out, doc_comment, 1);
-                    out.push_str("    pub fn retrieve(client: &Client, id: &");
+                    out.push_str("    pub fn retrieve<C: StripeClient>(client: &C, id: &");
                     out.push_str(id_type);
                     if let Some(param) = expand_param {
                         state.use_params.insert("Expand");
                         assert!(matches!(param, Parameter::Query { .. }));
-                        out.push_str(", expand: &[&str]) -> Response<");
+                        out.push_str(", expand: &[&str]) -> C::Response<");
                         out.push_str(&rust_struct);
                         out.push_str("> {\n");
                         out.push_str("        client.get_query(");
                         out.push_str(&format!("&format!(\"/{}/{{}}\", id)", segments[0]));
                         out.push_str(", &Expand { expand })\n");
                     } else {
-                        out.push_str(") -> Response<");
+                        out.push_str(") -> C::Response<");
                         out.push_str(&rust_struct);
                         out.push_str("> {\n");
                         out.push_str("        client.get(/");
@@ -1468,9 +1468,9 @@ pub fn gen_impl_requests(
                 let mut out = String::new();
                 out.push('\n');
                 print_doc_comment(&mut out, doc_comment, 1);
-                out.push_str("    pub fn create(client: &Client, params: ");
+                out.push_str("    pub fn create<C: StripeClient>(client: &C, params: ");
                 out.push_str(&params_name);
-                out.push_str("<'_>) -> Response<");
+                out.push_str("<'_>) -> C::Response<");
                 out.push_str(&return_type);
                 out.push_str("> {\n");
                 out.push_str("        client.post_form(\"/");
@@ -1506,11 +1506,11 @@ pub fn gen_impl_requests(
                     let mut out = String::new();
                     out.push('\n');
                     print_doc_comment(&mut out, doc_comment, 1);
-                    out.push_str("    pub fn update(client: &Client, id: &");
+                    out.push_str("    pub fn update<C: StripeClient>(client: &C, id: &");
                     out.push_str(id_type);
                     out.push_str(", params: ");
                     out.push_str(&params_name);
-                    out.push_str("<'_>) -> Response<");
+                    out.push_str("<'_>) -> C::Response<");
                     out.push_str(&return_type);
                     out.push_str("> {\n");
                     out.push_str("        client.post_form(");
@@ -1556,9 +1556,9 @@ pub fn gen_impl_requests(
                     let mut out = String::new();
                     out.push('\n');
                     print_doc_comment(&mut out, doc_comment, 1);
-                    out.push_str("    pub fn delete(client: &Client, id: &");
+                    out.push_str("    pub fn delete<C: StripeClient>(client: &C, id: &");
                     out.push_str(id_type);
-                    out.push_str(") -> Response<Deleted<");
+                    out.push_str(") -> C::Response<Deleted<");
                     out.push_str(id_type);
                     out.push_str(">> {\n");
                     out.push_str("        client.delete(");
@@ -1577,8 +1577,7 @@ pub fn gen_impl_requests(
         None
     } else {
         // Add imports
-        state.use_config.insert("Client");
-        state.use_config.insert("Response");
+        state.use_config.insert("StripeClient");
 
         // Output the impl block
         Some(format!(
@@ -56,6 +56,8 @@ fn main() -> Result<()> {
 
     meta.write_placeholders(&out_path);
     meta.write_version(&out_path);
+    meta.write_event_object(&out_path);
+    meta.write_typed_event(&out_path);
 
     // write files and get those files referenced
     let shared_objects = meta
@@ -38,6 +38,17 @@ pub struct Metadata<'a> {
     pub requests: BTreeMap<String, BTreeSet<&'a str>>,
 }
 
+/// A schema for an event, such as `charge.succeeded`.
+#[derive(Debug)]
+pub struct EventSchema<'a> {
+    /// The type of the event.
+    pub event_type: &'a str,
+    /// The schemas of the objects which the event may contain.
+    pub objects: Vec<&'a str>,
+    /// Whether the event includes the previous values of any updated attributes.
+    pub previous_attributes: bool,
+}
+
 impl<'a> Metadata<'a> {
     pub fn from_spec(spec: &'a Spec) -> Self {
         let id_renames = mappings::id_renames();
@@ -140,6 +151,196 @@ impl<'a> Metadata<'a> {
         write(&out_path.as_ref().join("placeholders.rs"), out.as_bytes()).unwrap();
     }
 
+    /// The events in the spec, which are the schemas with an `x-stripeEvent` extension.
+    pub fn events(&self) -> Vec<EventSchema<'a>> {
+        let mut events = Vec::new();
+        for ref_or_schema in self.spec.component_schemas().values() {
+            let (schema, event_type) = match ref_or_schema.as_item().and_then(|schema| {
+                let event = schema.schema_data.extensions.get("x-stripeEvent")?;
+                Some((schema, event.get("type")?.as_str()?))
+            }) {
+                Some(event) => event,
+                None => continue,
+            };
+            let properties = match as_object_properties(schema) {
+                Some(properties) => properties,
+                None => continue,
+            };
+            let objects = match properties.get("object") {
+                Some(ReferenceOr::Reference { reference }) => {
+                    vec![reference.trim_start_matches("#/components/schemas/")]
+                }
+                // events such as `customer.source.created` may contain one of several objects
+                Some(ReferenceOr::Item(schema)) => match &schema.schema_kind {
+                    SchemaKind::AnyOf { any_of } => any_of
+                        .iter()
+                        .filter_map(|ty| match ty {
+                            ReferenceOr::Reference { reference } => {
+                                Some(reference.trim_start_matches("#/components/schemas/"))
+                            }
+                            ReferenceOr::Item(_) => None,
+                        })
+                        .collect(),
+                    _ => continue,
+                },
+                None => continue,
+            };
+            events.push(EventSchema {
+                event_type,
+                objects,
+                previous_attributes: properties.contains_key("previous_attributes"),
+            });
+        }
+        events.sort_by_key(|event| event.event_type);
+        events
+    }
+
+    /// generate the `EventObject` enum, with a variant for every object that an event may contain
+    pub fn write_event_object<T>(&self, out_path: T)
+    where
+        T: AsRef<Path>,
+    {
+        let event_objects: BTreeSet<_> =
+            self.events().into_iter().flat_map(|event| event.objects).collect();
+
+        let mut out = String::from(
+            r#"use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::resources::*;
+
+/// The object that an [`Event`](crate::Event) is about.
+///
+/// Objects of a type which this crate does not model, such as those added in a newer api version,
+/// are kept as [`EventObject::Unknown`], so that the event may still be routed or stored.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self", tag = "object", rename_all = "snake_case")]
+pub enum EventObject {
+"#,
+        );
+        for schema in &event_objects {
+            let struct_type = self.schema_to_rust_type(schema);
+            if struct_type.to_snake_case() != *schema {
+                out.push_str(&format!("\t#[serde(rename = \"{}\")]\n", schema));
+            }
+            out.push_str(&format!("\t{}({}),\n", struct_type, struct_type));
+        }
+        out.push_str(
+            "\t/// The raw json of an object of a type which this crate does not model.\n",
+        );
+        out.push_str("\t#[serde(skip)]\n");
+        out.push_str("\tUnknown(Value),\n");
+        out.push_str("}\n\n");
+
+        out.push_str("impl EventObject {\n");
+        out.push_str("\t/// The `object` of every type with a variant, other than [`EventObject::Unknown`].\n");
+        out.push_str("\tpub(crate) const OBJECT_TYPES: &'static [&'static str] = &[\n");
+        for schema in &event_objects {
+            out.push_str(&format!("\t\t\"{}\",\n", schema));
+        }
+        out.push_str("\t];\n");
+        out.push_str("}\n");
+
+        write(&out_path.as_ref().join("event_object.rs"), out.as_bytes()).unwrap();
+    }
+
+    /// generate the `TypedEvent` enum, with a variant for every event holding its object
+    pub fn write_typed_event<T>(&self, out_path: T)
+    where
+        T: AsRef<Path>,
+    {
+        let events = self.events();
+        // N.B. This matches the names of the `EventType` variants, such as `InvoiceItemCreated`
+        let variant = |event_type: &str| {
+            event_type.split('.').map(|seg| self.schema_to_rust_type(seg)).collect::<String>()
+        };
+
+        let mut out = String::from(
+            r#"use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::resources::*;
+
+/// An [`Event`], with the object of the type that its [`EventType`] is about.
+///
+/// Created with [`Event::into_typed`]. Events of a type which this crate does not know of, or whose
+/// object does not match the type of the event, are kept as [`TypedEvent::Unknown`].
+#[derive(Clone, Debug)]
+pub enum TypedEvent {
+"#,
+        );
+        for event in &events {
+            let object = match event.objects.as_slice() {
+                [object] => self.schema_to_rust_type(object),
+                _ => "EventObject".to_string(),
+            };
+            out.push_str(&format!("\t/// `{}`\n", event.event_type));
+            if event.previous_attributes {
+                out.push_str(&format!(
+                    "\t{} {{ object: {}, previous_attributes: Option<HashMap<String, Value>> }},\n",
+                    variant(event.event_type),
+                    object
+                ));
+            } else {
+                out.push_str(&format!("\t{}({}),\n", variant(event.event_type), object));
+            }
+        }
+        out.push_str("\t/// An event of an unknown type, or with an unexpected object.\n");
+        out.push_str("\tUnknown(Event),\n");
+        out.push_str("}\n\n");
+
+        out.push_str("impl TypedEvent {\n");
+        out.push_str("\t/// The type of the event.\n");
+        out.push_str("\tpub fn event_type(&self) -> EventType {\n");
+        out.push_str("\t\tmatch self {\n");
+        for event in &events {
+            let variant = variant(event.event_type);
+            out.push_str(&format!(
+                "\t\t\tTypedEvent::{} {{ .. }} => EventType::{},\n",
+                variant, variant
+            ));
+        }
+        out.push_str("\t\t\tTypedEvent::Unknown(event) => event.type_,\n");
+        out.push_str("\t\t}\n\t}\n}\n\n");
+
+        out.push_str("impl Event {\n");
+        out.push_str(
+            "\t/// Convert the event into a [`TypedEvent`], so that its object may be matched on \
+             together with its type.\n",
+        );
+        out.push_str("\tpub fn into_typed(self) -> TypedEvent {\n");
+        out.push_str("\t\tlet previous_attributes = self.data.previous_attributes;\n");
+        out.push_str("\t\tmatch (self.type_, self.data.object) {\n");
+        for event in &events {
+            let variant = variant(event.event_type);
+            let pattern = match event.objects.as_slice() {
+                [object] => format!("EventObject::{}(object)", self.schema_to_rust_type(object)),
+                objects => format!(
+                    "object @ ({})",
+                    objects
+                        .iter()
+                        .map(|o| format!("EventObject::{}(_)", self.schema_to_rust_type(o)))
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                ),
+            };
+            let value = if event.previous_attributes {
+                format!("TypedEvent::{} {{ object, previous_attributes }}", variant)
+            } else {
+                format!("TypedEvent::{}(object)", variant)
+            };
+            out.push_str(&format!("\t\t\t(EventType::{}, {}) => {},\n", variant, pattern, value));
+        }
+        out.push_str(
+            "\t\t\t(_, object) => TypedEvent::Unknown(Event { \
+             data: NotificationEventData { object, previous_attributes }, ..self }),\n",
+        );
+        out.push_str("\t\t}\n\t}\n}\n");
+
+        write(&out_path.as_ref().join("typed_event.rs"), out.as_bytes()).unwrap();
+    }
+
     pub fn write_version<T>(&self, out_path: T)
     where
         T: AsRef<Path>,
@@ -264,14 +465,20 @@ pub fn feature_groups() -> BTreeMap<&'static str, &'static str> {
 
 		// Checkout
 		("checkout_session", "checkout"),
+		("payment_link", "checkout"),
 
 		// Billing (aka. Subscriptions)
+		("billing_portal.configuration", "billing"),
+		("billing_portal.session", "billing"),
 		("coupon", "billing"),
+		("credit_note", "billing"),
 		("discount", "billing"),
 		("invoice", "billing"),
 		("invoiceitem", "billing"),
         ("line_item", "billing"),
 		("plan", "billing"),
+		("promotion_code", "billing"),
+		("quote", "billing"),
 		("subscription", "billing"),
 		("subscription_item", "billing"),
 		("subscription_schedule", "billing"),
@@ -283,6 +490,7 @@ pub fn feature_groups() -> BTreeMap<&'static str, &'static str> {
 		("account", "connect"),
 		("application", "connect"),
 		("application_fee", "connect"),
+		("capability", "connect"),
 		("connect_collection_transfer", "connect"),
 		("fee_refund", "connect"),
 		("person", "connect"),
@@ -291,6 +499,7 @@ pub fn feature_groups() -> BTreeMap<&'static str, &'static str> {
 		("transfer_reversal", "connect"),
 
 		// Fraud
+		("radar.early_fraud_warning", "fraud"),
 		("review", "fraud"),
 
 		// Issuing
@@ -298,6 +507,7 @@ pub fn feature_groups() -> BTreeMap<&'static str, &'static str> {
 		("issuing.card", "issuing"),
 		("issuing.cardholder", "issuing"),
 		("issuing.dispute", "issuing"),
+		("issuing.token", "issuing"),
 		("issuing.transaction", "issuing"),
 
 		// Orders
@@ -309,6 +519,36 @@ pub fn feature_groups() -> BTreeMap<&'static str, &'static str> {
 		// Sigma
 		("scheduled_query_run", "sigma"),
 
+		// Terminal
+		("terminal.reader", "terminal"),
+
+		// Tax
+		("tax.settings", "tax-calculation"),
+
+		// Climate
+		("climate.order", "climate"),
+		("climate.product", "climate"),
+
+		// Financial Connections
+		("financial_connections.account", "financial-connections"),
+
+		// Identity
+		("identity.verification_session", "identity"),
+
+		// Reporting
+		("reporting.report_run", "reporting"),
+		("reporting.report_type", "reporting"),
+
+		// Treasury
+		("treasury.credit_reversal", "treasury"),
+		("treasury.debit_reversal", "treasury"),
+		("treasury.financial_account", "treasury"),
+		("treasury.inbound_transfer", "treasury"),
+		("treasury.outbound_payment", "treasury"),
+		("treasury.outbound_transfer", "treasury"),
+		("treasury.received_credit", "treasury"),
+		("treasury.received_debit", "treasury"),
+
 		// Webhooks Endpoints
 		("webhook_endpoint", "webhook-endpoints"),
 	]
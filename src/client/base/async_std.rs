@@ -1,132 +1,151 @@
 use std::future::{self, Future};
 use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
 
-use async_std::task::sleep;
-use http_types::{Request, StatusCode};
+use http_types::Request;
 use serde::de::DeserializeOwned;
 
-use crate::client::request_strategy::{Outcome, RequestStrategy};
-use crate::error::{ErrorResponse, StripeError};
+use super::{retry, BaseClient};
+use crate::client::request_options::RequestConfig;
+use crate::client::transport::{HttpTransport, TransportFuture};
+use crate::error::StripeError;
 
 pub type Response<T> = Pin<Box<dyn Future<Output = Result<T, StripeError>> + Send>>;
 
-#[allow(dead_code)]
-#[inline(always)]
-pub(crate) fn ok<T: Send + 'static>(ok: T) -> Response<T> {
-    Box::pin(future::ready(Ok(ok)))
-}
-
-#[allow(dead_code)]
-#[inline(always)]
-pub(crate) fn err<T: Send + 'static>(err: StripeError) -> Response<T> {
-    Box::pin(future::ready(Err(err)))
-}
-
 #[derive(Clone)]
 pub struct AsyncStdClient {
-    client: surf::Client,
+    transport: Arc<dyn HttpTransport>,
+    custom_transport: bool,
+    connect_timeout: Option<Duration>,
 }
 
-impl AsyncStdClient {
+impl BaseClient for AsyncStdClient {
+    type Response<T: Send + 'static> = Response<T>;
+
     /// Creates a new client pointed to `https://api.stripe.com/`
-    pub fn new() -> Self {
-        Self { client: surf::Client::new() }
+    fn new() -> Self {
+        Self {
+            transport: Arc::new(SurfTransport {
+                client: surf::Client::new(),
+                connect_timeout: None,
+            }),
+            custom_transport: false,
+            connect_timeout: None,
+        }
+    }
+
+    /// Give up on establishing a connection after the given duration.
+    ///
+    /// N.B. surf does not expose connecting separately from the rest of the request,
+    ///      so this limits the time until the response headers are received.
+    ///
+    /// # Panics
+    ///
+    /// If a custom transport has been set, as it cannot be reconfigured.
+    fn with_connect_timeout(self, timeout: Duration) -> Self {
+        assert!(!self.custom_transport, "{}", super::CONNECT_TIMEOUT_WITH_TRANSPORT);
+        Self {
+            transport: Arc::new(SurfTransport {
+                client: surf::Client::new(),
+                connect_timeout: Some(timeout),
+            }),
+            connect_timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Send requests through the given transport, rather than the default surf client.
+    ///
+    /// # Panics
+    ///
+    /// If a connect timeout has been set, as it would not apply to the transport.
+    fn with_transport(self, transport: impl HttpTransport + 'static) -> Self {
+        assert!(self.connect_timeout.is_none(), "{}", super::CONNECT_TIMEOUT_WITH_TRANSPORT);
+        Self { transport: Arc::new(transport), custom_transport: true, ..self }
     }
 
-    pub fn execute<T: DeserializeOwned + Send + 'static>(
+    fn execute<T: DeserializeOwned + Send + 'static>(
         &self,
         request: Request,
-        strategy: &RequestStrategy,
+        config: RequestConfig,
     ) -> Response<T> {
-        // need to clone here since client could be used across threads.
-        // N.B. Client is send sync; cloned clients share the same pool.
-        let client = self.client.clone();
-        let strategy = strategy.clone();
-
-        Box::pin(async move {
-            let bytes = send_inner(&client, request, &strategy).await?;
-            let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-            serde_path_to_error::deserialize(json_deserializer).map_err(StripeError::from)
-        })
+        // N.B. The transport is shared, so cloned clients share the same pool.
+        Box::pin(retry::execute(self.transport.clone(), request, config, async_std::task::sleep))
     }
-}
 
-async fn send_inner(
-    client: &surf::Client,
-    mut request: Request,
-    strategy: &RequestStrategy,
-) -> Result<Vec<u8>, StripeError> {
-    let mut tries = 0;
-    let mut last_status: Option<StatusCode> = None;
-    let mut last_retry_header: Option<bool> = None;
+    fn execute_bytes(&self, request: Request, config: RequestConfig) -> Response<Vec<u8>> {
+        Box::pin(retry::execute_bytes(
+            self.transport.clone(),
+            request,
+            config,
+            async_std::task::sleep,
+        ))
+    }
 
-    // if we have no last error, then the strategy is invalid
-    let mut last_error = StripeError::ClientError("Invalid strategy".to_string());
+    fn ok<T: Send + 'static>(value: T) -> Response<T> {
+        Box::pin(future::ready(Ok(value)))
+    }
 
-    if let Some(key) = strategy.get_key() {
-        request.insert_header("Idempotency-Key", key);
+    fn err<T: Send + 'static>(error: StripeError) -> Response<T> {
+        Box::pin(future::ready(Err(error)))
     }
 
-    let body = request.body_bytes().await?;
+    fn map<T: Send + 'static, U: Send + 'static>(
+        response: Response<T>,
+        f: impl FnOnce(T) -> U + Send + 'static,
+    ) -> Response<U> {
+        Box::pin(async move { response.await.map(f) })
+    }
+}
 
-    loop {
-        return match strategy.test(last_status, last_retry_header, tries) {
-            Outcome::Stop => Err(last_error),
-            Outcome::Continue(duration) => {
-                if let Some(duration) = duration {
-                    sleep(duration).await;
-                }
+/// The default transport, which bounds the time taken to receive a response.
+struct SurfTransport {
+    client: surf::Client,
+    connect_timeout: Option<Duration>,
+}
 
-                // we need to clone the request before sending it so we can
-                // re-use it if we need to retry. ditto for the body
-                let mut request = request.clone();
-                request.set_body(body.clone());
-
-                let mut response = match client.send(request).await {
-                    Ok(response) => response,
-                    Err(err) => {
-                        last_error = StripeError::from(err);
-                        tries += 1;
-                        continue;
-                    }
-                };
-
-                let status = response.status();
-                let retry = response
-                    .header("Stripe-Should-Retry")
-                    .and_then(|s| s.last().as_str().parse().ok());
-
-                // if this fails parsing, we can probably just exit
-                let bytes = response.body_bytes().await?;
-
-                if !status.is_success() {
-                    tries += 1;
-                    let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-                    last_error = serde_path_to_error::deserialize(json_deserializer)
-                        .map(|mut e: ErrorResponse| {
-                            e.error.http_status = status.into();
-                            StripeError::from(e.error)
-                        })
-                        .unwrap_or_else(StripeError::from);
-                    last_status = Some(status);
-                    last_retry_header = retry;
-
-                    continue;
-                }
+impl HttpTransport for SurfTransport {
+    fn send(&self, request: Request) -> TransportFuture {
+        let client = self.client.clone();
+        let connect_timeout = self.connect_timeout;
+        Box::pin(async move {
+            let response = match connect_timeout {
+                Some(duration) => async_std::future::timeout(duration, client.send(request))
+                    .await
+                    .map_err(|_| StripeError::Timeout)??,
+                None => client.send(request).await?,
+            };
+            Ok(response.into())
+        })
+    }
+}
 
-                Ok(bytes)
-            }
-        };
+/// Any surf client may be used as a transport, to share its configuration and connection pool.
+impl HttpTransport for surf::Client {
+    fn send(&self, request: Request) -> TransportFuture {
+        let client = self.clone();
+        Box::pin(async move { Ok(client.send(request).await?.into()) })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
     use http_types::{Request, Url};
     use httpmock::prelude::*;
 
     use super::AsyncStdClient;
-    use crate::{client::request_strategy::RequestStrategy, StripeError};
+    use crate::client::base::BaseClient;
+    use crate::client::request_options::RequestConfig;
+    use crate::client::request_strategy::{RequestStrategy, RetryPolicy};
+    use crate::StripeError;
+
+    fn config(strategy: impl RetryPolicy + 'static) -> RequestConfig {
+        RequestConfig { strategy: Arc::new(strategy), ..Default::default() }
+    }
 
     #[async_std::test]
     async fn retry() {
@@ -142,7 +161,7 @@ mod tests {
         });
 
         let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res = client.execute::<()>(req, config(RequestStrategy::Retry(5))).await;
 
         hello_mock.assert_hits_async(5).await;
         assert!(res.is_err());
@@ -167,7 +186,7 @@ mod tests {
         });
 
         let req = Request::get(Url::parse(&server.url("/v1/missing")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(3)).await;
+        let res = client.execute::<()>(req, config(RequestStrategy::Retry(3))).await;
 
         mock.assert_hits_async(1).await;
 
@@ -191,12 +210,40 @@ mod tests {
         });
 
         let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res = client.execute::<()>(req, config(RequestStrategy::Retry(5))).await;
 
         hello_mock.assert_hits_async(1).await;
         assert!(res.is_err());
     }
 
+    #[async_std::test]
+    async fn attempt_timeout() {
+        let client = AsyncStdClient::new();
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        // Create a mock on the server.
+        let hello_mock = server.mock(|when, then| {
+            when.method(GET).path("/slow");
+            then.status(200).body("null").delay(Duration::from_millis(500));
+        });
+
+        let req = Request::get(Url::parse(&server.url("/slow")).unwrap());
+        let res = client
+            .execute::<()>(
+                req,
+                RequestConfig {
+                    timeout: Some(Duration::from_millis(50)),
+                    ..config(RequestStrategy::Retry(2))
+                },
+            )
+            .await;
+
+        hello_mock.assert_hits_async(2).await;
+        assert!(matches!(res, Err(StripeError::Timeout)));
+    }
+
     #[async_std::test]
     async fn retry_body() {
         let client = AsyncStdClient::new();
@@ -212,7 +259,7 @@ mod tests {
 
         let mut req = Request::post(Url::parse(&server.url("/server-errors")).unwrap());
         req.set_body("body");
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res = client.execute::<()>(req, config(RequestStrategy::Retry(5))).await;
 
         hello_mock.assert_hits_async(5).await;
         assert!(res.is_err());
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use http_types::Request;
+use serde::de::DeserializeOwned;
+
+use crate::client::request_options::RequestConfig;
+use crate::client::transport::HttpTransport;
+use crate::error::StripeError;
+
+pub(crate) mod retry;
+
+#[cfg(any(
+    feature = "runtime-tokio-hyper",
+    feature = "runtime-tokio-hyper-rustls",
+    feature = "runtime-tokio-hyper-rustls-webpki",
+    feature = "runtime-blocking",
+    feature = "runtime-blocking-rustls",
+    feature = "runtime-blocking-rustls-webpki",
+))]
+pub mod tokio;
+
+#[cfg(feature = "runtime-async-std-surf")]
+pub mod async_std;
+
+#[cfg(any(
+    feature = "runtime-blocking",
+    feature = "runtime-blocking-rustls",
+    feature = "runtime-blocking-rustls-webpki"
+))]
+pub mod tokio_blocking;
+
+/// The message of the panic raised when a connect timeout and a custom transport are combined.
+const CONNECT_TIMEOUT_WITH_TRANSPORT: &str = "a connect timeout cannot be applied to a custom \
+    transport; configure it on the transport instead, such as with `ClientBuilder::with_connect_timeout`";
+
+/// The http client for a runtime, which a [`GenericClient`](crate::GenericClient) sends its requests through.
+///
+/// This is implemented for each of the enabled `runtime-*` features.
+pub trait BaseClient: Clone + Send + Sync + 'static {
+    /// The type returned by a request; either a future or the result itself.
+    type Response<T: Send + 'static>;
+
+    /// Create the default http client for the runtime.
+    fn new() -> Self;
+
+    /// Give up on establishing a connection after the given duration.
+    ///
+    /// # Panics
+    ///
+    /// If a custom transport has been set with [`BaseClient::with_transport`].
+    fn with_connect_timeout(self, timeout: Duration) -> Self;
+
+    /// Send requests through the given transport, rather than the default http client.
+    ///
+    /// # Panics
+    ///
+    /// If a connect timeout has been set with [`BaseClient::with_connect_timeout`].
+    fn with_transport(self, transport: impl HttpTransport + 'static) -> Self;
+
+    /// Send a request, retrying it according to the config, and deserialize the response.
+    fn execute<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: Request,
+        config: RequestConfig,
+    ) -> Self::Response<T>;
+
+    /// Send a request, retrying it according to the config, and return the raw response body.
+    fn execute_bytes(&self, request: Request, config: RequestConfig) -> Self::Response<Vec<u8>>;
+
+    /// Wrap a value in a response which has already completed.
+    fn ok<T: Send + 'static>(value: T) -> Self::Response<T>;
+
+    /// Wrap an error in a response which has already failed.
+    fn err<T: Send + 'static>(error: StripeError) -> Self::Response<T>;
+
+    /// Transform the value of a successful response.
+    fn map<T: Send + 'static, U: Send + 'static>(
+        response: Self::Response<T>,
+        f: impl FnOnce(T) -> U + Send + 'static,
+    ) -> Self::Response<U>;
+}
@@ -0,0 +1,249 @@
+//! The retry loop shared by every runtime, which sends requests through an [`HttpTransport`].
+
+use std::future::{poll_fn, Future};
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::Poll;
+use std::time::{Duration, Instant};
+
+use http_types::{headers::Headers, Request, StatusCode};
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "tracing")]
+use crate::client::instrumentation;
+use crate::client::middleware::{notify, Attempt};
+use crate::client::request_options::RequestConfig;
+use crate::client::request_strategy::{Outcome, RetryContext};
+use crate::client::response_metadata::ResponseMetadata;
+use crate::client::transport::HttpTransport;
+use crate::error::{ErrorResponse, StripeError};
+
+/// Send a request, retrying it according to the config, and deserialize the response.
+///
+/// `sleep` is provided by the runtime, and is used for backoff, timeouts and the deadline.
+pub(crate) fn execute<T, S, F>(
+    transport: Arc<dyn HttpTransport>,
+    request: Request,
+    config: RequestConfig,
+    sleep: S,
+) -> impl Future<Output = Result<T, StripeError>> + Send + 'static
+where
+    T: DeserializeOwned + Send + 'static,
+    S: Fn(Duration) -> F + Send + Sync + 'static,
+    F: Future<Output = ()> + Send,
+{
+    let bytes = execute_bytes(transport, request, config, sleep);
+    async move {
+        let bytes = bytes.await?;
+        let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
+        serde_path_to_error::deserialize(json_deserializer).map_err(StripeError::from)
+    }
+}
+
+/// Send a request, retrying it according to the config, and return the raw response body.
+///
+/// This is used for the endpoints which respond with files, rather than json.
+pub(crate) fn execute_bytes<S, F>(
+    transport: Arc<dyn HttpTransport>,
+    mut request: Request,
+    config: RequestConfig,
+    sleep: S,
+) -> impl Future<Output = Result<Vec<u8>, StripeError>> + Send + 'static
+where
+    S: Fn(Duration) -> F + Send + Sync + 'static,
+    F: Future<Output = ()> + Send,
+{
+    // a key set on the request itself takes precedence over the strategy
+    if request.header("Idempotency-Key").is_none() {
+        if let Some(key) = config.strategy.idempotency_key() {
+            request.insert_header("Idempotency-Key", key);
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    let span = instrumentation::request_span(&request);
+
+    let future = async move { send_inner(transport.as_ref(), request, &config, sleep).await };
+
+    #[cfg(feature = "tracing")]
+    let future = tracing::Instrument::instrument(future, span);
+
+    future
+}
+
+async fn send_inner<S, F>(
+    transport: &dyn HttpTransport,
+    request: Request,
+    config: &RequestConfig,
+    sleep: S,
+) -> Result<Vec<u8>, StripeError>
+where
+    S: Fn(Duration) -> F,
+    F: Future<Output = ()>,
+{
+    let attempts = send_attempts(transport, request, config, &sleep);
+    match config.deadline {
+        Some(deadline) => {
+            timeout(attempts, sleep(deadline)).await.unwrap_or(Err(StripeError::Timeout))
+        }
+        None => attempts.await,
+    }
+}
+
+/// Make attempts at the request until one succeeds, or the strategy gives up.
+async fn send_attempts<S, F>(
+    transport: &dyn HttpTransport,
+    mut request: Request,
+    config: &RequestConfig,
+    sleep: &S,
+) -> Result<Vec<u8>, StripeError>
+where
+    S: Fn(Duration) -> F,
+    F: Future<Output = ()>,
+{
+    let start = Instant::now();
+    let mut tries = 0;
+    let mut last_status: Option<StatusCode> = None;
+    let mut last_retry_header: Option<bool> = None;
+    let mut last_headers: Option<Headers> = None;
+
+    // if we have no last error, then the strategy is invalid
+    let mut last_error = StripeError::ClientError("Invalid strategy".to_string());
+
+    let body = request.body_bytes().await?;
+
+    loop {
+        let context = RetryContext {
+            method: request.method(),
+            path: request.url().path(),
+            retry_count: tries,
+            status: last_status,
+            stripe_should_retry: last_retry_header,
+            headers: last_headers.as_ref(),
+            error: if tries > 0 { Some(&last_error) } else { None },
+            elapsed: start.elapsed(),
+        };
+
+        return match config.strategy.decide(&context) {
+            Outcome::Stop => Err(last_error),
+            Outcome::Continue(duration) => {
+                if let Some(duration) = duration {
+                    sleep(duration).await;
+                }
+
+                let method = request.method();
+                let path = request.url().path();
+
+                // we need to clone the request before sending it so we can
+                // re-use it if we need to retry. ditto for the body
+                let mut request = request.clone();
+                request.set_body(body.clone());
+
+                #[cfg(feature = "tracing")]
+                let span = instrumentation::attempt_span(tries + 1, duration);
+
+                let attempt_start = Instant::now();
+                let attempt = send_once(transport, request);
+                #[cfg(feature = "tracing")]
+                let attempt = tracing::Instrument::instrument(attempt, span.clone());
+                let result = match config.timeout {
+                    Some(duration) => {
+                        timeout(attempt, sleep(duration)).await.unwrap_or(Err(StripeError::Timeout))
+                    }
+                    None => attempt.await,
+                };
+                let mut attempt = Attempt {
+                    method,
+                    path,
+                    attempt: tries + 1,
+                    latency: attempt_start.elapsed(),
+                    status: None,
+                    error: None,
+                    response: None,
+                };
+
+                let (response, bytes) = match result {
+                    Ok(response) => response,
+                    Err(err) => {
+                        let attempt = Attempt { error: Some(&err), ..attempt };
+                        #[cfg(feature = "tracing")]
+                        instrumentation::record(&span, &attempt);
+                        notify(&config.middleware, &attempt);
+                        // there was no response, so forget that of the previous attempt
+                        last_status = None;
+                        last_retry_header = None;
+                        last_headers = None;
+                        last_error = err;
+                        tries += 1;
+                        continue;
+                    }
+                };
+
+                let status = response.status();
+                let metadata = ResponseMetadata::new(status, |name| {
+                    response.header(name).map(|s| s.last().as_str())
+                });
+                let retry = response
+                    .header("Stripe-Should-Retry")
+                    .and_then(|s| s.last().as_str().parse().ok());
+                let headers = Some(AsRef::<Headers>::as_ref(&response).clone());
+
+                if !status.is_success() {
+                    tries += 1;
+                    let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
+                    last_error = serde_path_to_error::deserialize(json_deserializer)
+                        .map(|mut e: ErrorResponse| {
+                            e.error.http_status = status.into();
+                            e.error.request_id = metadata.request_id.clone();
+                            StripeError::from(e.error)
+                        })
+                        .unwrap_or_else(StripeError::from);
+                    attempt.status = Some(status);
+                    attempt.error = Some(&last_error);
+                    attempt.response = Some(&metadata);
+                    #[cfg(feature = "tracing")]
+                    instrumentation::record(&span, &attempt);
+                    notify(&config.middleware, &attempt);
+
+                    last_status = Some(status);
+                    last_retry_header = retry;
+                    last_headers = headers;
+                    config.last_response.set(metadata);
+                    continue;
+                }
+
+                attempt.status = Some(status);
+                attempt.response = Some(&metadata);
+                #[cfg(feature = "tracing")]
+                instrumentation::record(&span, &attempt);
+                notify(&config.middleware, &attempt);
+
+                config.last_response.set(metadata);
+                Ok(bytes)
+            }
+        };
+    }
+}
+
+/// Make a single attempt at a request, reading the entire response body.
+async fn send_once(
+    transport: &dyn HttpTransport,
+    request: Request,
+) -> Result<(http_types::Response, Vec<u8>), StripeError> {
+    let mut response = transport.send(request).await?;
+    let bytes = response.body_bytes().await?;
+    Ok((response, bytes))
+}
+
+/// Run the future to completion, unless the timer finishes first.
+async fn timeout<T>(future: impl Future<Output = T>, timer: impl Future<Output = ()>) -> Option<T> {
+    let mut future = pin!(future);
+    let mut timer = pin!(timer);
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+        timer.as_mut().poll(cx).map(|_| None)
+    })
+    .await
+}
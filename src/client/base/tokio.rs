@@ -1,178 +1,260 @@
 use std::future::{self, Future};
 use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
 
 use http_types::{Request, StatusCode};
+use hyper::client::connect::Connect;
 use hyper::http;
 use hyper::{client::HttpConnector, Body};
 use serde::de::DeserializeOwned;
-use tokio::time::sleep;
 
-use crate::client::request_strategy::{Outcome, RequestStrategy};
-use crate::error::{ErrorResponse, StripeError};
+use self::builder::ProxyConnector;
+use super::{retry, BaseClient};
+use crate::client::request_options::RequestConfig;
+use crate::client::transport::{HttpTransport, TransportFuture};
+use crate::error::StripeError;
+
+mod builder;
+pub use self::builder::ClientBuilder;
 
 #[cfg(feature = "hyper-rustls-native")]
 mod connector {
-    use hyper::client::{connect::dns::GaiResolver, HttpConnector};
     pub use hyper_rustls::HttpsConnector;
     use hyper_rustls::HttpsConnectorBuilder;
 
-    pub fn create() -> HttpsConnector<HttpConnector<GaiResolver>> {
+    use crate::error::StripeError;
+
+    pub fn create<C>(http: C) -> HttpsConnector<C> {
         HttpsConnectorBuilder::new()
             .with_native_roots()
             .https_or_http()
             .enable_http1()
             .enable_http2()
-            .build()
+            .wrap_connector(http)
+    }
+
+    /// Create a connector trusting the given PEM encoded certificates, as well as the native roots.
+    pub fn create_with_roots<C>(
+        http: C,
+        pems: &[Vec<u8>],
+    ) -> Result<HttpsConnector<C>, StripeError> {
+        let mut roots = rustls::RootCertStore::empty();
+        let native = rustls_native_certs::load_native_certs().map_err(|e| {
+            StripeError::ClientError(format!("unable to load native root certificates: {}", e))
+        })?;
+        for cert in native {
+            // N.B. Some platforms ship certificates rustls cannot parse, so skip them like hyper-rustls.
+            let _ = roots.add(&rustls::Certificate(cert.0));
+        }
+        super::add_pems(&mut roots, pems)?;
+        Ok(HttpsConnectorBuilder::new()
+            .with_tls_config(super::tls_config(roots))
+            .https_or_http()
+            .enable_http1()
+            .enable_http2()
+            .wrap_connector(http))
     }
 }
 
 #[cfg(feature = "hyper-rustls-webpki")]
 mod connector {
-    use hyper::client::{connect::dns::GaiResolver, HttpConnector};
     pub use hyper_rustls::HttpsConnector;
     use hyper_rustls::HttpsConnectorBuilder;
 
-    pub fn create() -> HttpsConnector<HttpConnector<GaiResolver>> {
+    use crate::error::StripeError;
+
+    pub fn create<C>(http: C) -> HttpsConnector<C> {
         HttpsConnectorBuilder::new()
             .with_webpki_roots()
             .https_or_http()
             .enable_http1()
             .enable_http2()
-            .build()
+            .wrap_connector(http)
     }
+
+    /// Create a connector trusting the given PEM encoded certificates, as well as the webpki roots.
+    pub fn create_with_roots<C>(
+        http: C,
+        pems: &[Vec<u8>],
+    ) -> Result<HttpsConnector<C>, StripeError> {
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|ta| {
+            rustls::OwnedTrustAnchor::from_subject_spki_name_constraints(
+                ta.subject,
+                ta.spki,
+                ta.name_constraints,
+            )
+        }));
+        super::add_pems(&mut roots, pems)?;
+        Ok(HttpsConnectorBuilder::new()
+            .with_tls_config(super::tls_config(roots))
+            .https_or_http()
+            .enable_http1()
+            .enable_http2()
+            .wrap_connector(http))
+    }
+}
+
+#[cfg(feature = "hyper-rustls")]
+fn add_pems(roots: &mut rustls::RootCertStore, pems: &[Vec<u8>]) -> Result<(), StripeError> {
+    let invalid = |e: &dyn std::fmt::Display| {
+        StripeError::ClientError(format!("invalid root certificate: {}", e))
+    };
+    for pem in pems {
+        for der in rustls_pemfile::certs(&mut pem.as_slice()).map_err(|e| invalid(&e))? {
+            roots.add(&rustls::Certificate(der)).map_err(|e| invalid(&e))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "hyper-rustls")]
+fn tls_config(roots: rustls::RootCertStore) -> rustls::ClientConfig {
+    rustls::ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth()
 }
 
 #[cfg(feature = "hyper-tls")]
 mod connector {
-    use hyper::client::{connect::dns::GaiResolver, HttpConnector};
     pub use hyper_tls::HttpsConnector;
 
-    pub fn create() -> HttpsConnector<HttpConnector<GaiResolver>> {
-        HttpsConnector::new()
+    use crate::error::StripeError;
+
+    pub fn create<C>(http: C) -> HttpsConnector<C> {
+        HttpsConnector::new_with_connector(http)
+    }
+
+    /// Create a connector trusting the given PEM encoded certificates, as well as the system roots.
+    pub fn create_with_roots<C>(
+        http: C,
+        pems: &[Vec<u8>],
+    ) -> Result<HttpsConnector<C>, StripeError> {
+        let invalid = |e: native_tls::Error| {
+            StripeError::ClientError(format!("invalid root certificate: {}", e))
+        };
+        let mut tls = native_tls::TlsConnector::builder();
+        for pem in pems {
+            tls.add_root_certificate(native_tls::Certificate::from_pem(pem).map_err(invalid)?);
+        }
+        Ok(HttpsConnector::from((http, tls.build().map_err(invalid)?.into())))
     }
 }
 
 #[cfg(all(feature = "hyper-tls", feature = "hyper-rustls"))]
 compile_error!("You must enable only one TLS implementation");
 
-type HttpClient = hyper::Client<connector::HttpsConnector<HttpConnector>, Body>;
+type HttpClient = hyper::Client<connector::HttpsConnector<ProxyConnector>, Body>;
 
 pub type Response<T> = Pin<Box<dyn Future<Output = Result<T, StripeError>> + Send>>;
 
-#[allow(dead_code)]
-#[inline(always)]
-pub(crate) fn ok<T: Send + 'static>(ok: T) -> Response<T> {
-    Box::pin(future::ready(Ok(ok)))
-}
-
-#[allow(dead_code)]
-#[inline(always)]
-pub(crate) fn err<T: Send + 'static>(err: StripeError) -> Response<T> {
-    Box::pin(future::ready(Err(err)))
-}
-
 #[derive(Clone)]
 pub struct TokioClient {
-    client: HttpClient,
+    transport: Arc<dyn HttpTransport>,
+    custom_transport: bool,
+    connect_timeout: Option<Duration>,
 }
 
-impl TokioClient {
-    pub fn new() -> Self {
+impl BaseClient for TokioClient {
+    type Response<T: Send + 'static> = Response<T>;
+
+    fn new() -> Self {
         Self {
-            client: hyper::Client::builder().pool_max_idle_per_host(0).build(connector::create()),
+            transport: Arc::new(build_client(None)),
+            custom_transport: false,
+            connect_timeout: None,
         }
     }
 
-    pub fn execute<T: DeserializeOwned + Send + 'static>(
+    /// Give up on establishing a connection after the given duration.
+    ///
+    /// # Panics
+    ///
+    /// If a custom transport has been set, as it cannot be reconfigured.
+    fn with_connect_timeout(self, timeout: Duration) -> Self {
+        assert!(!self.custom_transport, "{}", super::CONNECT_TIMEOUT_WITH_TRANSPORT);
+        Self {
+            transport: Arc::new(build_client(Some(timeout))),
+            connect_timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Send requests through the given transport, rather than the default hyper client.
+    ///
+    /// # Panics
+    ///
+    /// If a connect timeout has been set, as it would not apply to the transport.
+    fn with_transport(self, transport: impl HttpTransport + 'static) -> Self {
+        assert!(self.connect_timeout.is_none(), "{}", super::CONNECT_TIMEOUT_WITH_TRANSPORT);
+        Self { transport: Arc::new(transport), custom_transport: true, ..self }
+    }
+
+    fn execute<T: DeserializeOwned + Send + 'static>(
         &self,
         request: Request,
-        strategy: &RequestStrategy,
+        config: RequestConfig,
     ) -> Response<T> {
-        // need to clone here since client could be used across threads.
-        // N.B. Client is send sync; cloned clients share the same pool.
-        let client = self.client.clone();
-        let strategy = strategy.clone();
-
-        Box::pin(async move {
-            let bytes = send_inner(&client, request, &strategy).await?;
-            let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-            serde_path_to_error::deserialize(json_deserializer).map_err(StripeError::from)
-        })
+        // N.B. The transport is shared, so cloned clients share the same pool.
+        Box::pin(retry::execute(self.transport.clone(), request, config, tokio::time::sleep))
     }
-}
-
-async fn send_inner(
-    client: &HttpClient,
-    mut request: Request,
-    strategy: &RequestStrategy,
-) -> Result<hyper::body::Bytes, StripeError> {
-    let mut tries = 0;
-    let mut last_status: Option<StatusCode> = None;
-    let mut last_retry_header: Option<bool> = None;
 
-    // if we have no last error, then the strategy is invalid
-    let mut last_error = StripeError::ClientError("Invalid strategy".to_string());
+    fn execute_bytes(&self, request: Request, config: RequestConfig) -> Response<Vec<u8>> {
+        Box::pin(retry::execute_bytes(self.transport.clone(), request, config, tokio::time::sleep))
+    }
 
-    if let Some(key) = strategy.get_key() {
-        request.insert_header("Idempotency-Key", key);
+    fn ok<T: Send + 'static>(value: T) -> Response<T> {
+        Box::pin(future::ready(Ok(value)))
     }
 
-    let body = request.body_bytes().await?;
+    fn err<T: Send + 'static>(error: StripeError) -> Response<T> {
+        Box::pin(future::ready(Err(error)))
+    }
 
-    loop {
-        return match strategy.test(last_status, last_retry_header, tries) {
-            Outcome::Stop => Err(last_error),
-            Outcome::Continue(duration) => {
-                if let Some(duration) = duration {
-                    sleep(duration).await;
-                }
+    fn map<T: Send + 'static, U: Send + 'static>(
+        response: Response<T>,
+        f: impl FnOnce(T) -> U + Send + 'static,
+    ) -> Response<U> {
+        Box::pin(async move { response.await.map(f) })
+    }
+}
 
-                // note: http::Request provides no easy way to clone, so we perform
-                //       the conversion from the clonable http_types::Request each time
-                //       obviously cloning before the first request is not ideal
-                let mut request = request.clone();
-                request.set_body(body.clone());
-
-                let response = match client.request(convert_request(request).await).await {
-                    Ok(response) => response,
-                    Err(err) => {
-                        last_error = StripeError::from(err);
-                        tries += 1;
-                        continue;
-                    }
-                };
+fn build_client(connect_timeout: Option<Duration>) -> HttpClient {
+    let mut http = HttpConnector::new();
+    // the https connector decides whether to use tls, so allow any scheme here
+    http.enforce_http(false);
+    http.set_connect_timeout(connect_timeout);
+    let proxy = ProxyConnector { http, proxy: None };
+    hyper::Client::builder().pool_max_idle_per_host(0).build(connector::create(proxy))
+}
 
-                let status = response.status();
-                let retry = response
-                    .headers()
-                    .get("Stripe-Should-Retry")
-                    .and_then(|s| s.to_str().ok())
-                    .and_then(|s| s.parse().ok());
-
-                let bytes = hyper::body::to_bytes(response.into_body()).await?;
-
-                if !status.is_success() {
-                    tries += 1;
-                    let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-                    last_error = serde_path_to_error::deserialize(json_deserializer)
-                        .map(|mut e: ErrorResponse| {
-                            e.error.http_status = status.into();
-                            StripeError::from(e.error)
-                        })
-                        .unwrap_or_else(StripeError::from);
-                    last_status = Some(
-                        // NOTE: StatusCode::from can panic here, so fall back to InternalServerError
-                        //       see https://github.com/http-rs/http-types/blob/ac5d645ce5294554b86ebd49233d3ec01665d1d7/src/hyperium_http.rs#L20-L24
-                        StatusCode::try_from(u16::from(status))
-                            .unwrap_or(StatusCode::InternalServerError),
-                    );
-                    last_retry_header = retry;
-                    continue;
+/// Any hyper client may be used as a transport, to share its configuration and connection pool.
+impl<C> HttpTransport for hyper::Client<C, Body>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    fn send(&self, request: Request) -> TransportFuture {
+        let client = self.clone();
+        Box::pin(async move {
+            let response = client.request(convert_request(request).await).await?;
+            let (parts, body) = response.into_parts();
+            let bytes = hyper::body::to_bytes(body).await?;
+
+            // NOTE: StatusCode::from can panic here, so fall back to InternalServerError
+            //       see https://github.com/http-rs/http-types/blob/ac5d645ce5294554b86ebd49233d3ec01665d1d7/src/hyperium_http.rs#L20-L24
+            let status = StatusCode::try_from(u16::from(parts.status))
+                .unwrap_or(StatusCode::InternalServerError);
+            let mut response = http_types::Response::new(status);
+            for (name, value) in parts.headers.iter() {
+                if let Ok(value) = value.to_str() {
+                    response.append_header(name.as_str(), value);
                 }
-
-                Ok(bytes)
             }
-        };
+            response.set_body(bytes.to_vec());
+            Ok(response)
+        })
     }
 }
 
@@ -188,17 +270,31 @@ async fn convert_request(mut request: http_types::Request) -> http::Request<hype
 
 #[cfg(test)]
 mod tests {
-    use http_types::{Method, Request, Url};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use http_types::{Method, Request, StatusCode, Url};
     use httpmock::prelude::*;
     use hyper::{body::to_bytes, Body, Request as HyperRequest};
 
     use super::convert_request;
     use super::TokioClient;
-    use crate::client::request_strategy::RequestStrategy;
+    use crate::client::base::BaseClient;
+    use crate::client::middleware::{Attempt, Middleware};
+    use crate::client::request_options::RequestConfig;
+    use crate::client::request_strategy::{
+        Backoff, Outcome, RequestStrategy, RetryContext, RetryPolicy,
+    };
+    use crate::client::response_metadata::LastResponse;
+    use crate::client::transport::{HttpTransport, TransportFuture};
     use crate::StripeError;
 
     const TEST_URL: &str = "https://api.stripe.com/v1/";
 
+    fn config(strategy: impl RetryPolicy + 'static) -> RequestConfig {
+        RequestConfig { strategy: Arc::new(strategy), ..Default::default() }
+    }
+
     #[tokio::test]
     async fn basic_conversion() {
         req_equal(
@@ -262,7 +358,7 @@ mod tests {
         });
 
         let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res = client.execute::<()>(req, config(RequestStrategy::Retry(5))).await;
 
         hello_mock.assert_hits_async(5).await;
         assert!(res.is_err());
@@ -287,7 +383,7 @@ mod tests {
         });
 
         let req = Request::get(Url::parse(&server.url("/v1/missing")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(3)).await;
+        let res = client.execute::<()>(req, config(RequestStrategy::Retry(3))).await;
 
         mock.assert_hits_async(1).await;
 
@@ -297,6 +393,46 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn response_metadata() {
+        let client = TokioClient::new();
+        let last_response = LastResponse::default();
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/v1/customers");
+            then.status(400)
+                .header("Request-Id", "req_123")
+                .header("Idempotent-Replayed", "true")
+                .body("{\"error\": {\"type\": \"invalid_request_error\"}}");
+        });
+
+        let req = Request::post(Url::parse(&server.url("/v1/customers")).unwrap());
+        let res = client
+            .execute::<()>(
+                req,
+                RequestConfig {
+                    last_response: last_response.clone(),
+                    ..config(RequestStrategy::Once)
+                },
+            )
+            .await;
+
+        mock.assert_hits_async(1).await;
+
+        match res {
+            Err(StripeError::Stripe(x)) => assert_eq!(x.request_id.as_deref(), Some("req_123")),
+            _ => panic!("Expected stripe error {:?}", res),
+        }
+
+        let metadata = last_response.get().unwrap();
+        assert_eq!(metadata.status, StatusCode::BadRequest);
+        assert_eq!(metadata.request_id.as_deref(), Some("req_123"));
+        assert!(metadata.idempotent_replayed);
+    }
+
     #[tokio::test]
     async fn nice_serde_error() {
         use serde::Deserialize;
@@ -327,7 +463,7 @@ mod tests {
         });
 
         let req = Request::get(Url::parse(&server.url("/v1/odd_data")).unwrap());
-        let res = client.execute::<DataType>(req, &RequestStrategy::Retry(3)).await;
+        let res = client.execute::<DataType>(req, config(RequestStrategy::Retry(3))).await;
 
         mock.assert_hits_async(1).await;
 
@@ -339,6 +475,23 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn raw_bytes() {
+        let client = TokioClient::new();
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/quotes/qt_123/pdf");
+            then.status(200).header("Content-Type", "application/pdf").body(b"%PDF-1.4");
+        });
+
+        let req = Request::get(Url::parse(&server.url("/quotes/qt_123/pdf")).unwrap());
+        let res = client.execute_bytes(req, config(RequestStrategy::Once)).await;
+
+        mock.assert_hits_async(1).await;
+        assert_eq!(res.unwrap(), b"%PDF-1.4");
+    }
+
     #[tokio::test]
     async fn retry_header() {
         let client = TokioClient::new();
@@ -353,12 +506,119 @@ mod tests {
         });
 
         let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res = client.execute::<()>(req, config(RequestStrategy::Retry(5))).await;
 
         hello_mock.assert_hits_async(1).await;
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn retry_rate_limit() {
+        let client = TokioClient::new();
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        // Create a mock on the server.
+        let hello_mock = server.mock(|when, then| {
+            when.method(GET).path("/rate-limited");
+            then.status(429).header("Retry-After", "0");
+        });
+
+        let req = Request::get(Url::parse(&server.url("/rate-limited")).unwrap());
+        let policy = Backoff::new(2).with_initial_delay(Duration::ZERO);
+        let res = client.execute::<()>(req, config(policy)).await;
+
+        hello_mock.assert_hits_async(3).await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn attempt_timeout() {
+        let client = TokioClient::new();
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        // Create a mock on the server.
+        let hello_mock = server.mock(|when, then| {
+            when.method(GET).path("/slow");
+            then.status(200).body("null").delay(Duration::from_millis(500));
+        });
+
+        let req = Request::get(Url::parse(&server.url("/slow")).unwrap());
+        let res = client
+            .execute::<()>(
+                req,
+                RequestConfig {
+                    timeout: Some(Duration::from_millis(50)),
+                    ..config(RequestStrategy::Retry(2))
+                },
+            )
+            .await;
+
+        hello_mock.assert_hits_async(2).await;
+        assert!(matches!(res, Err(StripeError::Timeout)));
+    }
+
+    #[tokio::test]
+    async fn request_deadline() {
+        let client = TokioClient::new();
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        // Create a mock on the server.
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/server-errors");
+            then.status(500).delay(Duration::from_millis(100));
+        });
+
+        let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
+        let res = client
+            .execute::<()>(
+                req,
+                RequestConfig {
+                    deadline: Some(Duration::from_millis(250)),
+                    ..config(RequestStrategy::Retry(10))
+                },
+            )
+            .await;
+
+        assert!(matches!(res, Err(StripeError::Timeout)));
+        assert!(mock.hits_async().await < 10);
+    }
+
+    #[tokio::test]
+    async fn request_deadline_backoff() {
+        let client = TokioClient::new();
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        // Create a mock on the server.
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/server-errors");
+            then.status(500);
+        });
+
+        let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
+        let start = std::time::Instant::now();
+        let res = client
+            .execute::<()>(
+                req,
+                RequestConfig {
+                    deadline: Some(Duration::from_millis(100)),
+                    ..config(Backoff::new(3).with_initial_delay(Duration::from_secs(60)))
+                },
+            )
+            .await;
+
+        mock.assert_hits_async(1).await;
+        assert!(matches!(res, Err(StripeError::Timeout)));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
     #[tokio::test]
     async fn retry_body() {
         let client = TokioClient::new();
@@ -374,9 +634,189 @@ mod tests {
 
         let mut req = Request::post(Url::parse(&server.url("/server-errors")).unwrap());
         req.set_body("body");
-        let res = client.execute::<()>(req, &RequestStrategy::Retry(5)).await;
+        let res = client.execute::<()>(req, config(RequestStrategy::Retry(5))).await;
 
         hello_mock.assert_hits_async(5).await;
         assert!(res.is_err());
     }
+
+    #[tokio::test]
+    async fn custom_policy() {
+        /// Retries once, recording the status seen on each decision.
+        #[derive(Default)]
+        struct Recording(Mutex<Vec<Option<u16>>>);
+
+        impl RetryPolicy for Recording {
+            fn decide(&self, context: &RetryContext<'_>) -> Outcome {
+                self.0.lock().unwrap().push(context.status.map(|s| s as u16));
+                assert_eq!(context.path, "/server-errors");
+                assert_eq!(context.error.is_some(), context.retry_count > 0);
+                if context.retry_count < 2 {
+                    Outcome::Continue(None)
+                } else {
+                    Outcome::Stop
+                }
+            }
+        }
+
+        let client = TokioClient::new();
+        let server = MockServer::start_async().await;
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/server-errors");
+            then.status(500);
+        });
+
+        let policy = Arc::new(Recording::default());
+        let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
+        let res = client
+            .execute::<()>(req, RequestConfig { strategy: policy.clone(), ..Default::default() })
+            .await;
+
+        mock.assert_hits_async(2).await;
+        assert!(res.is_err());
+        assert_eq!(*policy.0.lock().unwrap(), vec![None, Some(500), Some(500)]);
+    }
+
+    #[tokio::test]
+    async fn middleware() {
+        #[derive(Default)]
+        struct Recording(Mutex<Vec<(u32, Option<u16>, bool)>>);
+
+        impl Middleware for Recording {
+            fn on_attempt(&self, attempt: &Attempt<'_>) {
+                self.0.lock().unwrap().push((
+                    attempt.attempt,
+                    attempt.status.map(u16::from),
+                    attempt.error.is_some(),
+                ));
+            }
+        }
+
+        let client = TokioClient::new();
+
+        // Start a lightweight mock server.
+        let server = MockServer::start_async().await;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/server-errors");
+            then.status(500);
+        });
+
+        let req = Request::get(Url::parse(&server.url("/server-errors")).unwrap());
+        let recording = Arc::new(Recording::default());
+        let res = client
+            .execute::<()>(
+                req,
+                RequestConfig {
+                    middleware: vec![recording.clone()],
+                    ..config(RequestStrategy::Retry(2))
+                },
+            )
+            .await;
+
+        mock.assert_hits_async(2).await;
+        assert!(res.is_err());
+        assert_eq!(*recording.0.lock().unwrap(), vec![(1, Some(500), true), (2, Some(500), true)]);
+    }
+
+    #[tokio::test]
+    async fn custom_transport() {
+        /// Replays the given responses in order, recording the requests it is sent.
+        struct Fixture(Mutex<Vec<http_types::Response>>, Mutex<Vec<String>>);
+
+        impl HttpTransport for Fixture {
+            fn send(&self, request: Request) -> TransportFuture {
+                self.1.lock().unwrap().push(request.url().path().to_string());
+                let response = self.0.lock().unwrap().remove(0);
+                Box::pin(async move { Ok(response) })
+            }
+        }
+
+        let mut ok = http_types::Response::new(StatusCode::Ok);
+        ok.set_body("{\"id\": \"cus_123\"}");
+        let fixture = Arc::new(Fixture(
+            Mutex::new(vec![http_types::Response::new(StatusCode::InternalServerError), ok]),
+            Mutex::new(vec![]),
+        ));
+
+        let client = TokioClient::new().with_transport(fixture.clone());
+        let req = Request::get(Url::parse("https://api.stripe.com/v1/customers/cus_123").unwrap());
+        let res = client.execute::<serde_json::Value>(req, config(RequestStrategy::Retry(3))).await;
+
+        assert_eq!(res.unwrap()["id"], "cus_123");
+        assert_eq!(*fixture.1.lock().unwrap(), vec!["/v1/customers/cus_123"; 2]);
+    }
+
+    #[tokio::test]
+    async fn transport_error_clears_last_response() {
+        /// Responds with a retryable server error, and then fails to connect.
+        struct Flaky(Mutex<u32>);
+
+        impl HttpTransport for Flaky {
+            fn send(&self, _request: Request) -> TransportFuture {
+                let mut sent = self.0.lock().unwrap();
+                *sent += 1;
+                let result = if *sent == 1 {
+                    let mut response = http_types::Response::new(StatusCode::InternalServerError);
+                    response.insert_header("Retry-After", "7");
+                    response.insert_header("Stripe-Should-Retry", "true");
+                    Ok(response)
+                } else {
+                    Err(StripeError::ClientError("connection refused".into()))
+                };
+                Box::pin(async move { result })
+            }
+        }
+
+        #[derive(Default)]
+        struct Recording(Mutex<Vec<(Option<u16>, Option<Duration>, Option<bool>)>>);
+
+        impl RetryPolicy for Recording {
+            fn decide(&self, context: &RetryContext<'_>) -> Outcome {
+                self.0.lock().unwrap().push((
+                    context.status.map(u16::from),
+                    context.retry_after(),
+                    context.stripe_should_retry,
+                ));
+                if context.retry_count < 2 {
+                    Outcome::Continue(None)
+                } else {
+                    Outcome::Stop
+                }
+            }
+        }
+
+        let client = TokioClient::new().with_transport(Flaky(Mutex::new(0)));
+        let policy = Arc::new(Recording::default());
+        let req = Request::get(Url::parse("https://api.stripe.com/v1/customers").unwrap());
+        let res = client
+            .execute::<()>(req, RequestConfig { strategy: policy.clone(), ..Default::default() })
+            .await;
+
+        assert!(matches!(res, Err(StripeError::ClientError(_))));
+        assert_eq!(
+            *policy.0.lock().unwrap(),
+            vec![
+                (None, None, None),
+                (Some(500), Some(Duration::from_secs(7)), Some(true)),
+                (None, None, None),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "a connect timeout cannot be applied to a custom transport")]
+    fn connect_timeout_with_transport() {
+        TokioClient::new()
+            .with_transport(hyper::Client::new())
+            .with_connect_timeout(Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "a connect timeout cannot be applied to a custom transport")]
+    fn transport_with_connect_timeout() {
+        TokioClient::new()
+            .with_connect_timeout(Duration::from_secs(1))
+            .with_transport(hyper::Client::new());
+    }
 }
@@ -0,0 +1,282 @@
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use hyper::client::HttpConnector;
+use hyper::service::Service;
+use hyper::Uri;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use super::{connector, HttpClient};
+use crate::client::transport::HttpTransport;
+use crate::error::StripeError;
+
+/// Configures the hyper client used to talk to stripe with the tokio runtimes.
+///
+/// The default client opens a new connection for every request. Keeping connections
+/// alive saves a tls handshake per request:
+///
+/// ```no_run
+/// # fn run() -> Result<(), stripe::StripeError> {
+/// use std::time::Duration;
+///
+/// let transport = stripe::ClientBuilder::new()
+///     .with_pool_max_idle_per_host(8)
+///     .with_pool_idle_timeout(Duration::from_secs(60))
+///     .with_tcp_keepalive(Duration::from_secs(30))
+///     .build()?;
+///
+/// let client = stripe::Client::new("sk_test_123").with_transport(transport);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ClientBuilder {
+    pool_max_idle_per_host: usize,
+    pool_idle_timeout: Option<Duration>,
+    http2_only: bool,
+    http2_keep_alive_interval: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<String>,
+    root_certificates: Vec<Vec<u8>>,
+}
+
+impl ClientBuilder {
+    /// Create a builder with the same settings as the default client.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep up to this many idle connections open to reuse for later requests.
+    ///
+    /// The default is 0, so that every request uses a new connection.
+    pub fn with_pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.pool_max_idle_per_host = max_idle;
+        self
+    }
+
+    /// Close idle connections after the given duration.
+    pub fn with_pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Only use http2, rather than negotiating the protocol for each connection.
+    ///
+    /// N.B. The rustls backends already prefer http2 where the server supports it.
+    pub fn with_http2_only(mut self, http2_only: bool) -> Self {
+        self.http2_only = http2_only;
+        self
+    }
+
+    /// Send http2 pings at the given interval to keep connections alive.
+    pub fn with_http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Enable tcp keepalive, probing idle connections after the given duration.
+    pub fn with_tcp_keepalive(mut self, keepalive: Duration) -> Self {
+        self.tcp_keepalive = Some(keepalive);
+        self
+    }
+
+    /// Give up on establishing a connection after the given duration.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Tunnel connections through the given http proxy, such as `http://proxy.internal:3128`.
+    ///
+    /// N.B. Proxies which require authentication are not supported.
+    pub fn with_proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Trust the given PEM encoded root certificate, in addition to the default roots.
+    pub fn add_root_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Build the client, to pass to [`Client::with_transport`](crate::Client::with_transport).
+    ///
+    /// # Errors
+    ///
+    /// Fails if the proxy url or a root certificate is invalid.
+    pub fn build(self) -> Result<impl HttpTransport + Clone, StripeError> {
+        self.build_client()
+    }
+
+    pub(super) fn build_client(self) -> Result<HttpClient, StripeError> {
+        let proxy = match self.proxy {
+            Some(proxy) => Some(proxy.parse::<Uri>().map_err(|e| {
+                StripeError::ClientError(format!("invalid proxy url {:?}: {}", proxy, e))
+            })?),
+            None => None,
+        };
+
+        let mut http = HttpConnector::new();
+        // the https connector decides whether to use tls, so allow any scheme here
+        http.enforce_http(false);
+        http.set_connect_timeout(self.connect_timeout);
+        http.set_keepalive(self.tcp_keepalive);
+        let proxy = ProxyConnector { http, proxy };
+
+        let connector = if self.root_certificates.is_empty() {
+            connector::create(proxy)
+        } else {
+            connector::create_with_roots(proxy, &self.root_certificates)?
+        };
+
+        let mut builder = hyper::Client::builder();
+        builder
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .http2_only(self.http2_only)
+            .http2_keep_alive_interval(self.http2_keep_alive_interval);
+        Ok(builder.build(connector))
+    }
+}
+
+/// Connects directly, or through an http proxy using `CONNECT` if one is configured.
+#[derive(Clone, Debug)]
+pub struct ProxyConnector {
+    pub(super) http: HttpConnector,
+    pub(super) proxy: Option<Uri>,
+}
+
+impl Service<Uri> for ProxyConnector {
+    type Response = TcpStream;
+    type Error = Box<dyn Error + Send + Sync>;
+    type Future = Pin<Box<dyn Future<Output = Result<TcpStream, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.http.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let mut http = self.http.clone();
+        let proxy = self.proxy.clone();
+
+        Box::pin(async move {
+            let proxy = match proxy {
+                Some(proxy) => proxy,
+                None => return Ok(http.call(uri).await?),
+            };
+
+            let host = uri.host().ok_or("request url has no host")?;
+            let port = uri.port_u16().unwrap_or(match uri.scheme_str() {
+                Some("http") => 80,
+                _ => 443,
+            });
+
+            let mut stream = http.call(proxy).await?;
+            let connect = format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n\r\n");
+            stream.write_all(connect.as_bytes()).await?;
+
+            // N.B. The server sends nothing else until the client speaks,
+            //      so the response head is all that will be read here.
+            let mut head = Vec::new();
+            let mut buf = [0; 1024];
+            while !head.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buf).await?;
+                if read == 0 || head.len() > 8192 {
+                    return Err("invalid response from proxy".into());
+                }
+                head.extend_from_slice(&buf[..read]);
+            }
+
+            let status = head.split(|b| *b == b' ').nth(1).unwrap_or_default();
+            if status != b"200" {
+                let line = head.split(|b| *b == b'\r').next().unwrap_or_default();
+                return Err(
+                    format!("proxy refused to connect: {}", String::from_utf8_lossy(line)).into()
+                );
+            }
+
+            Ok(stream)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use http_types::{Request, Url};
+    use httpmock::prelude::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    use super::ClientBuilder;
+    use crate::client::base::tokio::TokioClient;
+    use crate::client::base::BaseClient;
+    use crate::client::request_options::RequestConfig;
+    use crate::client::request_strategy::RequestStrategy;
+
+    #[tokio::test]
+    async fn pooled() {
+        let server = MockServer::start_async().await;
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers");
+            then.status(200).body("null");
+        });
+
+        let transport = ClientBuilder::new().with_pool_max_idle_per_host(4).build().unwrap();
+        let client = TokioClient::new().with_transport(transport);
+        for _ in 0..2 {
+            let req = Request::get(Url::parse(&server.url("/v1/customers")).unwrap());
+            client.execute::<()>(req, RequestConfig::default()).await.unwrap();
+        }
+
+        mock.assert_hits_async(2).await;
+    }
+
+    #[tokio::test]
+    async fn proxy() {
+        let server = MockServer::start_async().await;
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/customers");
+            then.status(200).body("null");
+        });
+
+        // a proxy which tunnels a single connection, recording where it was asked to connect
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy = format!("http://{}", listener.local_addr().unwrap());
+        let tunnel = tokio::spawn(async move {
+            let (mut inbound, _) = listener.accept().await.unwrap();
+            let mut buf = [0; 1024];
+            let read = inbound.read(&mut buf).await.unwrap();
+            let request = String::from_utf8_lossy(&buf[..read]).to_string();
+            let target = request.split(' ').nth(1).unwrap().to_string();
+
+            let mut outbound = TcpStream::connect(&target).await.unwrap();
+            inbound.write_all(b"HTTP/1.1 200 Connection Established\r\n\r\n").await.unwrap();
+            tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await.ok();
+            target
+        });
+
+        let transport = ClientBuilder::new().with_proxy(proxy).build().unwrap();
+        let client = TokioClient::new().with_transport(transport);
+        let req = Request::get(Url::parse(&server.url("/v1/customers")).unwrap());
+        let config =
+            RequestConfig { strategy: Arc::new(RequestStrategy::Once), ..Default::default() };
+        client.execute::<()>(req, config).await.unwrap();
+
+        mock.assert_hits_async(1).await;
+        drop(client);
+        assert_eq!(tunnel.await.unwrap(), server.address().to_string());
+    }
+
+    #[test]
+    fn invalid_proxy() {
+        assert!(ClientBuilder::new().with_proxy("not a url").build().is_err());
+    }
+}
@@ -1,27 +1,20 @@
-use std::{sync::Arc, time::Duration};
+use std::{future::Future, sync::Arc, time::Duration};
 
 use http_types::Request;
 use serde::de::DeserializeOwned;
 
 use crate::client::base::tokio::TokioClient;
-use crate::client::request_strategy::RequestStrategy;
+use crate::client::base::BaseClient;
+use crate::client::request_options::RequestConfig;
+use crate::client::transport::HttpTransport;
 use crate::error::StripeError;
 
-/// The delay after which the blocking `Client` will assume the request has failed.
-const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+/// The delay after which the blocking `Client` will assume the request has failed,
+/// including any retries, unless another deadline is configured.
+const DEFAULT_DEADLINE: Duration = Duration::from_secs(30);
 
 pub type Response<T> = Result<T, StripeError>;
 
-#[inline(always)]
-pub(crate) fn ok<T>(ok: T) -> Response<T> {
-    Ok(ok)
-}
-
-#[inline(always)]
-pub(crate) fn err<T>(err: crate::StripeError) -> Response<T> {
-    Err(err)
-}
-
 #[derive(Clone)]
 pub struct TokioBlockingClient {
     inner: TokioClient,
@@ -29,11 +22,6 @@ pub struct TokioBlockingClient {
 }
 
 impl TokioBlockingClient {
-    /// Creates a new client pointed to `https://api.stripe.com/`
-    pub fn new() -> TokioBlockingClient {
-        TokioBlockingClient::from_async(TokioClient::new())
-    }
-
     fn from_async(inner: TokioClient) -> TokioBlockingClient {
         let runtime = tokio::runtime::Builder::new_current_thread()
             .enable_io()
@@ -43,19 +31,57 @@ impl TokioBlockingClient {
         TokioBlockingClient { inner, runtime: Arc::new(runtime) }
     }
 
-    pub fn execute<T: DeserializeOwned + Send + 'static>(
+    fn block_on<T>(&self, future: impl Future<Output = Response<T>>) -> Response<T> {
+        self.runtime.block_on(future)
+    }
+}
+
+impl BaseClient for TokioBlockingClient {
+    type Response<T: Send + 'static> = Response<T>;
+
+    /// Creates a new client pointed to `https://api.stripe.com/`
+    fn new() -> TokioBlockingClient {
+        TokioBlockingClient::from_async(TokioClient::new())
+    }
+
+    /// Give up on establishing a connection after the given duration.
+    fn with_connect_timeout(self, timeout: Duration) -> Self {
+        Self { inner: self.inner.with_connect_timeout(timeout), runtime: self.runtime }
+    }
+
+    /// Send requests through the given transport, rather than the default hyper client.
+    ///
+    /// The transport is driven by the client's own single-threaded tokio runtime.
+    fn with_transport(self, transport: impl HttpTransport + 'static) -> Self {
+        Self { inner: self.inner.with_transport(transport), runtime: self.runtime }
+    }
+
+    fn execute<T: DeserializeOwned + Send + 'static>(
         &self,
         request: Request,
-        strategy: &RequestStrategy,
+        mut config: RequestConfig,
     ) -> Response<T> {
-        let future = self.inner.execute(request, strategy);
-        match self.runtime.block_on(async {
-            // N.B. The `tokio::time::timeout` must be called from within a running async
-            //      context or else it will panic (it registers with the thread-local timer).
-            tokio::time::timeout(DEFAULT_TIMEOUT, future).await
-        }) {
-            Ok(finished) => finished,
-            Err(_) => Err(StripeError::Timeout),
-        }
+        config.deadline.get_or_insert(DEFAULT_DEADLINE);
+        self.block_on(self.inner.execute(request, config))
+    }
+
+    fn execute_bytes(&self, request: Request, mut config: RequestConfig) -> Response<Vec<u8>> {
+        config.deadline.get_or_insert(DEFAULT_DEADLINE);
+        self.block_on(self.inner.execute_bytes(request, config))
+    }
+
+    fn ok<T: Send + 'static>(value: T) -> Response<T> {
+        Ok(value)
+    }
+
+    fn err<T: Send + 'static>(error: StripeError) -> Response<T> {
+        Err(error)
+    }
+
+    fn map<T: Send + 'static, U: Send + 'static>(
+        response: Response<T>,
+        f: impl FnOnce(T) -> U + Send + 'static,
+    ) -> Response<U> {
+        response.map(f)
     }
 }
@@ -0,0 +1,171 @@
+//! Spans emitted for requests when the `tracing` feature is enabled.
+//!
+//! Each call to the api gets a `stripe.request` span, with a `stripe.attempt`
+//! child span for every attempt the retry loop makes at it.
+
+use std::time::Duration;
+
+use http_types::Request;
+use tracing::field::Empty;
+use tracing::Span;
+
+use crate::client::middleware::Attempt;
+use crate::error::StripeError;
+use crate::params::to_snakecase;
+
+/// Create the span covering a request, including all of its retries.
+pub(crate) fn request_span(request: &Request) -> Span {
+    tracing::info_span!(
+        "stripe.request",
+        http.method = %request.method(),
+        http.route = %templated_path(request.url().path()),
+        http.status_code = Empty,
+        stripe.idempotency_key = request.header("Idempotency-Key").map(|key| key.last().as_str()),
+        stripe.request_id = Empty,
+        stripe.attempts = Empty,
+        stripe.error_type = Empty,
+        stripe.error_code = Empty,
+        error = Empty,
+    )
+}
+
+/// Create the span covering a single attempt, as a child of the current request span.
+pub(crate) fn attempt_span(attempt: u32, delay: Option<Duration>) -> Span {
+    tracing::info_span!(
+        "stripe.attempt",
+        stripe.attempt = attempt,
+        stripe.backoff_ms = delay.map(|delay| delay.as_millis() as u64),
+        http.status_code = Empty,
+        stripe.request_id = Empty,
+        stripe.error_type = Empty,
+        stripe.error_code = Empty,
+        error = Empty,
+    )
+}
+
+/// Record the outcome of an attempt on its span, and on the enclosing request span.
+///
+/// N.B. This must be called from within the request span, but not the attempt span.
+pub(crate) fn record(span: &Span, attempt: &Attempt<'_>) {
+    let request = Span::current();
+    request.record("stripe.attempts", attempt.attempt);
+
+    for span in [span, &request] {
+        if let Some(status) = attempt.status {
+            span.record("http.status_code", u16::from(status));
+        }
+        if let Some(request_id) = attempt.response.and_then(|r| r.request_id.as_deref()) {
+            span.record("stripe.request_id", request_id);
+        }
+        match attempt.error {
+            Some(StripeError::Stripe(error)) => {
+                span.record("stripe.error_type", error.error_type.to_string().as_str());
+                if let Some(code) = error.code {
+                    span.record("stripe.error_code", to_snakecase(&format!("{:?}", code)).as_str());
+                }
+                span.record("error", tracing::field::display(error));
+            }
+            Some(error) => {
+                span.record("error", tracing::field::display(error));
+            }
+            None => {}
+        }
+    }
+
+    if let Some(error) = attempt.error {
+        tracing::debug!(parent: span, attempt = attempt.attempt, %error, "stripe request attempt failed");
+    }
+}
+
+/// The segments which group collections under a common prefix, such as `/v1/issuing/cards`.
+const NAMESPACES: &[&str] = &[
+    "v1",
+    "apps",
+    "billing",
+    "billing_portal",
+    "checkout",
+    "climate",
+    "entitlements",
+    "financial_connections",
+    "forwarding",
+    "identity",
+    "issuing",
+    "radar",
+    "reporting",
+    "sigma",
+    "tax",
+    "terminal",
+    "test_helpers",
+    "treasury",
+];
+
+/// The segments which may follow a collection without naming an object in it.
+const COLLECTION_ACTIONS: &[&str] = &["search", "upcoming"];
+
+/// Replace the object ids in a path with `{id}`, so paths can be grouped by endpoint.
+///
+/// Paths alternate between a collection and an object in it, such as
+/// `/v1/customers/{id}/sources/{id}`, so every segment following a collection
+/// is taken to be an id, whatever its format. Actions on an object, like
+/// `/v1/payment_intents/{id}/capture`, take the place of a collection.
+pub(crate) fn templated_path(path: &str) -> String {
+    let mut in_namespace = true;
+    let mut after_collection = false;
+    path.split('/')
+        .map(|segment| {
+            if in_namespace && (segment.is_empty() || NAMESPACES.contains(&segment)) {
+                segment
+            } else if after_collection {
+                after_collection = false;
+                if COLLECTION_ACTIONS.contains(&segment) {
+                    segment
+                } else {
+                    "{id}"
+                }
+            } else {
+                in_namespace = false;
+                after_collection = true;
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::templated_path;
+
+    #[test]
+    fn template_ids() {
+        assert_eq!(templated_path("/v1/customers"), "/v1/customers");
+        assert_eq!(templated_path("/v1/customers/cus_NffrFeUfNV2Hib"), "/v1/customers/{id}");
+        assert_eq!(
+            templated_path("/v1/payment_intents/pi_123/capture"),
+            "/v1/payment_intents/{id}/capture"
+        );
+        assert_eq!(
+            templated_path("/v1/customers/cus_123/sources/card_456"),
+            "/v1/customers/{id}/sources/{id}"
+        );
+    }
+
+    #[test]
+    fn template_custom_ids() {
+        assert_eq!(templated_path("/v1/coupons/summer"), "/v1/coupons/{id}");
+        assert_eq!(templated_path("/v1/products/gold-plan"), "/v1/products/{id}");
+        assert_eq!(templated_path("/v1/plans/basic"), "/v1/plans/{id}");
+    }
+
+    #[test]
+    fn template_namespaces_and_actions() {
+        assert_eq!(templated_path("/v1/issuing/cards/ic_123"), "/v1/issuing/cards/{id}");
+        assert_eq!(
+            templated_path("/v1/test_helpers/treasury/outbound_payments/obp_123/fail"),
+            "/v1/test_helpers/treasury/outbound_payments/{id}/fail"
+        );
+        assert_eq!(templated_path("/v1/customers/search"), "/v1/customers/search");
+        assert_eq!(templated_path("/v1/invoices/upcoming/lines"), "/v1/invoices/upcoming/lines");
+        assert_eq!(templated_path("/v1/checkout/sessions"), "/v1/checkout/sessions");
+    }
+}
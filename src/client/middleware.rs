@@ -0,0 +1,72 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use http_types::{Method, Request, StatusCode};
+
+use crate::client::response_metadata::ResponseMetadata;
+use crate::error::StripeError;
+
+/// Hooks that run around every request made by a [`Client`](crate::Client).
+///
+/// Middleware is added with [`Client::with_middleware`](crate::Client::with_middleware),
+/// and runs in the order it was added. This is the place for custom headers,
+/// metrics, or logging, without wrapping each resource method:
+///
+/// ```
+/// use std::sync::atomic::{AtomicU32, Ordering};
+/// use stripe::{Attempt, Middleware};
+///
+/// #[derive(Default)]
+/// struct CountFailures(AtomicU32);
+///
+/// impl Middleware for CountFailures {
+///     fn on_attempt(&self, attempt: &Attempt<'_>) {
+///         if attempt.error.is_some() {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+///
+/// let client = stripe::Client::new("sk_test_123").with_middleware(CountFailures::default());
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Called once per request, before it is first sent.
+    ///
+    /// The request may be modified, for example to add headers.
+    fn on_request(&self, request: &mut Request) {
+        let _ = request;
+    }
+
+    /// Called after every attempt at a request, including retries.
+    fn on_attempt(&self, attempt: &Attempt<'_>) {
+        let _ = attempt;
+    }
+}
+
+/// The outcome of a single attempt at a request, as seen by a [`Middleware`].
+///
+/// N.B. Error responses from stripe have both a `status` and an `error`.
+#[derive(Debug)]
+pub struct Attempt<'a> {
+    /// The http method of the request.
+    pub method: Method,
+    /// The path of the request, such as `/v1/customers/cus_123`.
+    pub path: &'a str,
+    /// The number of this attempt, starting at 1.
+    pub attempt: u32,
+    /// The time taken to send the request and read the response.
+    pub latency: Duration,
+    /// The status of the response, if one was received.
+    pub status: Option<StatusCode>,
+    /// The error produced by this attempt, if any.
+    pub error: Option<&'a StripeError>,
+    /// The metadata of the response, if one was received.
+    pub response: Option<&'a ResponseMetadata>,
+}
+
+/// Run the `on_attempt` hook of each middleware in turn.
+pub(crate) fn notify(middleware: &[Arc<dyn Middleware>], attempt: &Attempt<'_>) {
+    for middleware in middleware {
+        middleware.on_attempt(attempt);
+    }
+}
@@ -1,69 +1,116 @@
+#[cfg(feature = "tracing")]
+mod instrumentation;
+mod middleware;
+mod request_options;
 mod request_strategy;
+mod response_metadata;
 mod stripe;
+mod transport;
 
-mod base {
-    #[cfg(any(
+mod base;
+
+#[cfg(all(
+    feature = "runtime-async-std-surf",
+    any(
         feature = "runtime-tokio-hyper",
         feature = "runtime-tokio-hyper-rustls",
-        feature = "runtime-tokio-hyper-rustls-webpki",
-        feature = "runtime-blocking",
-        feature = "runtime-blocking-rustls",
-        feature = "runtime-blocking-rustls-webpki",
-    ))]
-    pub mod tokio;
-
-    #[cfg(feature = "runtime-async-std-surf")]
-    pub mod async_std;
-
-    #[cfg(any(
-        feature = "runtime-blocking",
-        feature = "runtime-blocking-rustls",
-        feature = "runtime-blocking-rustls-webpki"
-    ))]
-    pub mod tokio_blocking;
-}
-
-#[cfg(any(
-    feature = "runtime-blocking",
-    feature = "runtime-blocking-rustls",
-    feature = "runtime-blocking-rustls-webpki"
+        feature = "runtime-tokio-hyper-rustls-webpki"
+    )
 ))]
-pub(crate) mod config {
-    pub(crate) use super::base::tokio_blocking::{err, ok};
-    pub use super::base::tokio_blocking::{Response, TokioBlockingClient as BaseClient};
-}
+compile_error!("You must enable only one async runtime");
 
 #[cfg(any(
     feature = "runtime-tokio-hyper",
     feature = "runtime-tokio-hyper-rustls",
     feature = "runtime-tokio-hyper-rustls-webpki"
 ))]
-pub(crate) mod config {
-    pub(crate) use super::base::tokio::{err, ok};
-    pub use super::base::tokio::{Response, TokioClient as BaseClient};
+mod config {
+    pub(crate) use tokio::time::sleep;
+
+    pub use super::base::tokio::TokioClient as AsyncClient;
 }
 
 #[cfg(feature = "runtime-async-std-surf")]
-pub(crate) mod config {
-    pub(crate) use super::base::async_std::{err, ok};
-    pub use super::base::async_std::{AsyncStdClient as BaseClient, Response};
+mod config {
+    pub(crate) use async_std::task::sleep;
+
+    pub use super::base::async_std::AsyncStdClient as AsyncClient;
 }
 
-pub use config::BaseClient;
-/// An alias for `Result`.
+/// Sleep for the given duration, on the selected async runtime.
+#[cfg(feature = "async")]
+pub(crate) use config::sleep;
+
+/// The async client, for the selected async runtime.
+#[cfg(feature = "async")]
+pub type Client = GenericClient<config::AsyncClient>;
+
+/// The blocking client, since no async runtime is enabled.
+#[cfg(all(feature = "blocking", not(feature = "async")))]
+pub type Client = blocking::Client;
+
+/// An alias for the type returned by requests made with [`Client`].
 ///
-/// If `blocking` is enabled, defined as:
+/// If an async runtime is enabled, defined as:
 ///
 /// ```rust,ignore
-/// type Response<T> = Result<T, Error>;
+/// type Response<T> = Pin<Box<dyn Future<Output = Result<T, StripeError>> + Send>>;
 /// ```
 ///
-/// If the `async` feature is enabled, this type is defined as:
+/// Otherwise, if `blocking` is enabled, this type is defined as:
 ///
 /// ```rust,ignore
-/// type Response<T> = Box<dyn Future<Result<T, Error>>>;
+/// type Response<T> = Result<T, StripeError>;
 /// ```
-pub use config::Response;
-pub use request_strategy::RequestStrategy;
+pub type Response<T> = <Client as StripeClient>::Response<T>;
+
+/// A client which blocks the current thread until each request completes.
+///
+/// This is available alongside the async [`Client`] when both a `runtime-blocking*` and
+/// an async runtime feature are enabled, so that tools and services sharing a workspace
+/// may use whichever suits them. The api methods accept either client:
+///
+/// ```no_run
+/// # fn run() -> Result<(), stripe::StripeError> {
+/// let client = stripe::blocking::Client::new("sk_test_123");
+/// let customer = stripe::Customer::retrieve(&client, &"cus_123".parse().unwrap(), &[])?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// N.B. The blocking client drives its requests on its own runtime, so it
+///      must not be used from within an async context.
+#[cfg(any(
+    feature = "runtime-blocking",
+    feature = "runtime-blocking-rustls",
+    feature = "runtime-blocking-rustls-webpki"
+))]
+pub mod blocking {
+    use crate::client::base::tokio_blocking::TokioBlockingClient;
+    use crate::client::GenericClient;
+    use crate::StripeError;
+
+    /// The blocking client.
+    pub type Client = GenericClient<TokioBlockingClient>;
+
+    /// An alias for `Result`, the type returned by requests made with the blocking [`Client`].
+    pub type Response<T> = Result<T, StripeError>;
+}
+
+#[cfg(any(
+    feature = "runtime-tokio-hyper",
+    feature = "runtime-tokio-hyper-rustls",
+    feature = "runtime-tokio-hyper-rustls-webpki",
+    feature = "runtime-blocking",
+    feature = "runtime-blocking-rustls",
+    feature = "runtime-blocking-rustls-webpki",
+))]
+pub use base::tokio::ClientBuilder;
+pub use base::BaseClient;
+pub use middleware::{Attempt, Middleware};
+pub use request_options::RequestOptions;
+pub use request_strategy::{Backoff, Outcome, RequestStrategy, RetryContext, RetryPolicy};
+pub use response_metadata::ResponseMetadata;
+pub use transport::{HttpTransport, TransportFuture};
 
-pub use self::stripe::Client;
+pub use self::stripe::{GenericClient, StripeClient};
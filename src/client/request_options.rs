@@ -0,0 +1,164 @@
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use crate::client::middleware::Middleware;
+use crate::client::request_strategy::{RequestStrategy, RetryPolicy};
+use crate::client::response_metadata::LastResponse;
+use crate::{AccountId, ApiVersion};
+
+/// Options that apply to individual requests, overriding those configured on the [`Client`](crate::Client).
+///
+/// These are applied with [`Client::with_options`](crate::Client::with_options), which returns
+/// a cheap copy of the client to make the call with:
+///
+/// ```no_run
+/// # use stripe::{Client, CreateCustomer, Customer, RequestOptions};
+/// # async fn run() -> Result<(), stripe::StripeError> {
+/// # let client = Client::new("sk_test_123");
+/// let options = RequestOptions::new()
+///     .with_stripe_account("acct_123".parse().unwrap())
+///     .with_idempotency_key("create-customer-42");
+///
+/// let customer = Customer::create(&client.with_options(options), CreateCustomer::new()).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct RequestOptions {
+    /// The `Idempotency-Key` header, replacing the key chosen by the strategy.
+    pub(crate) idempotency_key: Option<String>,
+    /// The `Stripe-Account` header, for requests made on behalf of a connected account.
+    pub(crate) stripe_account: Option<AccountId>,
+    /// The `Stripe-Version` header, replacing the version the client was generated for.
+    pub(crate) stripe_version: Option<ApiVersion>,
+    /// The retry policy, replacing the one configured on the client.
+    pub(crate) strategy: Option<Arc<dyn RetryPolicy>>,
+    /// The limit on the duration of each attempt, replacing the one configured on the client.
+    pub(crate) timeout: Option<Duration>,
+    /// The limit on the duration of the request, replacing the one configured on the client.
+    pub(crate) deadline: Option<Duration>,
+}
+
+impl RequestOptions {
+    /// Create an empty set of options, which leaves the client's configuration unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send the given `Idempotency-Key`, instead of the one provided by the request strategy.
+    ///
+    /// Stripe rejects requests that reuse a key with different parameters, so the key
+    /// is only sent with the first request made by the client carrying these options.
+    /// Later requests through that client use the key from the request strategy.
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Make the request on behalf of a connected account.
+    pub fn with_stripe_account(mut self, id: AccountId) -> Self {
+        self.stripe_account = Some(id);
+        self
+    }
+
+    /// Make the request against a specific version of the api.
+    ///
+    /// N.B. The types in this crate are generated for a single version,
+    ///      so responses from other versions may fail to deserialize.
+    pub fn with_stripe_version(mut self, version: ApiVersion) -> Self {
+        self.stripe_version = Some(version);
+        self
+    }
+
+    /// Use the given request strategy.
+    pub fn with_strategy(mut self, strategy: impl RetryPolicy + 'static) -> Self {
+        self.strategy = Some(Arc::new(strategy));
+        self
+    }
+
+    /// Give up on an attempt at the request if it takes longer than the given duration.
+    ///
+    /// An attempt that times out fails with [`StripeError::Timeout`](crate::StripeError::Timeout),
+    /// and may be retried according to the request strategy.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Give up on the request if it takes longer than the given duration, including
+    /// every attempt and the delays between them.
+    ///
+    /// A request that runs out of time fails with [`StripeError::Timeout`](crate::StripeError::Timeout).
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Combine two sets of options, preferring those in `other`.
+    pub(crate) fn merge(self, other: RequestOptions) -> Self {
+        Self {
+            idempotency_key: other.idempotency_key.or(self.idempotency_key),
+            stripe_account: other.stripe_account.or(self.stripe_account),
+            stripe_version: other.stripe_version.or(self.stripe_version),
+            strategy: other.strategy.or(self.strategy),
+            timeout: other.timeout.or(self.timeout),
+            deadline: other.deadline.or(self.deadline),
+        }
+    }
+}
+
+impl fmt::Debug for RequestOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // N.B. A retry policy is not required to implement `Debug`
+        f.debug_struct("RequestOptions")
+            .field("idempotency_key", &self.idempotency_key)
+            .field("stripe_account", &self.stripe_account)
+            .field("stripe_version", &self.stripe_version)
+            .field("strategy", &self.strategy.as_ref().map(|_| ".."))
+            .field("timeout", &self.timeout)
+            .field("deadline", &self.deadline)
+            .finish()
+    }
+}
+
+/// An idempotency key given in [`RequestOptions`], which is sent with one request only.
+///
+/// This is shared by clones of the client it was given to, so that the key
+/// is used at most once however the client is copied.
+#[derive(Clone, Default)]
+pub(crate) struct PendingKey(Arc<Mutex<Option<String>>>);
+
+impl PendingKey {
+    pub(crate) fn new(key: String) -> Self {
+        Self(Arc::new(Mutex::new(Some(key))))
+    }
+
+    /// Take the key for a request, leaving none for the next one.
+    pub(crate) fn take(&self) -> Option<String> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).take()
+    }
+}
+
+/// The settings for a single request, resolved from the client and its options,
+/// as handed to the runtime-specific base client.
+#[derive(Clone)]
+pub struct RequestConfig {
+    pub(crate) strategy: Arc<dyn RetryPolicy>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) last_response: LastResponse,
+    pub(crate) middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl Default for RequestConfig {
+    fn default() -> Self {
+        Self {
+            strategy: Arc::new(RequestStrategy::Once),
+            timeout: None,
+            deadline: None,
+            last_response: LastResponse::default(),
+            middleware: Vec::new(),
+        }
+    }
+}
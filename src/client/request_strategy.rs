@@ -1,6 +1,84 @@
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
-use http_types::StatusCode;
+use http_types::headers::{Headers, RETRY_AFTER};
+use http_types::other::RetryAfter;
+use http_types::{Method, StatusCode};
+
+use crate::error::StripeError;
+
+/// Decides whether, and when, a failed request should be attempted again.
+///
+/// The built-in [`RequestStrategy`] covers the common cases, but custom policies
+/// (for example jittered backoff, a total time budget, or different rules per
+/// endpoint) can be implemented and passed to [`Client::with_strategy`](crate::Client::with_strategy).
+///
+/// ```
+/// use std::time::Duration;
+/// use stripe::{Outcome, RetryContext, RetryPolicy};
+///
+/// struct Budget(Duration);
+///
+/// impl RetryPolicy for Budget {
+///     fn decide(&self, context: &RetryContext<'_>) -> Outcome {
+///         match (context.retry_count, context.status) {
+///             (0, _) => Outcome::Continue(None),
+///             (_, Some(status)) if status.is_client_error() => Outcome::Stop,
+///             _ if context.elapsed < self.0 => Outcome::Continue(Some(Duration::from_millis(500))),
+///             _ => Outcome::Stop,
+///         }
+///     }
+/// }
+///
+/// let client = stripe::Client::new("sk_test_123").with_strategy(Budget(Duration::from_secs(10)));
+/// ```
+pub trait RetryPolicy: Send + Sync {
+    /// Called before every attempt, including the first, to decide whether to
+    /// (re)send the request, and how long to wait before doing so.
+    fn decide(&self, context: &RetryContext<'_>) -> Outcome;
+
+    /// The idempotency key to send with the request, if any.
+    ///
+    /// This is called once per request, so the key is stable across retries.
+    fn idempotency_key(&self) -> Option<String> {
+        None
+    }
+}
+
+/// The state of a request, as seen by a [`RetryPolicy`].
+#[derive(Debug)]
+pub struct RetryContext<'a> {
+    /// The http method of the request.
+    pub method: Method,
+    /// The path of the request, such as `/v1/customers/cus_123`.
+    pub path: &'a str,
+    /// The number of attempts made so far.
+    pub retry_count: u32,
+    /// The status of the last response, if one was received.
+    pub status: Option<StatusCode>,
+    /// The value of the `Stripe-Should-Retry` header on the last response, if present.
+    pub stripe_should_retry: Option<bool>,
+    /// The headers of the last response, if one was received.
+    pub headers: Option<&'a Headers>,
+    /// The error produced by the last attempt, if any.
+    pub error: Option<&'a StripeError>,
+    /// The time elapsed since the first attempt was made.
+    pub elapsed: Duration,
+}
+
+impl RetryContext<'_> {
+    /// The delay requested by the `Retry-After` header of the last response, if any.
+    pub fn retry_after(&self) -> Option<Duration> {
+        let headers = self.headers?;
+        let value = headers.get(RETRY_AFTER)?.last().as_str();
+        match value.parse::<u64>() {
+            Ok(seconds) => Some(Duration::from_secs(seconds)),
+            // otherwise it is an http date
+            Err(_) => RetryAfter::from_headers(headers)
+                .ok()?
+                .map(|at| at.duration_since(SystemTime::now()).unwrap_or_default()),
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 pub enum RequestStrategy {
@@ -14,6 +92,10 @@ pub enum RequestStrategy {
     /// This strategy will retry the request up to the
     /// specified number of times using the same, random,
     /// idempotency key with exponential backoff, up to n times.
+    ///
+    /// Attempt `n` waits `2^n` seconds, or longer if stripe asks with a
+    /// `Retry-After` header. See [`Backoff`] for a policy with jitter and
+    /// configurable caps.
     ExponentialBackoff(u32),
 }
 
@@ -62,12 +144,7 @@ impl RequestStrategy {
         match self {
             RequestStrategy::Once => None,
             RequestStrategy::Idempotent(key) => Some(key.clone()),
-            #[cfg(feature = "uuid")]
-            RequestStrategy::Retry(_) | RequestStrategy::ExponentialBackoff(_) => {
-                Some(uuid::Uuid::new_v4().to_string())
-            }
-            #[cfg(not(feature = "uuid"))]
-            RequestStrategy::Retry(_) | RequestStrategy::ExponentialBackoff(_) => None,
+            RequestStrategy::Retry(_) | RequestStrategy::ExponentialBackoff(_) => random_key(),
         }
     }
 }
@@ -76,9 +153,172 @@ fn calculate_backoff(retry_count: u32) -> Duration {
     Duration::from_secs(2_u64.pow(retry_count))
 }
 
+impl RetryPolicy for RequestStrategy {
+    fn decide(&self, context: &RetryContext<'_>) -> Outcome {
+        match self.test(context.status, context.stripe_should_retry, context.retry_count) {
+            // never wait for less time than stripe asks for
+            Outcome::Continue(delay) if context.retry_count > 0 => {
+                Outcome::Continue(match (delay, context.retry_after()) {
+                    (Some(delay), Some(retry_after)) => Some(delay.max(retry_after)),
+                    (delay, retry_after) => delay.or(retry_after),
+                })
+            }
+            outcome => outcome,
+        }
+    }
+
+    fn idempotency_key(&self) -> Option<String> {
+        self.get_key()
+    }
+}
+
+/// An exponential backoff [`RetryPolicy`] with full jitter.
+///
+/// Before retry `n`, this waits a random duration between zero and
+/// `min(max_delay, initial_delay * 2^(n - 1))`, or for as long as the
+/// `Retry-After` header of the last response asks, whichever is longer.
+/// `max_delay` only caps the computed backoff, so a longer `Retry-After`
+/// is still honoured. It gives up once `max_retries` retries have been
+/// made after the first attempt, or when the next delay would push the
+/// total time spent on the request past `max_elapsed`.
+///
+/// Unlike [`RequestStrategy`], this also retries requests that were rate
+/// limited (`429 Too Many Requests`), as those are expected to succeed later.
+///
+/// Randomising the delay prevents many clients that hit an error at the
+/// same time from retrying in lockstep.
+///
+/// ```
+/// use std::time::Duration;
+/// use stripe::Backoff;
+///
+/// let policy = Backoff::new(5)
+///     .with_initial_delay(Duration::from_millis(500))
+///     .with_max_delay(Duration::from_secs(8))
+///     .with_max_elapsed(Duration::from_secs(30));
+///
+/// let client = stripe::Client::new("sk_test_123").with_strategy(policy);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Backoff {
+    max_retries: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    max_elapsed: Option<Duration>,
+    jitter: bool,
+}
+
+impl Backoff {
+    /// Retry a failed request up to `max_retries` times after the first attempt,
+    /// backing off for at most 30 seconds between attempts, and spending at most
+    /// 2 minutes in total.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            max_elapsed: Some(Duration::from_secs(120)),
+            jitter: true,
+        }
+    }
+
+    /// Set the upper bound of the delay before the first retry.
+    pub fn with_initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Set the longest computed backoff between two attempts.
+    ///
+    /// A longer `Retry-After` requested by stripe is still honoured, within `max_elapsed`.
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the longest time allowed between the first attempt and the start of the last.
+    pub fn with_max_elapsed(mut self, elapsed: Duration) -> Self {
+        self.max_elapsed = Some(elapsed);
+        self
+    }
+
+    /// Always wait for the full delay, rather than a random fraction of it.
+    pub fn without_jitter(mut self) -> Self {
+        self.jitter = false;
+        self
+    }
+
+    /// The delay before the given attempt, not accounting for `Retry-After`.
+    fn delay(&self, retry_count: u32) -> Option<Duration> {
+        let exponent = retry_count.checked_sub(1)?;
+        let ceiling =
+            self.initial_delay.saturating_mul(2_u32.saturating_pow(exponent)).min(self.max_delay);
+        Some(if self.jitter { ceiling.mul_f64(fastrand::f64()) } else { ceiling })
+    }
+}
+
+impl RetryPolicy for Backoff {
+    fn decide(&self, context: &RetryContext<'_>) -> Outcome {
+        // the first attempt is not a retry
+        if context.retry_count > self.max_retries
+            || !is_retryable(context.status, context.stripe_should_retry)
+        {
+            return Outcome::Stop;
+        }
+
+        let delay = match (self.delay(context.retry_count), context.retry_after()) {
+            (Some(delay), Some(retry_after)) => Some(delay.max(retry_after)),
+            (delay, retry_after) => delay.or(retry_after),
+        };
+
+        match delay {
+            Some(delay)
+                if self.max_elapsed.map_or(false, |max| {
+                    context.elapsed.checked_add(delay).map_or(true, |elapsed| elapsed > max)
+                }) =>
+            {
+                Outcome::Stop
+            }
+            delay => Outcome::Continue(delay),
+        }
+    }
+
+    fn idempotency_key(&self) -> Option<String> {
+        random_key()
+    }
+}
+
+/// Whether a request that failed with the given status may succeed if retried.
+fn is_retryable(status: Option<StatusCode>, stripe_should_retry: Option<bool>) -> bool {
+    match (stripe_should_retry, status) {
+        // if stripe explicitly says whether to retry then listen
+        (Some(should_retry), _) => should_retry,
+
+        // requests with idempotency keys that hit client errors usually
+        // cannot be solved with retries, with the exception of rate limits
+        // see: https://stripe.com/docs/error-handling#content-errors
+        (None, Some(c)) if c.is_client_error() => c == StatusCode::TooManyRequests,
+
+        _ => true,
+    }
+}
+
+#[cfg(feature = "uuid")]
+fn random_key() -> Option<String> {
+    Some(uuid::Uuid::new_v4().to_string())
+}
+
+#[cfg(not(feature = "uuid"))]
+fn random_key() -> Option<String> {
+    None
+}
+
+/// The decision made by a [`RetryPolicy`].
 #[derive(PartialEq, Eq, Debug)]
 pub enum Outcome {
+    /// Give up, returning the last error.
     Stop,
+    /// Send the request, optionally after waiting for the given duration.
     Continue(Option<Duration>),
 }
 
@@ -86,7 +326,22 @@ pub enum Outcome {
 mod tests {
     use std::time::Duration;
 
-    use super::{Outcome, RequestStrategy};
+    use http_types::{Method, Response, StatusCode};
+
+    use super::{Backoff, Outcome, RequestStrategy, RetryContext, RetryPolicy};
+
+    fn context(retry_count: u32, status: Option<StatusCode>) -> RetryContext<'static> {
+        RetryContext {
+            method: Method::Get,
+            path: "/v1/customers",
+            retry_count,
+            status,
+            stripe_should_retry: None,
+            headers: None,
+            error: None,
+            elapsed: Duration::ZERO,
+        }
+    }
 
     #[test]
     fn test_idempotent_strategy() {
@@ -142,4 +397,138 @@ mod tests {
         let strategy = RequestStrategy::Retry(3);
         assert_eq!(strategy.test(None, Some(false), 0), Outcome::Stop);
     }
+
+    #[test]
+    fn test_policy_impl() {
+        let strategy = RequestStrategy::ExponentialBackoff(3);
+        let server_error = context(1, Some(StatusCode::InternalServerError));
+        assert_eq!(strategy.decide(&server_error), Outcome::Continue(Some(Duration::from_secs(2))));
+        assert_eq!(strategy.decide(&context(1, Some(StatusCode::NotFound))), Outcome::Stop);
+    }
+
+    #[test]
+    fn test_client_errors_stop_strategies() {
+        let rate_limited = context(1, Some(StatusCode::TooManyRequests));
+        assert_eq!(RequestStrategy::Retry(3).decide(&rate_limited), Outcome::Stop);
+        assert_eq!(RequestStrategy::ExponentialBackoff(3).decide(&rate_limited), Outcome::Stop);
+
+        let conflict = RetryContext {
+            stripe_should_retry: Some(true),
+            ..context(1, Some(StatusCode::Conflict))
+        };
+        assert_eq!(RequestStrategy::Retry(3).decide(&conflict), Outcome::Stop);
+    }
+
+    #[test]
+    fn test_rate_limit_retried_by_backoff() {
+        let policy = Backoff::new(3).without_jitter();
+        assert_eq!(
+            policy.decide(&context(1, Some(StatusCode::TooManyRequests))),
+            Outcome::Continue(Some(Duration::from_secs(1)))
+        );
+        assert_eq!(policy.decide(&context(1, Some(StatusCode::NotFound))), Outcome::Stop);
+        assert_eq!(
+            policy.decide(&RetryContext {
+                stripe_should_retry: Some(true),
+                ..context(1, Some(StatusCode::Conflict))
+            }),
+            Outcome::Continue(Some(Duration::from_secs(1)))
+        );
+    }
+
+    #[test]
+    fn test_backoff_counts_retries() {
+        let policy = Backoff::new(2).without_jitter();
+        assert_eq!(policy.decide(&context(0, None)), Outcome::Continue(None));
+        assert_eq!(
+            policy.decide(&context(2, None)),
+            Outcome::Continue(Some(Duration::from_secs(2)))
+        );
+        assert_eq!(policy.decide(&context(3, None)), Outcome::Stop);
+
+        assert_eq!(Backoff::new(0).decide(&context(0, None)), Outcome::Continue(None));
+        assert_eq!(Backoff::new(0).decide(&context(1, None)), Outcome::Stop);
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let mut response = Response::new(StatusCode::ServiceUnavailable);
+        response.insert_header("Retry-After", "3");
+        let context = RetryContext {
+            headers: Some(response.as_ref()),
+            ..context(1, Some(StatusCode::ServiceUnavailable))
+        };
+
+        assert_eq!(context.retry_after(), Some(Duration::from_secs(3)));
+        assert_eq!(
+            RequestStrategy::Retry(3).decide(&context),
+            Outcome::Continue(Some(Duration::from_secs(3)))
+        );
+        assert_eq!(
+            Backoff::new(3).decide(&context),
+            Outcome::Continue(Some(Duration::from_secs(3)))
+        );
+        assert_eq!(
+            RequestStrategy::ExponentialBackoff(3).decide(&context),
+            Outcome::Continue(Some(Duration::from_secs(3)))
+        );
+
+        // a long retry-after is honoured beyond the backoff cap, but not past the time budget
+        let mut response = Response::new(StatusCode::TooManyRequests);
+        response.insert_header("Retry-After", "60");
+        let context = RetryContext { headers: Some(response.as_ref()), ..context };
+        assert_eq!(
+            Backoff::new(3).with_max_delay(Duration::from_secs(2)).decide(&context),
+            Outcome::Continue(Some(Duration::from_secs(60)))
+        );
+        assert_eq!(
+            Backoff::new(3).with_max_elapsed(Duration::from_secs(30)).decide(&context),
+            Outcome::Stop
+        );
+
+        // a retry-after too long to add to the elapsed time is over any time budget
+        let mut response = Response::new(StatusCode::TooManyRequests);
+        response.insert_header("Retry-After", u64::MAX.to_string());
+        let context = RetryContext {
+            headers: Some(response.as_ref()),
+            elapsed: Duration::from_secs(1),
+            ..context
+        };
+        assert_eq!(context.retry_after(), Some(Duration::from_secs(u64::MAX)));
+        assert_eq!(Backoff::new(3).decide(&context), Outcome::Stop);
+    }
+
+    #[test]
+    fn test_backoff_caps() {
+        let policy = Backoff::new(10).without_jitter().with_max_delay(Duration::from_secs(5));
+        assert_eq!(policy.decide(&context(0, None)), Outcome::Continue(None));
+        assert_eq!(
+            policy.decide(&context(1, None)),
+            Outcome::Continue(Some(Duration::from_secs(1)))
+        );
+        assert_eq!(
+            policy.decide(&context(3, None)),
+            Outcome::Continue(Some(Duration::from_secs(4)))
+        );
+        assert_eq!(
+            policy.decide(&context(4, None)),
+            Outcome::Continue(Some(Duration::from_secs(5)))
+        );
+        assert_eq!(policy.decide(&context(11, None)), Outcome::Stop);
+
+        let policy = policy.with_max_elapsed(Duration::from_secs(10));
+        let late = RetryContext { elapsed: Duration::from_secs(8), ..context(3, None) };
+        assert_eq!(policy.decide(&late), Outcome::Stop);
+    }
+
+    #[test]
+    fn test_backoff_jitter() {
+        let policy = Backoff::new(10);
+        for _ in 0..100 {
+            match policy.decide(&context(2, None)) {
+                Outcome::Continue(Some(d)) => assert!(d <= Duration::from_secs(2)),
+                outcome => panic!("unexpected outcome {:?}", outcome),
+            }
+        }
+    }
 }
@@ -0,0 +1,95 @@
+use std::sync::{Arc, Mutex, PoisonError};
+
+use http_types::StatusCode;
+
+/// Details of the last response received by a [`Client`](crate::Client).
+///
+/// This is recorded for every attempt at a request, successful or not,
+/// and may be read back with [`Client::last_response`](crate::Client::last_response)
+/// from the client instance that made the request:
+///
+/// ```no_run
+/// # use stripe::{Client, CreateCustomer, Customer};
+/// # async fn run() -> Result<(), stripe::StripeError> {
+/// # let client = Client::new("sk_test_123");
+/// let client = client.clone();
+/// let customer = Customer::create(&client, CreateCustomer::new()).await?;
+///
+/// if let Some(response) = client.last_response() {
+///     println!("created {} in request {:?}", customer.id, response.request_id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResponseMetadata {
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+
+    /// The `Request-Id` stripe assigned to the request, to quote in support tickets.
+    pub request_id: Option<String>,
+
+    /// The `Idempotency-Key` the request was made with, if any.
+    pub idempotency_key: Option<String>,
+
+    /// Whether stripe replayed a previous response for the idempotency key,
+    /// rather than performing the request again.
+    pub idempotent_replayed: bool,
+
+    /// The `Stripe-Version` used to serve the request.
+    pub stripe_version: Option<String>,
+
+    /// The `Stripe-Account` the request was made on behalf of, if any.
+    pub stripe_account: Option<String>,
+}
+
+impl ResponseMetadata {
+    /// Collect the metadata from a response, given a way to look up its headers.
+    pub(crate) fn new<'a>(status: StatusCode, header: impl Fn(&str) -> Option<&'a str>) -> Self {
+        Self {
+            status,
+            request_id: header("Request-Id").map(str::to_string),
+            idempotency_key: header("Idempotency-Key").map(str::to_string),
+            idempotent_replayed: header("Idempotent-Replayed") == Some("true"),
+            stripe_version: header("Stripe-Version").map(str::to_string),
+            stripe_account: header("Stripe-Account").map(str::to_string),
+        }
+    }
+}
+
+/// A slot holding the metadata of the last response received by a client instance.
+#[derive(Clone, Debug, Default)]
+pub struct LastResponse(Arc<Mutex<Option<ResponseMetadata>>>);
+
+impl LastResponse {
+    pub(crate) fn get(&self) -> Option<ResponseMetadata> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    pub(crate) fn set(&self, metadata: ResponseMetadata) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = Some(metadata);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http_types::StatusCode;
+
+    use super::ResponseMetadata;
+
+    #[test]
+    fn from_headers() {
+        let metadata = ResponseMetadata::new(StatusCode::Ok, |name| match name {
+            "Request-Id" => Some("req_123"),
+            "Idempotent-Replayed" => Some("true"),
+            "Stripe-Version" => Some("2020-08-27"),
+            _ => None,
+        });
+
+        assert_eq!(metadata.status, StatusCode::Ok);
+        assert_eq!(metadata.request_id.as_deref(), Some("req_123"));
+        assert!(metadata.idempotent_replayed);
+        assert_eq!(metadata.stripe_version.as_deref(), Some("2020-08-27"));
+        assert_eq!(metadata.idempotency_key, None);
+    }
+}
@@ -1,9 +1,18 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use http_types::{Body, Method, Request, Url};
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    client::{request_strategy::RequestStrategy, BaseClient, Response},
-    config::err,
+    client::{
+        middleware::Middleware,
+        request_options::{PendingKey, RequestConfig, RequestOptions},
+        request_strategy::{RequestStrategy, RetryPolicy},
+        response_metadata::{LastResponse, ResponseMetadata},
+        transport::HttpTransport,
+        BaseClient,
+    },
     generated::core::version::VERSION,
     params::AppInfo,
     AccountId, ApplicationId, Headers, StripeError,
@@ -11,27 +20,134 @@ use crate::{
 
 static USER_AGENT: &str = concat!("Stripe/v1 RustBindings/", env!("CARGO_PKG_VERSION"));
 
-#[derive(Clone)]
-pub struct Client {
-    client: crate::client::BaseClient,
+/// Makes requests to the stripe api.
+///
+/// This is implemented by the async [`Client`](crate::Client) and, with a `runtime-blocking*`
+/// feature, the [`blocking::Client`](crate::blocking::Client), so the api methods accept either.
+/// Code which should work with both can be generic over it in the same way:
+///
+/// ```no_run
+/// use stripe::{Customer, CustomerId, StripeClient};
+///
+/// fn fetch<C: StripeClient>(client: &C, id: &CustomerId) -> C::Response<Customer> {
+///     Customer::retrieve(client, id, &["default_source"])
+/// }
+/// ```
+pub trait StripeClient {
+    /// The type returned by a request.
+    ///
+    /// This is a future resolving to a `Result` for the async client,
+    /// or the `Result` itself for the blocking client.
+    type Response<T: Send + 'static>;
+
+    /// Make a `GET` http request with just a path
+    fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Self::Response<T>;
+
+    /// Make a `GET` http request with url query parameters
+    fn get_query<T: DeserializeOwned + Send + 'static, P: Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Self::Response<T>;
+
+    /// Make a `DELETE` http request with just a path
+    fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Self::Response<T>;
+
+    /// Make a `DELETE` http request with url query parameters
+    fn delete_query<T: DeserializeOwned + Send + 'static, P: Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Self::Response<T>;
+
+    /// Make a `POST` http request with just a path
+    fn post<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Self::Response<T>;
+
+    /// Make a `POST` http request with urlencoded body
+    fn post_form<T: DeserializeOwned + Send + 'static, F: Serialize>(
+        &self,
+        path: &str,
+        form: F,
+    ) -> Self::Response<T>;
+
+    /// Make a `GET` http request to the files api, returning the raw response body
+    fn get_file(&self, path: &str) -> Self::Response<Vec<u8>>;
+
+    /// Wrap a value in a response which has already completed.
+    #[doc(hidden)]
+    fn ok<T: Send + 'static>(value: T) -> Self::Response<T>;
+
+    /// Wrap an error in a response which has already failed.
+    #[doc(hidden)]
+    fn err<T: Send + 'static>(error: StripeError) -> Self::Response<T>;
+
+    /// Transform the value of a successful response.
+    #[doc(hidden)]
+    fn map<T: Send + 'static, U: Send + 'static>(
+        response: Self::Response<T>,
+        f: impl FnOnce(T) -> U + Send + 'static,
+    ) -> Self::Response<U>;
+}
+
+/// A client for the stripe api, which sends its requests with the given [`BaseClient`].
+///
+/// This is usually named through the [`Client`](crate::Client)
+/// or [`blocking::Client`](crate::blocking::Client) aliases.
+pub struct GenericClient<B> {
+    client: B,
     secret_key: String,
     headers: Headers,
-    strategy: RequestStrategy,
+    strategy: Arc<dyn RetryPolicy>,
     app_info: Option<AppInfo>,
     api_base: Url,
+    files_base: Url,
     api_root: String,
+    options: RequestOptions,
+    idempotency_key: PendingKey,
+    last_response: LastResponse,
+    middleware: Vec<Arc<dyn Middleware>>,
 }
 
-impl Client {
+impl<B: Clone> Clone for GenericClient<B> {
+    /// Copy the client, sharing its connections but not its [`GenericClient::last_response`].
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            secret_key: self.secret_key.clone(),
+            headers: self.headers.clone(),
+            strategy: self.strategy.clone(),
+            app_info: self.app_info.clone(),
+            api_base: self.api_base.clone(),
+            files_base: self.files_base.clone(),
+            api_root: self.api_root.clone(),
+            options: self.options.clone(),
+            idempotency_key: self.idempotency_key.clone(),
+            last_response: LastResponse::default(),
+            middleware: self.middleware.clone(),
+        }
+    }
+}
+
+impl<B: BaseClient> GenericClient<B> {
     /// Create a new account with the given secret key.
     pub fn new(secret_key: impl Into<String>) -> Self {
-        Self::from_url("https://api.stripe.com/", secret_key)
+        let mut client = Self::from_url("https://api.stripe.com/", secret_key);
+        // N.B. Replacing the host of an https url with a valid domain cannot fail.
+        let _ = client.files_base.set_host(Some("files.stripe.com"));
+        client
     }
 
     /// Create a new account pointed at a specific URL. This is useful for testing.
+    ///
+    /// Requests to the files api, such as downloading a quote's pdf, are sent to the same URL.
+    ///
+    /// # Panics
+    ///
+    /// If the url cannot be parsed.
     pub fn from_url<'a>(url: impl Into<&'a str>, secret_key: impl Into<String>) -> Self {
-        Client {
-            client: BaseClient::new(),
+        let api_base = Url::parse(url.into()).expect("invalid url");
+        GenericClient {
+            client: B::new(),
             secret_key: secret_key.into(),
             headers: Headers {
                 stripe_version: VERSION,
@@ -39,10 +155,15 @@ impl Client {
                 client_id: None,
                 stripe_account: None,
             },
-            strategy: RequestStrategy::Once,
+            strategy: Arc::new(RequestStrategy::Once),
             app_info: None,
-            api_base: Url::parse(url.into()).expect("invalid url"),
+            files_base: api_base.clone(),
+            api_base,
             api_root: "v1".to_string(),
+            options: RequestOptions::default(),
+            idempotency_key: PendingKey::default(),
+            last_response: LastResponse::default(),
+            middleware: Vec::new(),
         }
     }
 
@@ -59,11 +180,129 @@ impl Client {
     }
 
     /// Set the request strategy for the client.
-    pub fn with_strategy(mut self, strategy: RequestStrategy) -> Self {
-        self.strategy = strategy;
+    ///
+    /// This accepts any [`RetryPolicy`], including the built-in [`RequestStrategy`].
+    pub fn with_strategy(mut self, strategy: impl RetryPolicy + 'static) -> Self {
+        self.strategy = Arc::new(strategy);
+        self
+    }
+
+    /// Add a middleware to run around every request made by the client.
+    ///
+    /// Middleware runs in the order it was added. See [`Middleware`] for an example.
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Send requests through the given transport, instead of the default http client
+    /// for the selected runtime. See [`HttpTransport`] for an example.
+    ///
+    /// # Panics
+    ///
+    /// If [`GenericClient::with_connect_timeout`] has been called, since that configures
+    /// the default http client. Set the timeout on the transport instead, such as with
+    /// `ClientBuilder::with_connect_timeout` for the tokio runtimes.
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.client = self.client.with_transport(transport);
+        self
+    }
+
+    /// Give up on an attempt at a request if it takes longer than the given duration.
+    ///
+    /// An attempt that times out fails with [`StripeError::Timeout`], and may be
+    /// retried according to the request strategy. This can be overridden for
+    /// individual requests with [`RequestOptions::with_timeout`].
+    ///
+    /// The attempts are also bounded by the deadline of the request, see
+    /// [`GenericClient::with_deadline`].
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Give up on a request if it takes longer than the given duration, including
+    /// every attempt and the delays between them.
+    ///
+    /// A request that runs out of time fails with [`StripeError::Timeout`]. This can be
+    /// overridden for individual requests with [`RequestOptions::with_deadline`].
+    ///
+    /// The async clients have no deadline by default, and the blocking client has a
+    /// deadline of 30 seconds, which must be raised for longer timeouts or backoff to apply.
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.options.deadline = Some(deadline);
         self
     }
 
+    /// Give up on establishing a connection to stripe after the given duration.
+    ///
+    /// As with [`GenericClient::with_timeout`], this fails the attempt with [`StripeError::Timeout`].
+    ///
+    /// # Panics
+    ///
+    /// If a custom transport has been set with [`GenericClient::with_transport`],
+    /// as its configuration cannot be changed.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.client = self.client.with_connect_timeout(timeout);
+        self
+    }
+
+    /// Get a copy of this client that applies the given options to its requests.
+    ///
+    /// This is how options are set per request: the resource methods take any
+    /// [`StripeClient`], so the copy is passed in place of the shared client for
+    /// a single call. Copying is cheap, as the connection pool, middleware and
+    /// retry policy are shared. Options already applied to this client are kept
+    /// unless overridden, except for the idempotency key, which is only sent with
+    /// the first request made by the copy.
+    ///
+    /// ```no_run
+    /// # use stripe::{Client, CreateRefund, Refund, RequestOptions};
+    /// # async fn run(client: &Client, account: stripe::AccountId) -> Result<(), stripe::StripeError> {
+    /// // refund a charge made on a connected account, safe to retry with the same order id
+    /// let options = RequestOptions::new()
+    ///     .with_stripe_account(account)
+    ///     .with_idempotency_key("refund-order-42");
+    ///
+    /// let mut params = CreateRefund::new();
+    /// params.charge = Some("ch_123".parse().unwrap());
+    /// let refund = Refund::create(&client.with_options(options), params).await?;
+    ///
+    /// // the shared client is unaffected
+    /// let other = Refund::retrieve(client, &refund.id, &[]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Like any clone, the copy records its own [`GenericClient::last_response`].
+    pub fn with_options(&self, mut options: RequestOptions) -> Self {
+        let mut client = self.clone();
+        if let Some(key) = options.idempotency_key.take() {
+            client.idempotency_key = PendingKey::new(key);
+        }
+        client.options = client.options.merge(options);
+        client
+    }
+
+    /// Get the metadata of the last response received, such as the `Request-Id`.
+    ///
+    /// Only requests made through this instance are recorded, as each clone of the
+    /// client keeps its own. To read the metadata of a particular call while the
+    /// client is shared between tasks, make the call with a clone of it:
+    ///
+    /// ```no_run
+    /// # use stripe::{Client, Customer};
+    /// # async fn run(client: &Client, id: &stripe::CustomerId) -> Result<(), stripe::StripeError> {
+    /// let scoped = client.clone();
+    /// let customer = Customer::retrieve(&scoped, id, &[]).await?;
+    /// let request_id = scoped.last_response().and_then(|response| response.request_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn last_response(&self) -> Option<ResponseMetadata> {
+        self.last_response.get()
+    }
+
     /// Set the application info for the client.
     ///
     /// It is recommended that applications set this so that
@@ -82,9 +321,9 @@ impl Client {
     }
 
     /// Make a `GET` http request with just a path
-    pub fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+    pub fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> B::Response<T> {
         let url = self.url(path);
-        self.client.execute::<T>(self.create_request(Method::Get, url), &self.strategy)
+        self.execute(self.create_request(Method::Get, url))
     }
 
     /// Make a `GET` http request with url query parameters
@@ -92,18 +331,18 @@ impl Client {
         &self,
         path: &str,
         params: P,
-    ) -> Response<T> {
+    ) -> B::Response<T> {
         let url = match self.url_with_params(path, params) {
-            Err(e) => return err(e),
+            Err(e) => return B::err(e),
             Ok(ok) => ok,
         };
-        self.client.execute::<T>(self.create_request(Method::Get, url), &self.strategy)
+        self.execute(self.create_request(Method::Get, url))
     }
 
     /// Make a `DELETE` http request with just a path
-    pub fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+    pub fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> B::Response<T> {
         let url = self.url(path);
-        self.client.execute::<T>(self.create_request(Method::Delete, url), &self.strategy)
+        self.execute(self.create_request(Method::Delete, url))
     }
 
     /// Make a `DELETE` http request with url query parameters
@@ -111,18 +350,18 @@ impl Client {
         &self,
         path: &str,
         params: P,
-    ) -> Response<T> {
+    ) -> B::Response<T> {
         let url = match self.url_with_params(path, params) {
-            Err(e) => return err(e),
+            Err(e) => return B::err(e),
             Ok(ok) => ok,
         };
-        self.client.execute::<T>(self.create_request(Method::Delete, url), &self.strategy)
+        self.execute(self.create_request(Method::Delete, url))
     }
 
     /// Make a `POST` http request with just a path
-    pub fn post<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
+    pub fn post<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> B::Response<T> {
         let url = self.url(path);
-        self.client.execute::<T>(self.create_request(Method::Post, url), &self.strategy)
+        self.execute(self.create_request(Method::Post, url))
     }
 
     /// Make a `POST` http request with urlencoded body
@@ -130,28 +369,61 @@ impl Client {
         &self,
         path: &str,
         form: F,
-    ) -> Response<T> {
-        let url = self.url(path);
-        let mut req = self.create_request(Method::Post, url);
-
+    ) -> B::Response<T> {
         let mut params_buffer = Vec::new();
         let qs_ser = &mut serde_qs::Serializer::new(&mut params_buffer);
         if let Err(qs_ser_err) = serde_path_to_error::serialize(&form, qs_ser) {
-            return err(StripeError::QueryStringSerialize(qs_ser_err));
+            return B::err(StripeError::QueryStringSerialize(qs_ser_err));
         }
 
         let body = std::str::from_utf8(params_buffer.as_slice())
             .expect("Unable to extract string from params_buffer")
             .to_string();
 
+        let url = self.url(path);
+        let mut req = self.create_request(Method::Post, url);
         req.set_body(Body::from_string(body));
 
         req.insert_header("content-type", "application/x-www-form-urlencoded");
-        self.client.execute::<T>(req, &self.strategy)
+        self.execute(req)
+    }
+
+    /// Make a `GET` http request to the files api, returning the raw response body
+    pub fn get_file(&self, path: &str) -> B::Response<Vec<u8>> {
+        let url = self.url_from(&self.files_base, path);
+        let mut request = self.create_request(Method::Get, url);
+        let config = self.config(&mut request);
+        self.client.execute_bytes(request, config)
+    }
+
+    fn execute<T: DeserializeOwned + Send + 'static>(
+        &self,
+        mut request: Request,
+    ) -> B::Response<T> {
+        let config = self.config(&mut request);
+        self.client.execute::<T>(request, config)
+    }
+
+    fn config(&self, request: &mut Request) -> RequestConfig {
+        for middleware in &self.middleware {
+            middleware.on_request(request);
+        }
+
+        RequestConfig {
+            strategy: self.options.strategy.clone().unwrap_or_else(|| self.strategy.clone()),
+            timeout: self.options.timeout,
+            deadline: self.options.deadline,
+            last_response: self.last_response.clone(),
+            middleware: self.middleware.clone(),
+        }
     }
 
     fn url(&self, path: &str) -> Url {
-        let mut url = self.api_base.clone();
+        self.url_from(&self.api_base, path)
+    }
+
+    fn url_from(&self, base: &Url, path: &str) -> Url {
+        let mut url = base.clone();
         url.set_path(&format!("{}/{}", self.api_root, path.trim_start_matches('/')));
         url
     }
@@ -175,21 +447,94 @@ impl Client {
         let mut req = Request::new(method, url);
         req.insert_header("authorization", &format!("Bearer {}", self.secret_key));
 
-        for (key, value) in self.headers.to_array().iter().filter_map(|(k, v)| v.map(|v| (*k, v))) {
+        let mut headers = self.headers.clone();
+        if let Some(account) = &self.options.stripe_account {
+            headers.stripe_account = Some(account.clone());
+        }
+        if let Some(version) = self.options.stripe_version {
+            headers.stripe_version = version;
+        }
+
+        for (key, value) in headers.to_array().iter().filter_map(|(k, v)| v.map(|v| (*k, v))) {
             req.insert_header(key, value);
         }
 
+        if let Some(key) = self.idempotency_key.take() {
+            req.insert_header("Idempotency-Key", key);
+        }
+
         req
     }
 }
 
+impl<B: BaseClient> StripeClient for GenericClient<B> {
+    type Response<T: Send + 'static> = B::Response<T>;
+
+    fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Self::Response<T> {
+        GenericClient::get(self, path)
+    }
+
+    fn get_query<T: DeserializeOwned + Send + 'static, P: Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Self::Response<T> {
+        GenericClient::get_query(self, path, params)
+    }
+
+    fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Self::Response<T> {
+        GenericClient::delete(self, path)
+    }
+
+    fn delete_query<T: DeserializeOwned + Send + 'static, P: Serialize>(
+        &self,
+        path: &str,
+        params: P,
+    ) -> Self::Response<T> {
+        GenericClient::delete_query(self, path, params)
+    }
+
+    fn post<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Self::Response<T> {
+        GenericClient::post(self, path)
+    }
+
+    fn post_form<T: DeserializeOwned + Send + 'static, F: Serialize>(
+        &self,
+        path: &str,
+        form: F,
+    ) -> Self::Response<T> {
+        GenericClient::post_form(self, path, form)
+    }
+
+    fn get_file(&self, path: &str) -> Self::Response<Vec<u8>> {
+        GenericClient::get_file(self, path)
+    }
+
+    fn ok<T: Send + 'static>(value: T) -> Self::Response<T> {
+        B::ok(value)
+    }
+
+    fn err<T: Send + 'static>(error: StripeError) -> Self::Response<T> {
+        B::err(error)
+    }
+
+    fn map<T: Send + 'static, U: Send + 'static>(
+        response: Self::Response<T>,
+        f: impl FnOnce(T) -> U + Send + 'static,
+    ) -> Self::Response<U> {
+        B::map(response, f)
+    }
+}
+
 #[cfg(test)]
 mod test {
     //! Ensures our user agent matches the format of the other stripe clients.
     //!
     //! See: <https://github.com/stripe/stripe-python/blob/3b917dc4cec6a3cccfd46961e05fe7b55c6bee87/stripe/api_requestor.py#L241>
 
-    use super::Client;
+    use http_types::{Method, StatusCode, Url};
+
+    use crate::{ApiVersion, Client, RequestOptions, ResponseMetadata};
 
     #[test]
     fn user_agent_base() {
@@ -228,4 +573,53 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn request_options() {
+        let client = Client::new("sk_test_12345").with_stripe_account("acct_123".parse().unwrap());
+        let url = Url::parse("https://api.stripe.com/v1/customers").unwrap();
+
+        let req = client.create_request(Method::Post, url.clone());
+        assert_eq!(req["Stripe-Account"], "acct_123");
+        assert!(req.header("Idempotency-Key").is_none());
+
+        let scoped = client.with_options(
+            RequestOptions::new()
+                .with_stripe_account("acct_456".parse().unwrap())
+                .with_stripe_version(ApiVersion::V2020_08_27)
+                .with_idempotency_key("key"),
+        );
+        let req = scoped.create_request(Method::Post, url.clone());
+        assert_eq!(req["Stripe-Account"], "acct_456");
+        assert_eq!(req["Stripe-Version"], "2020-08-27");
+        assert_eq!(req["Idempotency-Key"], "key");
+
+        // the key is only sent once, but the other options remain
+        let req = scoped.clone().create_request(Method::Post, url.clone());
+        assert_eq!(req["Stripe-Account"], "acct_456");
+        assert!(req.header("Idempotency-Key").is_none());
+
+        // the original client is unaffected
+        let req = client.create_request(Method::Post, url);
+        assert_eq!(req["Stripe-Account"], "acct_123");
+    }
+
+    #[test]
+    fn files_base() {
+        let client = Client::new("sk_test_12345");
+        assert_eq!(
+            client.url_from(&client.files_base, "/quotes/qt_123/pdf").as_str(),
+            "https://files.stripe.com/v1/quotes/qt_123/pdf"
+        );
+    }
+
+    #[test]
+    fn last_response_per_clone() {
+        let client = Client::new("sk_test_12345");
+        let clone = client.clone();
+
+        clone.last_response.set(ResponseMetadata::new(StatusCode::Ok, |_| None));
+        assert!(clone.last_response().is_some());
+        assert!(client.last_response().is_none());
+    }
 }
@@ -0,0 +1,49 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use http_types::{Request, Response};
+
+use crate::error::StripeError;
+
+/// The future returned by an [`HttpTransport`].
+pub type TransportFuture = Pin<Box<dyn Future<Output = Result<Response, StripeError>> + Send>>;
+
+/// Sends a single http request to stripe, without any retries.
+///
+/// By default, the [`Client`](crate::Client) uses a transport built for the selected runtime.
+/// Implementing this allows a pre-configured http client to be plugged in with
+/// [`Client::with_transport`](crate::Client::with_transport), for example to share a
+/// connection pool or proxy configuration with the rest of an application, or to replay
+/// recorded responses in tests:
+///
+/// ```
+/// use http_types::{Request, Response, StatusCode};
+/// use stripe::{HttpTransport, TransportFuture};
+///
+/// struct Fixture(&'static str);
+///
+/// impl HttpTransport for Fixture {
+///     fn send(&self, _request: Request) -> TransportFuture {
+///         let mut response = Response::new(StatusCode::Ok);
+///         response.set_body(self.0);
+///         Box::pin(async move { Ok(response) })
+///     }
+/// }
+///
+/// let client = stripe::Client::new("sk_test_123").with_transport(Fixture("{}"));
+/// ```
+///
+/// Retries, timeouts, and middleware are handled by the client, around the transport.
+/// A failed attempt should return a [`StripeError::ClientError`], or [`StripeError::Timeout`]
+/// if it timed out, so that it may be retried according to the request strategy.
+pub trait HttpTransport: Send + Sync {
+    /// Send the request, returning the response once it is received.
+    fn send(&self, request: Request) -> TransportFuture;
+}
+
+impl<T: HttpTransport + ?Sized> HttpTransport for Arc<T> {
+    fn send(&self, request: Request) -> TransportFuture {
+        (**self).send(request)
+    }
+}
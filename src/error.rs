@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::params::to_snakecase;
+use crate::resources::TestHelpersTestClock;
 
 /// An error encountered when communicating with the Stripe API.
 #[derive(Debug, Error)]
@@ -25,6 +26,17 @@ pub enum StripeError {
 #[cfg(feature = "hyper")]
 impl From<hyper::Error> for StripeError {
     fn from(err: hyper::Error) -> StripeError {
+        // connect timeouts are reported as an io error somewhere down the chain
+        let mut source = std::error::Error::source(&err);
+        while let Some(inner) = source {
+            if let Some(io) = inner.downcast_ref::<std::io::Error>() {
+                if io.kind() == std::io::ErrorKind::TimedOut {
+                    return StripeError::Timeout;
+                }
+            }
+            source = inner.source();
+        }
+
         StripeError::ClientError(err.to_string())
     }
 }
@@ -192,6 +204,10 @@ pub struct RequestError {
 
     /// The ID of the failed charge, if applicable.
     pub charge: Option<String>,
+
+    /// The `Request-Id` of the failed request, to quote when contacting stripe support.
+    #[serde(skip_deserializing)]
+    pub request_id: Option<String>,
 }
 
 /// The structure of the json body when an error is included in
@@ -201,6 +217,19 @@ pub struct ErrorResponse {
     pub error: RequestError,
 }
 
+/// An error from [`TestHelpersTestClock::advance_and_wait`].
+///
+/// The clock is included as it was last retrieved.
+#[derive(Debug, Error)]
+pub enum AdvanceTestClockError {
+    #[error(transparent)]
+    Stripe(#[from] StripeError),
+    #[error("test clock {} failed to advance", .0.id)]
+    Failed(Box<TestHelpersTestClock>),
+    #[error("test clock {} was still advancing when the timeout expired", .0.id)]
+    TimedOut(Box<TestHelpersTestClock>),
+}
+
 /// An error encountered when communicating with the Stripe API webhooks.
 #[derive(Debug, Error)]
 pub enum WebhookError {
@@ -215,3 +244,40 @@ pub enum WebhookError {
     #[error("error parsing event object")]
     BadParse(#[from] serde_json::Error),
 }
+
+/// The reason a webhook request was rejected by one of the framework integrations.
+#[derive(Debug, Error)]
+pub enum WebhookRejection {
+    #[error("missing webhook verifier in the app state")]
+    MissingVerifier,
+    #[error("missing Stripe-Signature header")]
+    MissingSignature,
+    #[error("request body is too large")]
+    PayloadTooLarge,
+    #[error("error reading request body: {0}")]
+    BadBody(String),
+    #[error(transparent)]
+    Webhook(#[from] WebhookError),
+}
+
+impl WebhookRejection {
+    /// The HTTP status code to respond with.
+    ///
+    /// Requests that could not have come from Stripe are a `400 Bad Request`. Verified
+    /// events that fail to deserialize, and a misconfigured secret, are a server error
+    /// so that Stripe retries them later.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            WebhookRejection::MissingSignature
+            | WebhookRejection::BadBody(_)
+            | WebhookRejection::Webhook(
+                WebhookError::BadHeader(_)
+                | WebhookError::BadSignature
+                | WebhookError::BadTimestamp(_),
+            ) => 400,
+            WebhookRejection::PayloadTooLarge => 413,
+            WebhookRejection::MissingVerifier
+            | WebhookRejection::Webhook(WebhookError::BadKey | WebhookError::BadParse(_)) => 500,
+        }
+    }
+}
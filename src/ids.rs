@@ -491,24 +491,34 @@ def_id!(
         TransferReversal(TransferReversalId),
     }
 );
+def_id!(CapabilityId: String); // N.B. A capability id is the name of the capability, such as `card_payments`
 def_id!(CardId, "card_");
 def_id!(CardTokenId, "tok_");
 def_id!(ChargeId, "ch_" | "py_"); // TODO: Understand (and then document) why "py_" is a valid charge id
 def_id!(CheckoutSessionId, "cs_");
 def_id!(CheckoutSessionItemId, "li_");
+def_id!(ClimateOrderId, "climorder_");
+def_id!(ClimateProductId: String);
+def_id!(ClimateSupplierId: String);
 def_id!(ConnectCollectionTransferId, "connct_");
 def_id!(ConnectTokenId, "ct_");
 def_id!(CouponId: String); // N.B. A coupon id can be user-provided so can be any arbitrary string
 def_id!(CreditNoteId, "cn_");
 def_id!(CreditNoteLineItemId, "cnli_");
 def_id!(CustomerBalanceTransactionId, "cbtxn_");
+def_id!(CustomerCashBalanceTransactionId, "ccsbtxn_");
 def_id!(CustomerId, "cus_");
 def_id!(DiscountId, "di_");
 def_id!(DisputeId, "dp_" | "du_" | "pdp_");
 def_id!(EphemeralKeyId, "ephkey_");
 def_id!(EventId, "evt_");
+def_id!(FinancialConnectionsAccountId, "fca_");
+def_id!(FinancialConnectionsAccountOwnerId: String);
+def_id!(FinancialConnectionsAccountOwnershipId, "fcaowns_");
 def_id!(FileId, "file_");
 def_id!(FileLinkId, "link_");
+def_id!(IdentityVerificationReportId, "vr_");
+def_id!(IdentityVerificationSessionId, "vs_");
 def_id!(InvoiceId, "in_", { _ });
 def_id!(InvoiceItemId, "ii_");
 def_id!(InvoiceLineItemIdWebhook, "il_");
@@ -559,8 +569,11 @@ def_id!(PriceId, "price_" | "plan_"); // see #470
 def_id!(ProductId: String); // N.B. A product id can be user-provided so can be any arbitrary string
 def_id!(PromotionCodeId, "promo_");
 def_id!(QuoteId, "qt_");
+def_id!(RadarEarlyFraudWarningId, "issfr_");
 def_id!(RecipientId: String); // FIXME: This doesn't seem to be documented yet
 def_id!(RefundId, "re_" | "pyr_");
+def_id!(ReportingReportRunId, "frr_");
+def_id!(ReportingReportTypeId: String); // N.B. A report type id is its name, such as `balance.summary.1`
 def_id!(ReserveTransactionId, "rtx");
 def_id!(ReviewId, "prv_");
 def_id!(ScheduledQueryRunId, "sqr_");
@@ -592,6 +605,16 @@ def_id!(
     }
 );
 def_id!(TopupId, "tu_");
+def_id!(TreasuryCreditReversalId, "credrev_");
+def_id!(TreasuryDebitReversalId, "debrev_");
+def_id!(TreasuryFinancialAccountId, "fa_");
+def_id!(TreasuryInboundTransferId, "ibt_");
+def_id!(TreasuryOutboundPaymentId, "obp_");
+def_id!(TreasuryOutboundTransferId, "obt_");
+def_id!(TreasuryReceivedCreditId, "rc_");
+def_id!(TreasuryReceivedDebitId, "rd_");
+def_id!(TreasuryTransactionEntryId, "trxne_");
+def_id!(TreasuryTransactionId, "trxn_");
 def_id!(TransferId, "tr_");
 def_id!(TransferReversalId, "trr_");
 def_id!(UsageRecordId, "mbur_");
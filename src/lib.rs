@@ -47,10 +47,16 @@
 //!                               stable across retries.
 //! - [`RequestStrategy::ExponentialBackoff`]: Make a request to the Stripe API and, if the request fails, retry
 //!                                            it up to n times with exponential backoff. The idempotency key is
-//!                                            generated automatically and is stable across retries.
+//!                                            generated automatically and is stable across retries. Use [`Backoff`]
+//!                                            for jittered delays with configurable caps.
 //!
-//! > Want to implement your own? If it is a common strategy, please consider opening a PR to add it to the library.
-//!   Otherwise, we are open to turning this into an open trait so that you can implement your own strategy.
+//! All strategies honor the `Retry-After` and `Stripe-Should-Retry` headers sent by Stripe.
+//!
+//! Want to implement your own? Any type implementing [`RetryPolicy`] can be passed to [`Client::with_strategy`].
+//! A policy is consulted before every attempt with a [`RetryContext`] describing the status, error,
+//! `Stripe-Should-Retry` header and attempt count of the previous attempt, and returns an [`Outcome`].
+//!
+//! > If it is a common strategy, please consider opening a PR to add it to the library.
 
 #![allow(clippy::map_clone, clippy::large_enum_variant)]
 #![warn(clippy::unwrap_used, clippy::missing_errors_doc, clippy::missing_panics_doc)]
@@ -67,15 +73,16 @@ mod ids;
 mod params;
 mod resources;
 
-// N.B. Ideally we would support both a blocking client and
-//      an async client without a feature flag, but the originally
-//      discussed solution requires Generic Associated Types--
-//      instead we provide an async client only a feature flag.
+// N.B. The async and blocking clients are both a `GenericClient`, and the api
+//      methods are generic over `StripeClient` (using Generic Associated Types
+//      for the response type), so both clients may be enabled at once.
 //
 // See https://github.com/wyyerd/stripe-rs/issues/24#issuecomment-451514187
-// See https://github.com/rust-lang/rust/issues/44265
 pub use crate::client::*;
-pub use crate::error::{ErrorCode, ErrorType, RequestError, StripeError, WebhookError};
+pub use crate::error::{
+    AdvanceTestClockError, ErrorCode, ErrorType, RequestError, StripeError, WebhookError,
+    WebhookRejection,
+};
 pub use crate::ids::*;
 pub use crate::params::{
     Expandable, Headers, IdOrCreate, List, Metadata, Object, RangeBounds, RangeQuery, SearchList,
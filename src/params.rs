@@ -5,13 +5,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::StripeError;
 use crate::resources::{ApiVersion, Currency};
-use crate::{
-    client::{
-        config::{err, ok},
-        Client, Response,
-    },
-    AccountId, ApplicationId,
-};
+use crate::{client::StripeClient, AccountId, ApplicationId};
 
 #[derive(Clone, Default)]
 pub struct AppInfo {
@@ -322,7 +316,7 @@ where
     ///
     /// Requires `feature = "blocking"`.
     #[cfg(feature = "blocking")]
-    pub fn get_all(self, client: &Client) -> Response<Vec<T::O>> {
+    pub fn get_all(self, client: &crate::blocking::Client) -> Result<Vec<T::O>, StripeError> {
         let mut data = Vec::with_capacity(self.page.get_total_count().unwrap_or(0) as usize);
         let mut paginator = self;
         loop {
@@ -368,7 +362,7 @@ where
     #[cfg(all(feature = "async", feature = "stream"))]
     pub fn stream(
         mut self,
-        client: &Client,
+        client: &crate::Client,
     ) -> impl futures_util::Stream<Item = Result<T::O, StripeError>> + Unpin {
         // We are going to be popping items off the end of the list, so we need to reverse it.
         self.page.get_data_mut().reverse();
@@ -379,8 +373,8 @@ where
     /// unfold a single item from the stream
     #[cfg(all(feature = "async", feature = "stream"))]
     async fn unfold_stream(
-        state: Option<(Self, Client)>,
-    ) -> Option<(Result<T::O, StripeError>, Option<(Self, Client)>)> {
+        state: Option<(Self, crate::Client)>,
+    ) -> Option<(Result<T::O, StripeError>, Option<(Self, crate::Client)>)> {
         let (mut paginator, client) = state?; // If none, we sent the last item in the last iteration
 
         if paginator.page.get_data().len() > 1 {
@@ -405,7 +399,7 @@ where
     }
 
     /// Fetch an additional page of data from stripe.
-    pub fn next(&self, client: &Client) -> Response<Self> {
+    pub fn next<C: StripeClient>(&self, client: &C) -> C::Response<Self> {
         if let Some(last) = self.page.get_data().last() {
             if self.page.get_url().starts_with("/v1/") {
                 let path = self.page.get_url().trim_start_matches("/v1/").to_string(); // the url we get back is prefixed
@@ -419,30 +413,17 @@ where
 
                 let page = client.get_query(&path, &params_next);
 
-                ListPaginator::create_paginator(page, params_next)
+                C::map(page, |page| ListPaginator { page, params: params_next })
             } else {
-                err(StripeError::UnsupportedVersion)
+                C::err(StripeError::UnsupportedVersion)
             }
         } else {
-            ok(ListPaginator {
+            C::ok(ListPaginator {
                 page: T::new(Vec::new(), self.page.get_url(), false, self.page.get_total_count()),
                 params: self.params.clone(),
             })
         }
     }
-
-    /// Pin a new future which maps the result inside the page future into
-    /// a ListPaginator
-    #[cfg(feature = "async")]
-    fn create_paginator(page: Response<T>, params: P) -> Response<Self> {
-        use futures_util::FutureExt;
-        Box::pin(page.map(|page| page.map(|page| ListPaginator { page, params })))
-    }
-
-    #[cfg(feature = "blocking")]
-    fn create_paginator(page: Response<T>, params: P) -> Response<Self> {
-        page.map(|page| ListPaginator { page, params })
-    }
 }
 
 pub type CurrencyMap<V> = HashMap<Currency, V>;
@@ -616,7 +597,7 @@ mod tests {
         use httpmock::Method::GET;
         use httpmock::MockServer;
 
-        use crate::Client;
+        use crate::blocking::Client;
         use crate::{Customer, ListCustomers};
 
         // Start a lightweight mock server.
@@ -23,6 +23,7 @@ mod core {
     pub mod payout_ext;
     pub mod placeholders;
     pub mod setup_intent_ext;
+    pub mod test_helpers_test_clock_ext;
     pub mod token_ext;
     pub mod transfer_reversal_ext;
 }
@@ -45,6 +46,7 @@ mod billing {
     pub mod customer_balance_transaction_ext;
     pub mod invoice_ext;
     pub mod line_item_ext;
+    pub mod quote_ext;
     pub mod subscription_ext;
     pub mod usage_record_ext;
 }
@@ -79,8 +81,10 @@ mod fraud {
 mod issuing {
     pub mod issuing_authorization_ext;
     pub mod issuing_card_ext;
+    pub mod issuing_cardholder_ext;
     pub mod issuing_dispute_ext;
     pub mod issuing_merchant_data;
+    pub mod issuing_token_ext;
     pub mod issuing_transaction_ext;
 }
 
@@ -90,6 +94,20 @@ mod orders {
     pub mod order_ext;
 }
 
+#[path = "resources"]
+#[cfg(feature = "treasury")]
+mod treasury {
+    pub mod treasury_credit_reversal_ext;
+    pub mod treasury_debit_reversal_ext;
+    pub mod treasury_financial_account_ext;
+    pub mod treasury_inbound_transfer_ext;
+    pub mod treasury_outbound_payment_ext;
+    pub mod treasury_outbound_transfer_ext;
+    pub mod treasury_received_credit_ext;
+    pub mod treasury_received_debit_ext;
+    pub mod treasury_transaction_ext;
+}
+
 #[path = "resources"]
 #[cfg(feature = "webhook-endpoints")]
 mod webhook_endpoints {
@@ -113,6 +131,7 @@ pub use {
         payout_ext::*,
         token_ext::*,
         setup_intent_ext::*,
+        test_helpers_test_clock_ext::*,
     },
     generated::core::{
         address::*,
@@ -123,6 +142,7 @@ pub use {
         charge::*,
         connect_account_reference::*,
         customer::*,
+        customer_cash_balance_transaction::*,
         custom_unit_amount::*,
         cash_balance::*,
         dispute::*,
@@ -169,6 +189,7 @@ pub use {
         payment_method::*,
         payment_method_card_present_networks::*,
         source::*,
+        source_transaction::*,
     },
 };
 
@@ -178,6 +199,8 @@ pub use {
     webhook_events::*,
     webhook_events::NotificationEventData,
     generated::event::*,
+    generated::event_object::*,
+    generated::typed_event::*,
 };
 
 #[rustfmt::skip]
@@ -207,6 +230,7 @@ pub use {
         customer_balance_transaction_ext::*,
         invoice_ext::*,
         line_item_ext::*,
+        quote_ext::*,
         subscription_ext::*,
         usage_record_ext::*,
     },
@@ -262,9 +286,21 @@ pub use {
         tax_calculation::*,
         tax_calculation_line_item::*,
         tax_product_resource_customer_details::*,
+        tax_settings::*,
     }
 };
 
+#[rustfmt::skip]
+#[cfg(feature = "climate")]
+pub use {
+    generated::climate::{
+        climate_order::*,
+        climate_product::*,
+        climate_supplier::*,
+        climate_supplier::ClimateRemovalsLocation,
+    },
+};
+
 #[rustfmt::skip]
 #[cfg(feature = "connect")]
 pub use {
@@ -274,8 +310,10 @@ pub use {
     generated::connect::{
         account_link::*,
         account::*,
+        account::CapabilityStatus,
         application::*,
         application_fee::*,
+        capability::*,
         connect_collection_transfer::*,
         fee_refund::*,
         login_link::*,
@@ -290,7 +328,30 @@ pub use {
 #[cfg(feature = "fraud")]
 pub use {
     fraud::review_ext::*,
-    generated::fraud::review::*
+    generated::fraud::{
+        radar_early_fraud_warning::*,
+        review::*,
+    },
+};
+
+#[rustfmt::skip]
+#[cfg(feature = "financial-connections")]
+pub use {
+    generated::financial_connections::{
+        bank_connections_resource_accountholder::*,
+        financial_connections_account::*,
+        financial_connections_account_owner::*,
+        financial_connections_account_ownership::*,
+    },
+};
+
+#[rustfmt::skip]
+#[cfg(feature = "identity")]
+pub use {
+    generated::identity::{
+        identity_verification_report::*,
+        identity_verification_session::*,
+    },
 };
 
 #[rustfmt::skip]
@@ -299,8 +360,10 @@ pub use {
     issuing::{
         issuing_authorization_ext::*,
         issuing_card_ext::*,
+        issuing_cardholder_ext::*,
         issuing_dispute_ext::*,
         issuing_merchant_data::*,
+        issuing_token_ext::*,
         issuing_transaction_ext::*,
     },
     generated::issuing::{
@@ -319,6 +382,15 @@ pub use {
     orders::order_ext::*,
 };
 
+#[rustfmt::skip]
+#[cfg(feature = "reporting")]
+pub use {
+    generated::reporting::{
+        reporting_report_run::*,
+        reporting_report_type::*,
+    },
+};
+
 #[rustfmt::skip]
 #[cfg(feature = "sigma")]
 pub use {
@@ -336,6 +408,47 @@ pub use {
     },
 };
 
+#[rustfmt::skip]
+#[cfg(feature = "treasury")]
+pub use {
+    treasury::{
+        treasury_credit_reversal_ext::*,
+        treasury_debit_reversal_ext::*,
+        treasury_financial_account_ext::*,
+        treasury_inbound_transfer_ext::*,
+        treasury_outbound_payment_ext::*,
+        treasury_outbound_transfer_ext::*,
+        treasury_received_credit_ext::*,
+        treasury_received_debit_ext::*,
+        treasury_transaction_ext::*,
+    },
+    generated::treasury::{
+        treasury_credit_reversal::*,
+        treasury_debit_reversal::*,
+        treasury_financial_account::*,
+        treasury_financial_account_features::*,
+        treasury_financial_accounts_resource_ach_toggle_settings::*,
+        treasury_financial_accounts_resource_toggle_settings::*,
+        treasury_financial_accounts_resource_toggle_settings::{
+            TreasuryFinancialAccountsResourceTogglesSettingStatusDetails,
+            TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsCode,
+            TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsResolution,
+            TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsRestriction,
+        },
+        treasury_inbound_transfer::*,
+        treasury_outbound_payment::*,
+        treasury_outbound_transfer::*,
+        treasury_received_credit::*,
+        treasury_received_debit::*,
+        treasury_shared_resource_billing_details::*,
+        treasury_shared_resource_initiating_payment_method_details_initiating_payment_method_details::*,
+        treasury_transaction::*,
+        treasury_transaction_entry::*,
+        treasury_transactions_resource_balance_impact::*,
+        treasury_transactions_resource_flow_details::*,
+    },
+};
+
 #[rustfmt::skip]
 #[cfg(feature = "webhook-endpoints")]
 pub use {
@@ -1,4 +1,4 @@
-use crate::client::{Client, Response};
+use crate::client::{BaseClient, GenericClient};
 use crate::ids::AccountId;
 use crate::resources::Balance;
 
@@ -6,7 +6,10 @@ impl Balance {
     /// Retrieves balance object by AccountId. Does not change stripe_account of the client.
     ///
     /// For more details see <https://stripe.com/docs/api/balance/balance_retrieve>.
-    pub fn retrieve(client: &Client, account_id: Option<AccountId>) -> Response<Balance> {
+    pub fn retrieve<B: BaseClient>(
+        client: &GenericClient<B>,
+        account_id: Option<AccountId>,
+    ) -> B::Response<Balance> {
         match account_id {
             Some(account_id) => client.clone().with_stripe_account(account_id).get("/balance"),
             None => client.get("/balance"),
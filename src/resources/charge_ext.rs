@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{AccountId, BankAccountId, CardId, ChargeId, SourceId, TokenId};
 use crate::params::{Object, SearchList};
 use crate::resources::{Charge, Rule};
@@ -37,18 +37,21 @@ impl Charge {
     /// Capture captures a previously created charge with capture set to false.
     ///
     /// For more details see <https://stripe.com/docs/api#charge_capture>.
-    pub fn capture(
-        client: &Client,
+    pub fn capture<C: StripeClient>(
+        client: &C,
         charge_id: &ChargeId,
         params: CaptureCharge<'_>,
-    ) -> Response<Charge> {
+    ) -> C::Response<Charge> {
         client.post_form(&format!("/charges/{}/capture", charge_id), params)
     }
 
     /// Searches for a charge.
     ///
     /// For more details see <https://stripe.com/docs/api/charges/search>.
-    pub fn search(client: &Client, params: ChargeSearchParams) -> Response<SearchList<Charge>> {
+    pub fn search<C: StripeClient>(
+        client: &C,
+        params: ChargeSearchParams,
+    ) -> C::Response<SearchList<Charge>> {
         client.get_query("/charges/search", params)
     }
 }
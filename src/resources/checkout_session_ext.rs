@@ -1,4 +1,4 @@
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::CheckoutSessionId;
 use crate::params::Expand;
 use crate::resources::CheckoutSession;
@@ -7,18 +7,21 @@ impl CheckoutSession {
     /// Retrieves a Session object.
     ///
     /// For more details see <https://stripe.com/docs/api/checkout/sessions/retrieve>.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &CheckoutSessionId,
         expand: &[&str],
-    ) -> Response<CheckoutSession> {
+    ) -> C::Response<CheckoutSession> {
         client.get_query(&format!("/checkout/sessions/{}", id), &Expand { expand })
     }
 
     /// Expires a checkout session.
     ///
     /// For more details see <https://stripe.com/docs/api/checkout/sessions/expire>.
-    pub fn expire(client: &Client, id: &CheckoutSessionId) -> Response<CheckoutSession> {
+    pub fn expire<C: StripeClient>(
+        client: &C,
+        id: &CheckoutSessionId,
+    ) -> C::Response<CheckoutSession> {
         client.post(&format!("/checkout/sessions/{}/expire", id))
     }
 }
@@ -1,4 +1,4 @@
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::CreditNoteId;
 use crate::resources::CreditNote;
 
@@ -6,7 +6,7 @@ impl CreditNote {
     /// Marks a credit note as void.
     ///
     /// You can only void a credit note if the associated invoice is open.
-    pub fn void(client: &Client, id: &CreditNoteId) -> Response<CreditNote> {
+    pub fn void<C: StripeClient>(client: &C, id: &CreditNoteId) -> C::Response<CreditNote> {
         client.post(&format!("/credit_notes/{}/void", id))
     }
 }
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerBalanceTransactionId, CustomerId};
 use crate::params::{Expand, List, Metadata, Paginable};
 use crate::resources::{Currency, Customer, CustomerBalanceTransaction};
@@ -92,30 +92,30 @@ pub struct UpdateCustomerBalanceTransaction<'a> {
 
 impl Customer {
     /// List all of a customer's balance transactions.
-    pub fn list_balance_transactions(
-        client: &Client,
+    pub fn list_balance_transactions<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         params: ListCustomerBalanceTransactions<'_>,
-    ) -> Response<List<CustomerBalanceTransaction>> {
+    ) -> C::Response<List<CustomerBalanceTransaction>> {
         client.get_query(&format!("/customers/{}/balance_transactions", customer_id), &params)
     }
 
     /// Create a new customer balance transaction.
-    pub fn create_balance_transaction(
-        client: &Client,
+    pub fn create_balance_transaction<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         params: CreateCustomerBalanceTransaction<'_>,
-    ) -> Response<CustomerBalanceTransaction> {
+    ) -> C::Response<CustomerBalanceTransaction> {
         client.post_form(&format!("/customers/{}/balance_transactions", customer_id), &params)
     }
 
     /// Retrieve a customer balance transaction.
-    pub fn retrieve_balance_transaction(
-        client: &Client,
+    pub fn retrieve_balance_transaction<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         id: &CustomerBalanceTransactionId,
         expand: &[&str],
-    ) -> Response<CustomerBalanceTransaction> {
+    ) -> C::Response<CustomerBalanceTransaction> {
         client.get_query(
             &format!("/customers/{}/balance_transactions/{}", customer_id, id),
             &Expand { expand },
@@ -125,12 +125,12 @@ impl Customer {
     /// Update a customer balance transaction.
     ///
     /// Only the description and metadata fields can be updated.
-    pub fn update_balance_transaction(
-        client: &Client,
+    pub fn update_balance_transaction<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         id: &CustomerBalanceTransactionId,
         params: UpdateCustomerBalanceTransaction<'_>,
-    ) -> Response<CustomerBalanceTransaction> {
+    ) -> C::Response<CustomerBalanceTransaction> {
         client
             .post_form(&format!("/customers/{}/balance_transactions/{}", customer_id, id), &params)
     }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{BankAccountId, CardId, CustomerId, PaymentSourceId};
 use crate::params::{Deleted, Expand, List, SearchList};
 use crate::resources::{
@@ -90,11 +90,11 @@ impl Customer {
     /// Attaches a source to a customer, does not change default Source for the Customer
     ///
     /// For more details see <https://stripe.com/docs/api#attach_source>.
-    pub fn attach_source(
-        client: &Client,
+    pub fn attach_source<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         source: PaymentSourceParams,
-    ) -> Response<PaymentSource> {
+    ) -> C::Response<PaymentSource> {
         #[derive(Serialize)]
         struct AttachSource {
             source: PaymentSourceParams,
@@ -106,32 +106,32 @@ impl Customer {
     /// Detaches a source from a customer
     ///
     /// For more details see <https://stripe.com/docs/api#detach_source>.
-    pub fn detach_source(
-        client: &Client,
+    pub fn detach_source<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         source_id: &PaymentSourceId,
-    ) -> Response<DetachedSource> {
+    ) -> C::Response<DetachedSource> {
         client.delete(&format!("/customers/{}/sources/{}", customer_id, source_id))
     }
 
     /// Retrieves a Card, BankAccount, or Source for a Customer
-    pub fn retrieve_source(
-        client: &Client,
+    pub fn retrieve_source<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         source_id: &PaymentSourceId,
-    ) -> Response<PaymentSource> {
+    ) -> C::Response<PaymentSource> {
         client.get(&format!("/customers/{}/sources/{}", customer_id, source_id))
     }
 
     /// Verifies a Bank Account for a Customer.
     ///
     /// For more details see <https://stripe.com/docs/api/customer_bank_accounts/verify>.
-    pub fn verify_bank_account(
-        client: &Client,
+    pub fn verify_bank_account<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         bank_account_id: &BankAccountId,
         params: VerifyBankAccount<'_>,
-    ) -> Response<BankAccount> {
+    ) -> C::Response<BankAccount> {
         client.post_form(
             &format!("/customers/{}/sources/{}/verify", customer_id, bank_account_id),
             params,
@@ -141,18 +141,21 @@ impl Customer {
     ///Returns a list of PaymentMethods for a given Customer
     ///
     ///For more details see <https://stripe.com/docs/api/payment_methods/customer_list>
-    pub fn retrieve_payment_methods(
-        client: &Client,
+    pub fn retrieve_payment_methods<C: StripeClient>(
+        client: &C,
         customer_id: &CustomerId,
         params: CustomerPaymentMethodRetrieval<'_>,
-    ) -> Response<List<PaymentMethod>> {
+    ) -> C::Response<List<PaymentMethod>> {
         client.get_query(&format!("/customers/{}/payment_methods", customer_id), &params)
     }
 
     /// Searches for a customer.
     ///
     /// For more details see <https://stripe.com/docs/api/customers/search>.
-    pub fn search(client: &Client, params: CustomerSearchParams) -> Response<SearchList<Customer>> {
+    pub fn search<C: StripeClient>(
+        client: &C,
+        params: CustomerSearchParams,
+    ) -> C::Response<SearchList<Customer>> {
         client.get_query("/customers/search", params)
     }
 }
@@ -22,6 +22,7 @@ pub mod core {
     pub mod connect_account_reference;
     pub mod custom_unit_amount;
     pub mod customer;
+    pub mod customer_cash_balance_transaction;
     pub mod dispute;
     pub mod ephemeral_key;
     pub mod file;
@@ -61,6 +62,7 @@ pub mod payment {
     pub mod payment_method;
     pub mod payment_method_card_present_networks;
     pub mod source;
+    pub mod source_transaction;
 }
 
 #[path = "generated"]
@@ -77,6 +79,7 @@ pub mod tax_calculation {
     pub mod tax_calculation;
     pub mod tax_calculation_line_item;
     pub mod tax_product_resource_customer_details;
+    pub mod tax_settings;
 }
 
 #[path = "generated"]
@@ -121,6 +124,7 @@ pub mod connect {
     pub mod account_link;
     pub mod application;
     pub mod application_fee;
+    pub mod capability;
     pub mod connect_collection_transfer;
     pub mod fee_refund;
     pub mod login_link;
@@ -133,9 +137,34 @@ pub mod connect {
 #[path = "generated"]
 #[cfg(feature = "fraud")]
 pub mod fraud {
+    pub mod radar_early_fraud_warning;
     pub mod review;
 }
 
+#[path = "generated"]
+#[cfg(feature = "climate")]
+pub mod climate {
+    pub mod climate_order;
+    pub mod climate_product;
+    pub mod climate_supplier;
+}
+
+#[path = "generated"]
+#[cfg(feature = "financial-connections")]
+pub mod financial_connections {
+    pub mod bank_connections_resource_accountholder;
+    pub mod financial_connections_account;
+    pub mod financial_connections_account_owner;
+    pub mod financial_connections_account_ownership;
+}
+
+#[path = "generated"]
+#[cfg(feature = "identity")]
+pub mod identity {
+    pub mod identity_verification_report;
+    pub mod identity_verification_session;
+}
+
 #[path = "generated"]
 #[cfg(feature = "issuing")]
 pub mod issuing {
@@ -147,6 +176,13 @@ pub mod issuing {
     pub mod issuing_transaction;
 }
 
+#[path = "generated"]
+#[cfg(feature = "reporting")]
+pub mod reporting {
+    pub mod reporting_report_run;
+    pub mod reporting_report_type;
+}
+
 #[cfg(feature = "sigma")]
 pub mod scheduled_query_run;
 
@@ -159,9 +195,37 @@ pub mod terminal {
     pub mod terminal_reader;
 }
 
+#[path = "generated"]
+#[cfg(feature = "treasury")]
+pub mod treasury {
+    pub mod treasury_credit_reversal;
+    pub mod treasury_debit_reversal;
+    pub mod treasury_financial_account;
+    pub mod treasury_financial_account_features;
+    pub mod treasury_financial_accounts_resource_ach_toggle_settings;
+    pub mod treasury_financial_accounts_resource_toggle_settings;
+    pub mod treasury_inbound_transfer;
+    pub mod treasury_outbound_payment;
+    pub mod treasury_outbound_transfer;
+    pub mod treasury_received_credit;
+    pub mod treasury_received_debit;
+    pub mod treasury_shared_resource_billing_details;
+    pub mod treasury_shared_resource_initiating_payment_method_details_initiating_payment_method_details;
+    pub mod treasury_transaction;
+    pub mod treasury_transaction_entry;
+    pub mod treasury_transactions_resource_balance_impact;
+    pub mod treasury_transactions_resource_flow_details;
+}
+
 #[cfg(feature = "events")]
 pub mod event;
 
+#[cfg(feature = "events")]
+pub mod event_object;
+
+#[cfg(feature = "events")]
+pub mod typed_event;
+
 #[path = "generated"]
 #[cfg(feature = "webhook-endpoints")]
 pub mod webhook_endpoints {
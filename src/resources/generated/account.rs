@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::AccountId;
 use crate::params::{
     Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
@@ -121,7 +121,10 @@ impl Account {
     /// Returns a list of accounts connected to your platform via [Connect](https://stripe.com/docs/connect).
     ///
     /// If you’re not a platform, the list is empty.
-    pub fn list(client: &Client, params: &ListAccounts<'_>) -> Response<List<Account>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListAccounts<'_>,
+    ) -> C::Response<List<Account>> {
         client.get_query("/accounts", &params)
     }
 
@@ -132,12 +135,16 @@ impl Account {
     /// creating the account.
     ///
     /// Connect Onboarding won’t ask for the prefilled information during account onboarding. You can prefill any information on the account.
-    pub fn create(client: &Client, params: CreateAccount<'_>) -> Response<Account> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateAccount<'_>) -> C::Response<Account> {
         client.post_form("/accounts", &params)
     }
 
     /// Retrieves the details of an account.
-    pub fn retrieve(client: &Client, id: &AccountId, expand: &[&str]) -> Response<Account> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &AccountId,
+        expand: &[&str],
+    ) -> C::Response<Account> {
         client.get_query(&format!("/accounts/{}", id), &Expand { expand })
     }
 
@@ -147,7 +154,11 @@ impl Account {
     /// For other accounts, you can update all information until that account has started to go through Connect Onboarding.
     /// Once you create an [Account Link](https://stripe.com/docs/api/account_links) or [Account Session](https://stripe.com/docs/api/account_sessions), some properties can only be changed or updated for Custom accounts.  To update your own account, use the [Dashboard](https://dashboard.stripe.com/settings/account).
     /// Refer to our [Connect](https://stripe.com/docs/connect/updating-accounts) documentation to learn more about updating accounts.
-    pub fn update(client: &Client, id: &AccountId, params: UpdateAccount<'_>) -> Response<Account> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &AccountId,
+        params: UpdateAccount<'_>,
+    ) -> C::Response<Account> {
         client.post_form(&format!("/accounts/{}", id), &params)
     }
 
@@ -157,7 +168,7 @@ impl Account {
     ///
     /// Standard accounts created using live-mode keys cannot be deleted.
     /// Custom or Express accounts created using live-mode keys can only be deleted once all balances are zero.  If you want to delete your own account, use the [account information tab in your account settings](https://dashboard.stripe.com/settings/account) instead.
-    pub fn delete(client: &Client, id: &AccountId) -> Response<Deleted<AccountId>> {
+    pub fn delete<C: StripeClient>(client: &C, id: &AccountId) -> C::Response<Deleted<AccountId>> {
         client.delete(&format!("/accounts/{}", id))
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::AccountId;
 use crate::params::{Expand, Object, Timestamp};
 
@@ -27,7 +27,10 @@ pub struct AccountLink {
 
 impl AccountLink {
     /// Creates an AccountLink object that includes a single-use Stripe URL that the platform can redirect their user to in order to take them through the Connect Onboarding flow.
-    pub fn create(client: &Client, params: CreateAccountLink<'_>) -> Response<AccountLink> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateAccountLink<'_>,
+    ) -> C::Response<AccountLink> {
         client.post_form("/account_links", &params)
     }
 }
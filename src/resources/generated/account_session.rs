@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{AccountId};
 use crate::params::{Expand, Object, Timestamp};
 use serde::{Deserialize, Serialize};
@@ -35,7 +35,10 @@ pub struct AccountSession {
 impl AccountSession {
 
     /// Creates a AccountSession object that includes a single-use token that the platform can use on their front-end to grant client-side API access.
-    pub fn create(client: &Client, params: CreateAccountSession<'_>) -> Response<AccountSession> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateAccountSession<'_>,
+    ) -> C::Response<AccountSession> {
         client.post_form("/account_sessions", &params)
     }
 }
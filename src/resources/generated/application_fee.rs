@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{ApplicationFeeId, ChargeId};
 use crate::params::{Expand, Expandable, List, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{
@@ -66,21 +66,21 @@ impl ApplicationFee {
     /// Returns a list of application fees you’ve previously collected.
     ///
     /// The application fees are returned in sorted order, with the most recent fees appearing first.
-    pub fn list(
-        client: &Client,
+    pub fn list<C: StripeClient>(
+        client: &C,
         params: &ListApplicationFees<'_>,
-    ) -> Response<List<ApplicationFee>> {
+    ) -> C::Response<List<ApplicationFee>> {
         client.get_query("/application_fees", &params)
     }
 
     /// Retrieves the details of an application fee that your account has collected.
     ///
     /// The same information is returned when refunding the application fee.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &ApplicationFeeId,
         expand: &[&str],
-    ) -> Response<ApplicationFee> {
+    ) -> C::Response<ApplicationFee> {
         client.get_query(&format!("/application_fees/{}", id), &Expand { expand })
     }
 }
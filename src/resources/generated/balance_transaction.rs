@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{BalanceTransactionId, PayoutId, SourceId};
 use crate::params::{Expand, Expandable, List, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{
@@ -84,21 +84,21 @@ impl BalanceTransaction {
     /// Returns a list of transactions that have contributed to the Stripe account balance (e.g., charges, transfers, and so forth).
     ///
     /// The transactions are returned in sorted order, with the most recent transactions appearing first.  Note that this endpoint was previously called “Balance history” and used the path `/v1/balance/history`.
-    pub fn list(
-        client: &Client,
+    pub fn list<C: StripeClient>(
+        client: &C,
         params: &ListBalanceTransactions<'_>,
-    ) -> Response<List<BalanceTransaction>> {
+    ) -> C::Response<List<BalanceTransaction>> {
         client.get_query("/balance_transactions", &params)
     }
 
     /// Retrieves the balance transaction with the given ID.
     ///
     /// Note that this endpoint previously used the path `/v1/balance/history/:id`.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &BalanceTransactionId,
         expand: &[&str],
-    ) -> Response<BalanceTransaction> {
+    ) -> C::Response<BalanceTransaction> {
         client.get_query(&format!("/balance_transactions/{}", id), &Expand { expand })
     }
 }
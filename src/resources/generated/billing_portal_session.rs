@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{BillingPortalSessionId, CustomerId};
 use crate::params::{Expand, Expandable, Object, Timestamp};
 use crate::resources::BillingPortalConfiguration;
@@ -55,10 +55,10 @@ pub struct BillingPortalSession {
 
 impl BillingPortalSession {
     /// Creates a session of the customer portal.
-    pub fn create(
-        client: &Client,
+    pub fn create<C: StripeClient>(
+        client: &C,
         params: CreateBillingPortalSession<'_>,
-    ) -> Response<BillingPortalSession> {
+    ) -> C::Response<BillingPortalSession> {
         client.post_form("/billing_portal/sessions", &params)
     }
 }
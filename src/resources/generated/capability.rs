@@ -2,11 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{CapabilityId};
-use crate::params::{Expandable, Object, Timestamp};
-use crate::resources::{Account};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::CapabilityId;
+use crate::params::{Expandable, Object, Timestamp};
+use crate::resources::Account;
+
 /// The resource representing a Stripe "AccountCapability".
 ///
 /// For more details see <https://stripe.com/docs/api/capabilities/object>
@@ -50,7 +51,6 @@ impl Object for Capability {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AccountCapabilityFutureRequirements {
-
     /// Fields that are due and can be satisfied by providing the corresponding alternative fields instead.
     pub alternatives: Option<Vec<AccountRequirementsAlternative>>,
 
@@ -90,7 +90,6 @@ pub struct AccountCapabilityFutureRequirements {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AccountCapabilityRequirements {
-
     /// Fields that are due and can be satisfied by providing the corresponding alternative fields instead.
     pub alternatives: Option<Vec<AccountRequirementsAlternative>>,
 
@@ -132,7 +131,6 @@ pub struct AccountCapabilityRequirements {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AccountRequirementsAlternative {
-
     /// Fields that can be provided to satisfy all fields in `original_fields_due`.
     pub alternative_fields_due: Vec<String>,
 
@@ -142,7 +140,6 @@ pub struct AccountRequirementsAlternative {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AccountRequirementsError {
-
     /// The code for the type of error.
     pub code: AccountRequirementsErrorCode,
 
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{
     CheckoutSessionId, CustomerId, PaymentIntentId, PaymentLinkId, PaymentMethodConfigurationId,
     SubscriptionId,
@@ -231,15 +231,18 @@ pub struct CheckoutSession {
 
 impl CheckoutSession {
     /// Returns a list of Checkout Sessions.
-    pub fn list(
-        client: &Client,
+    pub fn list<C: StripeClient>(
+        client: &C,
         params: &ListCheckoutSessions<'_>,
-    ) -> Response<List<CheckoutSession>> {
+    ) -> C::Response<List<CheckoutSession>> {
         client.get_query("/checkout/sessions", &params)
     }
 
     /// Creates a Session object.
-    pub fn create(client: &Client, params: CreateCheckoutSession<'_>) -> Response<CheckoutSession> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateCheckoutSession<'_>,
+    ) -> C::Response<CheckoutSession> {
         client.post_form("/checkout/sessions", &params)
     }
 }
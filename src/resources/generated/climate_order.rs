@@ -2,10 +2,11 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{ClimateOrderId};
+use serde::{Deserialize, Serialize};
+
+use crate::ids::ClimateOrderId;
 use crate::params::{Expandable, Metadata, Object, Timestamp};
 use crate::resources::{ClimateProduct, ClimateSupplier, Currency};
-use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "ClimateRemovalsOrders".
 ///
@@ -102,14 +103,12 @@ impl Object for ClimateOrder {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ClimateRemovalsBeneficiary {
-
     /// Publicly displayable name for the end beneficiary of carbon removal.
     pub public_name: String,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ClimateRemovalsOrderDeliveries {
-
     /// Time at which the delivery occurred.
     ///
     /// Measured in seconds since the Unix epoch.
@@ -129,7 +128,6 @@ pub struct ClimateRemovalsOrderDeliveries {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ClimateRemovalsLocation {
-
     /// The city where the supplier is located.
     pub city: Option<String>,
 
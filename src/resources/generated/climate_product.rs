@@ -2,11 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{ClimateProductId};
-use crate::params::{Object, Timestamp};
-use crate::resources::{ClimateSupplier};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::ClimateProductId;
+use crate::params::{Object, Timestamp};
+use crate::resources::ClimateSupplier;
+
 /// The resource representing a Stripe "ClimateRemovalsProducts".
 ///
 /// For more details see <https://stripe.com/docs/api/climate/product/object>
@@ -54,7 +55,6 @@ impl Object for ClimateProduct {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ClimateRemovalsProductsPrice {
-
     /// Fees for one metric ton of carbon removal in the currency's smallest unit.
     pub amount_fees: i64,
 
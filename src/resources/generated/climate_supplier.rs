@@ -2,10 +2,11 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{ClimateSupplierId};
-use crate::params::{Object};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::ClimateSupplierId;
+use crate::params::Object;
+
 /// The resource representing a Stripe "ClimateRemovalsSuppliers".
 ///
 /// For more details see <https://stripe.com/docs/api/climate/supplier/object>
@@ -42,7 +43,6 @@ impl Object for ClimateSupplier {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ClimateRemovalsLocation {
-
     /// The city where the supplier is located.
     pub city: Option<String>,
 
@@ -71,7 +71,9 @@ pub enum ClimateSupplierRemovalPathway {
 impl ClimateSupplierRemovalPathway {
     pub fn as_str(self) -> &'static str {
         match self {
-            ClimateSupplierRemovalPathway::BiomassCarbonRemovalAndStorage => "biomass_carbon_removal_and_storage",
+            ClimateSupplierRemovalPathway::BiomassCarbonRemovalAndStorage => {
+                "biomass_carbon_removal_and_storage"
+            }
             ClimateSupplierRemovalPathway::DirectAirCapture => "direct_air_capture",
             ClimateSupplierRemovalPathway::EnhancedWeathering => "enhanced_weathering",
         }
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CountrySpecId};
 use crate::params::{Expand, List, Object, Paginable};
 use crate::resources::{Currency};
@@ -44,13 +44,20 @@ pub struct CountrySpec {
 impl CountrySpec {
 
     /// Lists all Country Spec objects available in the API.
-pub fn list(client: &Client, params: &ListCountrySpecs<'_>) -> Response<List<CountrySpec>> {
+pub fn list<C: StripeClient>(
+    client: &C,
+    params: &ListCountrySpecs<'_>,
+) -> C::Response<List<CountrySpec>> {
    client.get_query("/country_specs", &params)
 }
 
 
     /// Returns a Country Spec for a given Country code.
-    pub fn retrieve(client: &Client, id: &CountrySpecId, expand: &[&str]) -> Response<CountrySpec> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &CountrySpecId,
+        expand: &[&str],
+    ) -> C::Response<CountrySpec> {
         client.get_query(&format!("/country_specs/{}", id), &Expand { expand })
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CouponId, CustomerId, PaymentMethodId, PaymentSourceId, PromotionCodeId};
 use crate::params::{
     Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
@@ -164,17 +164,27 @@ impl Customer {
     /// Returns a list of your customers.
     ///
     /// The customers are returned sorted by creation date, with the most recent customers appearing first.
-    pub fn list(client: &Client, params: &ListCustomers<'_>) -> Response<List<Customer>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListCustomers<'_>,
+    ) -> C::Response<List<Customer>> {
         client.get_query("/customers", &params)
     }
 
     /// Creates a new customer object.
-    pub fn create(client: &Client, params: CreateCustomer<'_>) -> Response<Customer> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateCustomer<'_>,
+    ) -> C::Response<Customer> {
         client.post_form("/customers", &params)
     }
 
     /// Retrieves a Customer object.
-    pub fn retrieve(client: &Client, id: &CustomerId, expand: &[&str]) -> Response<Customer> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &CustomerId,
+        expand: &[&str],
+    ) -> C::Response<Customer> {
         client.get_query(&format!("/customers/{}", id), &Expand { expand })
     }
 
@@ -185,11 +195,11 @@ impl Customer {
     /// When you update a customer to a new valid card source by passing the **source** parameter: for each of the customer’s current subscriptions, if the subscription bills automatically and is in the `past_due` state, then the latest open invoice for the subscription with automatic collection enabled will be retried.
     /// This retry will not count as an automatic retry, and will not affect the next regularly scheduled payment for the invoice.
     /// Changing the **default_source** for a customer will not trigger this behavior.  This request accepts mostly the same arguments as the customer creation call.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &CustomerId,
         params: UpdateCustomer<'_>,
-    ) -> Response<Customer> {
+    ) -> C::Response<Customer> {
         client.post_form(&format!("/customers/{}", id), &params)
     }
 
@@ -197,7 +207,10 @@ impl Customer {
     ///
     /// It cannot be undone.
     /// Also immediately cancels any active subscriptions on the customer.
-    pub fn delete(client: &Client, id: &CustomerId) -> Response<Deleted<CustomerId>> {
+    pub fn delete<C: StripeClient>(
+        client: &C,
+        id: &CustomerId,
+    ) -> C::Response<Deleted<CustomerId>> {
         client.delete(&format!("/customers/{}", id))
     }
 }
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerId};
 use crate::params::{Expand, Expandable, Object, Timestamp};
 use crate::resources::{Customer};
@@ -42,7 +42,10 @@ pub struct CustomerSession {
 impl CustomerSession {
 
     /// Creates a customer session object that includes a single-use client secret that you can use on your front-end to grant client-side API access for certain customer resources.
-    pub fn create(client: &Client, params: CreateCustomerSession<'_>) -> Response<CustomerSession> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateCustomerSession<'_>,
+    ) -> C::Response<CustomerSession> {
         client.post_form("/customer_sessions", &params)
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{ChargeId, DisputeId, PaymentIntentId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{BalanceTransaction, Charge, Currency, File, PaymentIntent};
@@ -79,12 +79,19 @@ pub struct Dispute {
 
 impl Dispute {
     /// Returns a list of your disputes.
-    pub fn list(client: &Client, params: &ListDisputes<'_>) -> Response<List<Dispute>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListDisputes<'_>,
+    ) -> C::Response<List<Dispute>> {
         client.get_query("/disputes", &params)
     }
 
     /// Retrieves the dispute with the given ID.
-    pub fn retrieve(client: &Client, id: &DisputeId, expand: &[&str]) -> Response<Dispute> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &DisputeId,
+        expand: &[&str],
+    ) -> C::Response<Dispute> {
         client.get_query(&format!("/disputes/{}", id), &Expand { expand })
     }
 }
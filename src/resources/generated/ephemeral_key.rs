@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerId, EphemeralKeyId, IssuingCardId};
 use crate::params::{Deleted, Expand, Object, Timestamp};
 
@@ -36,12 +36,18 @@ pub struct EphemeralKey {
 
 impl EphemeralKey {
     /// Creates a short-lived API key for a given resource.
-    pub fn create(client: &Client, params: CreateEphemeralKey<'_>) -> Response<EphemeralKey> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateEphemeralKey<'_>,
+    ) -> C::Response<EphemeralKey> {
         client.post_form("/ephemeral_keys", &params)
     }
 
     /// Invalidates a short-lived API key for a given resource.
-    pub fn delete(client: &Client, id: &EphemeralKeyId) -> Response<Deleted<EphemeralKeyId>> {
+    pub fn delete<C: StripeClient>(
+        client: &C,
+        id: &EphemeralKeyId,
+    ) -> C::Response<Deleted<EphemeralKeyId>> {
         client.delete(&format!("/ephemeral_keys/{}", id))
     }
 }
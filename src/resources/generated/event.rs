@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::EventId;
 use crate::params::{Expand, List, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{EventType, NotificationEventData};
@@ -51,14 +51,18 @@ impl Event {
     /// List events, going back up to 30 days.
     ///
     /// Each event data is rendered according to Stripe API version at its creation time, specified in [event object](https://stripe.com/docs/api/events/object) `api_version` attribute (not according to your current Stripe API version or `Stripe-Version` header).
-    pub fn list(client: &Client, params: &ListEvents<'_>) -> Response<List<Event>> {
+    pub fn list<C: StripeClient>(client: &C, params: &ListEvents<'_>) -> C::Response<List<Event>> {
         client.get_query("/events", &params)
     }
 
     /// Retrieves the details of an event.
     ///
     /// Supply the unique identifier of the event, which you might have received in a webhook.
-    pub fn retrieve(client: &Client, id: &EventId, expand: &[&str]) -> Response<Event> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &EventId,
+        expand: &[&str],
+    ) -> C::Response<Event> {
         client.get_query(&format!("/events/{}", id), &Expand { expand })
     }
 }
@@ -0,0 +1,189 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::resources::*;
+
+/// The object that an [`Event`](crate::Event) is about.
+///
+/// Objects of a type which this crate does not model, such as those added in a newer api version,
+/// are kept as [`EventObject::Unknown`], so that the event may still be routed or stored.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self", tag = "object", rename_all = "snake_case")]
+pub enum EventObject {
+    Account(Account),
+    Application(Application),
+    ApplicationFee(ApplicationFee),
+    Balance(Balance),
+    BankAccount(BankAccount),
+    #[serde(rename = "billing_portal.configuration")]
+    BillingPortalConfiguration(BillingPortalConfiguration),
+    #[serde(rename = "billing_portal.session")]
+    BillingPortalSession(BillingPortalSession),
+    Capability(Capability),
+    Card(Card),
+    CashBalance(CashBalance),
+    Charge(Charge),
+    #[serde(rename = "checkout.session")]
+    CheckoutSession(CheckoutSession),
+    #[serde(rename = "climate.order")]
+    ClimateOrder(ClimateOrder),
+    #[serde(rename = "climate.product")]
+    ClimateProduct(ClimateProduct),
+    Coupon(Coupon),
+    CreditNote(CreditNote),
+    Customer(Customer),
+    CustomerCashBalanceTransaction(CustomerCashBalanceTransaction),
+    Discount(Discount),
+    Dispute(Dispute),
+    #[serde(rename = "fee_refund")]
+    ApplicationFeeRefund(ApplicationFeeRefund),
+    File(File),
+    #[serde(rename = "financial_connections.account")]
+    FinancialConnectionsAccount(FinancialConnectionsAccount),
+    #[serde(rename = "identity.verification_session")]
+    IdentityVerificationSession(IdentityVerificationSession),
+    Invoice(Invoice),
+    #[serde(rename = "invoiceitem")]
+    InvoiceItem(InvoiceItem),
+    #[serde(rename = "issuing.authorization")]
+    IssuingAuthorization(IssuingAuthorization),
+    #[serde(rename = "issuing.card")]
+    IssuingCard(IssuingCard),
+    #[serde(rename = "issuing.cardholder")]
+    IssuingCardholder(IssuingCardholder),
+    #[serde(rename = "issuing.dispute")]
+    IssuingDispute(IssuingDispute),
+    #[serde(rename = "issuing.token")]
+    IssuingToken(IssuingToken),
+    #[serde(rename = "issuing.transaction")]
+    IssuingTransaction(IssuingTransaction),
+    Mandate(Mandate),
+    PaymentIntent(PaymentIntent),
+    PaymentLink(PaymentLink),
+    PaymentMethod(PaymentMethod),
+    Payout(Payout),
+    Person(Person),
+    Plan(Plan),
+    Price(Price),
+    Product(Product),
+    PromotionCode(PromotionCode),
+    Quote(Quote),
+    #[serde(rename = "radar.early_fraud_warning")]
+    RadarEarlyFraudWarning(RadarEarlyFraudWarning),
+    Refund(Refund),
+    #[serde(rename = "reporting.report_run")]
+    ReportingReportRun(ReportingReportRun),
+    #[serde(rename = "reporting.report_type")]
+    ReportingReportType(ReportingReportType),
+    Review(Review),
+    ScheduledQueryRun(ScheduledQueryRun),
+    SetupIntent(SetupIntent),
+    Source(Source),
+    SourceTransaction(SourceTransaction),
+    Subscription(Subscription),
+    SubscriptionSchedule(SubscriptionSchedule),
+    #[serde(rename = "tax.settings")]
+    TaxSettings(TaxSettings),
+    TaxId(TaxId),
+    TaxRate(TaxRate),
+    #[serde(rename = "terminal.reader")]
+    TerminalReader(TerminalReader),
+    #[serde(rename = "test_helpers.test_clock")]
+    TestHelpersTestClock(TestHelpersTestClock),
+    Topup(Topup),
+    Transfer(Transfer),
+    #[serde(rename = "treasury.credit_reversal")]
+    TreasuryCreditReversal(TreasuryCreditReversal),
+    #[serde(rename = "treasury.debit_reversal")]
+    TreasuryDebitReversal(TreasuryDebitReversal),
+    #[serde(rename = "treasury.financial_account")]
+    TreasuryFinancialAccount(TreasuryFinancialAccount),
+    #[serde(rename = "treasury.inbound_transfer")]
+    TreasuryInboundTransfer(TreasuryInboundTransfer),
+    #[serde(rename = "treasury.outbound_payment")]
+    TreasuryOutboundPayment(TreasuryOutboundPayment),
+    #[serde(rename = "treasury.outbound_transfer")]
+    TreasuryOutboundTransfer(TreasuryOutboundTransfer),
+    #[serde(rename = "treasury.received_credit")]
+    TreasuryReceivedCredit(TreasuryReceivedCredit),
+    #[serde(rename = "treasury.received_debit")]
+    TreasuryReceivedDebit(TreasuryReceivedDebit),
+    /// The raw json of an object of a type which this crate does not model.
+    #[serde(skip)]
+    Unknown(Value),
+}
+
+impl EventObject {
+    /// The `object` of every type with a variant, other than [`EventObject::Unknown`].
+    pub(crate) const OBJECT_TYPES: &'static [&'static str] = &[
+        "account",
+        "application",
+        "application_fee",
+        "balance",
+        "bank_account",
+        "billing_portal.configuration",
+        "billing_portal.session",
+        "capability",
+        "card",
+        "cash_balance",
+        "charge",
+        "checkout.session",
+        "climate.order",
+        "climate.product",
+        "coupon",
+        "credit_note",
+        "customer",
+        "customer_cash_balance_transaction",
+        "discount",
+        "dispute",
+        "fee_refund",
+        "file",
+        "financial_connections.account",
+        "identity.verification_session",
+        "invoice",
+        "invoiceitem",
+        "issuing.authorization",
+        "issuing.card",
+        "issuing.cardholder",
+        "issuing.dispute",
+        "issuing.token",
+        "issuing.transaction",
+        "mandate",
+        "payment_intent",
+        "payment_link",
+        "payment_method",
+        "payout",
+        "person",
+        "plan",
+        "price",
+        "product",
+        "promotion_code",
+        "quote",
+        "radar.early_fraud_warning",
+        "refund",
+        "reporting.report_run",
+        "reporting.report_type",
+        "review",
+        "scheduled_query_run",
+        "setup_intent",
+        "source",
+        "source_transaction",
+        "subscription",
+        "subscription_schedule",
+        "tax.settings",
+        "tax_id",
+        "tax_rate",
+        "terminal.reader",
+        "test_helpers.test_clock",
+        "topup",
+        "transfer",
+        "treasury.credit_reversal",
+        "treasury.debit_reversal",
+        "treasury.financial_account",
+        "treasury.inbound_transfer",
+        "treasury.outbound_payment",
+        "treasury.outbound_transfer",
+        "treasury.received_credit",
+        "treasury.received_debit",
+    ];
+}
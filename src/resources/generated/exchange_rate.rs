@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{ExchangeRateId};
 use crate::params::{Expand, List, Object, Paginable};
 use serde::{Deserialize, Serialize};
@@ -24,13 +24,20 @@ impl ExchangeRate {
     /// Returns a list of objects that contain the rates at which foreign currencies are converted to one another.
     ///
     /// Only shows the currencies for which Stripe supports.
-pub fn list(client: &Client, params: &ListExchangeRates<'_>) -> Response<List<ExchangeRate>> {
+pub fn list<C: StripeClient>(
+    client: &C,
+    params: &ListExchangeRates<'_>,
+) -> C::Response<List<ExchangeRate>> {
    client.get_query("/exchange_rates", &params)
 }
 
 
     /// Retrieves the exchange rates from the given currency to every supported currency.
-    pub fn retrieve(client: &Client, id: &ExchangeRateId, expand: &[&str]) -> Response<ExchangeRate> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &ExchangeRateId,
+        expand: &[&str],
+    ) -> C::Response<ExchangeRate> {
         client.get_query(&format!("/exchange_rates/{}", id), &Expand { expand })
     }
 }
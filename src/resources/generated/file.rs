@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::FileId;
 use crate::params::{Expand, List, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::FileLink;
@@ -53,7 +53,7 @@ impl File {
     /// Returns a list of the files that your account has access to.
     ///
     /// Stripe sorts and returns the files by their creation dates, placing the most recently created files at the top.
-    pub fn list(client: &Client, params: &ListFiles<'_>) -> Response<List<File>> {
+    pub fn list<C: StripeClient>(client: &C, params: &ListFiles<'_>) -> C::Response<List<File>> {
         client.get_query("/files", &params)
     }
 
@@ -61,7 +61,11 @@ impl File {
     ///
     /// After you supply a unique file ID, Stripe returns the corresponding file object.
     /// Learn how to [access file contents](https://stripe.com/docs/file-upload#download-file-contents).
-    pub fn retrieve(client: &Client, id: &FileId, expand: &[&str]) -> Response<File> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &FileId,
+        expand: &[&str],
+    ) -> C::Response<File> {
         client.get_query(&format!("/files/{}", id), &Expand { expand })
     }
 }
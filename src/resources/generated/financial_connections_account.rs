@@ -2,11 +2,14 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{FinancialConnectionsAccountId};
-use crate::params::{Expandable, Object, Timestamp};
-use crate::resources::{BankConnectionsResourceAccountholder, FinancialConnectionsAccountOwnership};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::FinancialConnectionsAccountId;
+use crate::params::{Expandable, Object, Timestamp};
+use crate::resources::{
+    BankConnectionsResourceAccountholder, FinancialConnectionsAccountOwnership,
+};
+
 /// The resource representing a Stripe "BankConnectionsResourceLinkedAccount".
 ///
 /// For more details see <https://stripe.com/docs/api/financial_connections/accounts/object>
@@ -98,7 +101,6 @@ impl Object for FinancialConnectionsAccount {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BankConnectionsResourceBalance {
-
     /// The time that the external institution calculated this balance.
     ///
     /// Measured in seconds since the Unix epoch.
@@ -129,7 +131,6 @@ pub struct BankConnectionsResourceBalance {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BankConnectionsResourceBalanceApiResourceCashBalance {
-
     /// The funds available to the account holder.
     ///
     /// Typically this is the current balance less any holds.  Each key is a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.  Each value is a integer amount.
@@ -140,7 +141,6 @@ pub struct BankConnectionsResourceBalanceApiResourceCashBalance {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BankConnectionsResourceBalanceApiResourceCreditBalance {
-
     /// The credit that has been used by the account holder.
     ///
     /// Each key is a three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
@@ -154,7 +154,6 @@ pub struct BankConnectionsResourceBalanceApiResourceCreditBalance {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BankConnectionsResourceBalanceRefresh {
-
     /// The time at which the last refresh attempt was initiated.
     ///
     /// Measured in seconds since the Unix epoch.
@@ -172,7 +171,6 @@ pub struct BankConnectionsResourceBalanceRefresh {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BankConnectionsResourceOwnershipRefresh {
-
     /// The time at which the last refresh attempt was initiated.
     ///
     /// Measured in seconds since the Unix epoch.
@@ -184,7 +182,6 @@ pub struct BankConnectionsResourceOwnershipRefresh {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BankConnectionsResourceTransactionRefresh {
-
     /// Unique identifier for the object.
     pub id: String,
 
@@ -543,7 +540,9 @@ impl FinancialConnectionsAccountSupportedPaymentMethodTypes {
     pub fn as_str(self) -> &'static str {
         match self {
             FinancialConnectionsAccountSupportedPaymentMethodTypes::Link => "link",
-            FinancialConnectionsAccountSupportedPaymentMethodTypes::UsBankAccount => "us_bank_account",
+            FinancialConnectionsAccountSupportedPaymentMethodTypes::UsBankAccount => {
+                "us_bank_account"
+            }
         }
     }
 }
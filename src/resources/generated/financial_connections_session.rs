@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{FinancialConnectionsSessionId};
 use crate::params::{Expand, List, Object};
 use crate::resources::{BankConnectionsResourceAccountholder, FinancialConnectionsAccount};
@@ -49,7 +49,10 @@ impl FinancialConnectionsSession {
     /// To launch the Financial Connections authorization flow, create a `Session`.
     ///
     /// The session’s `client_secret` can be used to launch the flow using Stripe.js.
-    pub fn create(client: &Client, params: CreateFinancialConnectionsSession<'_>) -> Response<FinancialConnectionsSession> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateFinancialConnectionsSession<'_>,
+    ) -> C::Response<FinancialConnectionsSession> {
         client.post_form("/financial_connections/sessions", &params)
     }
 }
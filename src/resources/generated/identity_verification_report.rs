@@ -2,11 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{IdentityVerificationReportId};
-use crate::params::{Object, Timestamp};
-use crate::resources::{Address};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::IdentityVerificationReportId;
+use crate::params::{Object, Timestamp};
+use crate::resources::Address;
+
 /// The resource representing a Stripe "GelatoVerificationReport".
 ///
 /// For more details see <https://stripe.com/docs/api/identity/verification_reports/object>
@@ -56,7 +57,6 @@ impl Object for IdentityVerificationReport {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDocumentReport {
-
     /// Address as it appears in the document.
     pub address: Option<Address>,
 
@@ -99,7 +99,6 @@ pub struct GelatoDocumentReport {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDataDocumentReportDateOfBirth {
-
     /// Numerical day between 1 and 31.
     pub day: Option<i64>,
 
@@ -112,7 +111,6 @@ pub struct GelatoDataDocumentReportDateOfBirth {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDataDocumentReportExpirationDate {
-
     /// Numerical day between 1 and 31.
     pub day: Option<i64>,
 
@@ -125,7 +123,6 @@ pub struct GelatoDataDocumentReportExpirationDate {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDataDocumentReportIssuedDate {
-
     /// Numerical day between 1 and 31.
     pub day: Option<i64>,
 
@@ -138,7 +135,6 @@ pub struct GelatoDataDocumentReportIssuedDate {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDocumentReportError {
-
     /// A short machine-readable string giving the reason for the verification failure.
     pub code: Option<GelatoDocumentReportErrorCode>,
 
@@ -150,7 +146,6 @@ pub struct GelatoDocumentReportError {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoIdNumberReport {
-
     /// Date of birth.
     pub dob: Option<GelatoDataIdNumberReportDate>,
 
@@ -179,7 +174,6 @@ pub struct GelatoIdNumberReport {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDataIdNumberReportDate {
-
     /// Numerical day between 1 and 31.
     pub day: Option<i64>,
 
@@ -192,7 +186,6 @@ pub struct GelatoDataIdNumberReportDate {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoIdNumberReportError {
-
     /// A short machine-readable string giving the reason for the verification failure.
     pub code: Option<GelatoIdNumberReportErrorCode>,
 
@@ -204,7 +197,6 @@ pub struct GelatoIdNumberReportError {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoSelfieReport {
-
     /// ID of the [File](https://stripe.com/docs/api/files) holding the image of the identity document used in this check.
     pub document: Option<String>,
 
@@ -222,7 +214,6 @@ pub struct GelatoSelfieReport {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoSelfieReportError {
-
     /// A short machine-readable string giving the reason for the verification failure.
     pub code: Option<GelatoSelfieReportErrorCode>,
 
@@ -234,7 +225,6 @@ pub struct GelatoSelfieReportError {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoVerificationReportOptions {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub document: Option<GelatoReportDocumentOptions>,
 
@@ -244,7 +234,6 @@ pub struct GelatoVerificationReportOptions {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoReportDocumentOptions {
-
     /// Array of strings of allowed identity document types.
     ///
     /// If the provided identity document isn’t one of the allowed types, the verification check will fail with a document_type_not_allowed error code.
@@ -267,8 +256,7 @@ pub struct GelatoReportDocumentOptions {
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct GelatoReportIdNumberOptions {
-}
+pub struct GelatoReportIdNumberOptions {}
 
 /// An enum representing the possible values of an `GelatoDocumentReportError`'s `code` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
@@ -283,7 +271,9 @@ impl GelatoDocumentReportErrorCode {
     pub fn as_str(self) -> &'static str {
         match self {
             GelatoDocumentReportErrorCode::DocumentExpired => "document_expired",
-            GelatoDocumentReportErrorCode::DocumentTypeNotSupported => "document_type_not_supported",
+            GelatoDocumentReportErrorCode::DocumentTypeNotSupported => {
+                "document_type_not_supported"
+            }
             GelatoDocumentReportErrorCode::DocumentUnverifiedOther => "document_unverified_other",
         }
     }
@@ -388,7 +378,9 @@ pub enum GelatoIdNumberReportErrorCode {
 impl GelatoIdNumberReportErrorCode {
     pub fn as_str(self) -> &'static str {
         match self {
-            GelatoIdNumberReportErrorCode::IdNumberInsufficientDocumentData => "id_number_insufficient_document_data",
+            GelatoIdNumberReportErrorCode::IdNumberInsufficientDocumentData => {
+                "id_number_insufficient_document_data"
+            }
             GelatoIdNumberReportErrorCode::IdNumberMismatch => "id_number_mismatch",
             GelatoIdNumberReportErrorCode::IdNumberUnverifiedOther => "id_number_unverified_other",
         }
@@ -531,7 +523,9 @@ pub enum GelatoSelfieReportErrorCode {
 impl GelatoSelfieReportErrorCode {
     pub fn as_str(self) -> &'static str {
         match self {
-            GelatoSelfieReportErrorCode::SelfieDocumentMissingPhoto => "selfie_document_missing_photo",
+            GelatoSelfieReportErrorCode::SelfieDocumentMissingPhoto => {
+                "selfie_document_missing_photo"
+            }
             GelatoSelfieReportErrorCode::SelfieFaceMismatch => "selfie_face_mismatch",
             GelatoSelfieReportErrorCode::SelfieManipulated => "selfie_manipulated",
             GelatoSelfieReportErrorCode::SelfieUnverifiedOther => "selfie_unverified_other",
@@ -2,10 +2,11 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{IdentityVerificationSessionId};
+use serde::{Deserialize, Serialize};
+
+use crate::ids::IdentityVerificationSessionId;
 use crate::params::{Expandable, Metadata, Object, Timestamp};
 use crate::resources::{Address, IdentityVerificationReport};
-use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "GelatoVerificationSession".
 ///
@@ -84,7 +85,6 @@ impl Object for IdentityVerificationSession {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoSessionLastError {
-
     /// A short machine-readable string giving the reason for the verification or user-session failure.
     pub code: Option<GelatoSessionLastErrorCode>,
 
@@ -94,7 +94,6 @@ pub struct GelatoSessionLastError {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoVerificationSessionOptions {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub document: Option<GelatoSessionDocumentOptions>,
 
@@ -104,7 +103,6 @@ pub struct GelatoVerificationSessionOptions {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoSessionDocumentOptions {
-
     /// Array of strings of allowed identity document types.
     ///
     /// If the provided identity document isn’t one of the allowed types, the verification check will fail with a document_type_not_allowed error code.
@@ -127,12 +125,10 @@ pub struct GelatoSessionDocumentOptions {
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct GelatoSessionIdNumberOptions {
-}
+pub struct GelatoSessionIdNumberOptions {}
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoVerifiedOutputs {
-
     /// The user's verified address.
     pub address: Option<Address>,
 
@@ -154,7 +150,6 @@ pub struct GelatoVerifiedOutputs {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct GelatoDataVerifiedOutputsDate {
-
     /// Numerical day between 1 and 31.
     pub day: Option<i64>,
 
@@ -167,7 +162,6 @@ pub struct GelatoDataVerifiedOutputsDate {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct VerificationSessionRedaction {
-
     /// Indicates whether this object and its related objects have been redacted or not.
     pub status: VerificationSessionRedactionStatus,
 }
@@ -239,10 +233,14 @@ impl GelatoSessionLastErrorCode {
             GelatoSessionLastErrorCode::DocumentExpired => "document_expired",
             GelatoSessionLastErrorCode::DocumentTypeNotSupported => "document_type_not_supported",
             GelatoSessionLastErrorCode::DocumentUnverifiedOther => "document_unverified_other",
-            GelatoSessionLastErrorCode::IdNumberInsufficientDocumentData => "id_number_insufficient_document_data",
+            GelatoSessionLastErrorCode::IdNumberInsufficientDocumentData => {
+                "id_number_insufficient_document_data"
+            }
             GelatoSessionLastErrorCode::IdNumberMismatch => "id_number_mismatch",
             GelatoSessionLastErrorCode::IdNumberUnverifiedOther => "id_number_unverified_other",
-            GelatoSessionLastErrorCode::SelfieDocumentMissingPhoto => "selfie_document_missing_photo",
+            GelatoSessionLastErrorCode::SelfieDocumentMissingPhoto => {
+                "selfie_document_missing_photo"
+            }
             GelatoSessionLastErrorCode::SelfieFaceMismatch => "selfie_face_mismatch",
             GelatoSessionLastErrorCode::SelfieManipulated => "selfie_manipulated",
             GelatoSessionLastErrorCode::SelfieUnverifiedOther => "selfie_unverified_other",
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerId, InvoiceId, SubscriptionId};
 use crate::params::{
     CurrencyMap, Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery,
@@ -472,19 +472,26 @@ impl Invoice {
     /// You can list all invoices, or list the invoices for a specific customer.
     ///
     /// The invoices are returned sorted by creation date, with the most recently created invoices appearing first.
-    pub fn list(client: &Client, params: &ListInvoices<'_>) -> Response<List<Invoice>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListInvoices<'_>,
+    ) -> C::Response<List<Invoice>> {
         client.get_query("/invoices", &params)
     }
 
     /// This endpoint creates a draft invoice for a given customer.
     ///
     /// The invoice remains a draft until you [finalize](https://stripe.com/docs/api#finalize_invoice) the invoice, which allows you to [pay](https://stripe.com/docs/api#pay_invoice) or [send](https://stripe.com/docs/api#send_invoice) the invoice to your customers.
-    pub fn create(client: &Client, params: CreateInvoice<'_>) -> Response<Invoice> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateInvoice<'_>) -> C::Response<Invoice> {
         client.post_form("/invoices", &params)
     }
 
     /// Retrieves the invoice with the given ID.
-    pub fn retrieve(client: &Client, id: &InvoiceId, expand: &[&str]) -> Response<Invoice> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &InvoiceId,
+        expand: &[&str],
+    ) -> C::Response<Invoice> {
         client.get_query(&format!("/invoices/{}", id), &Expand { expand })
     }
 
@@ -492,7 +499,7 @@ impl Invoice {
     ///
     /// This cannot be undone.
     /// Attempts to delete invoices that are no longer in a draft state will fail; once an invoice has been finalized or if an invoice is for a subscription, it must be [voided](https://stripe.com/docs/api#void_invoice).
-    pub fn delete(client: &Client, id: &InvoiceId) -> Response<Deleted<InvoiceId>> {
+    pub fn delete<C: StripeClient>(client: &C, id: &InvoiceId) -> C::Response<Deleted<InvoiceId>> {
         client.delete(&format!("/invoices/{}", id))
     }
 }
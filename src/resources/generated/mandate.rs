@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::MandateId;
 use crate::params::{Expand, Expandable, Object, Timestamp};
 use crate::resources::{Currency, PaymentMethod};
@@ -47,7 +47,11 @@ pub struct Mandate {
 
 impl Mandate {
     /// Retrieves a Mandate object.
-    pub fn retrieve(client: &Client, id: &MandateId, expand: &[&str]) -> Response<Mandate> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &MandateId,
+        expand: &[&str],
+    ) -> C::Response<Mandate> {
         client.get_query(&format!("/mandates/{}", id), &Expand { expand })
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{
     CustomerId, MandateId, PaymentIntentId, PaymentMethodConfigurationId, PaymentMethodId,
 };
@@ -197,7 +197,10 @@ pub struct PaymentIntent {
 
 impl PaymentIntent {
     /// Returns a list of PaymentIntents.
-    pub fn list(client: &Client, params: &ListPaymentIntents<'_>) -> Response<List<PaymentIntent>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListPaymentIntents<'_>,
+    ) -> C::Response<List<PaymentIntent>> {
         client.get_query("/payment_intents", &params)
     }
 
@@ -208,7 +211,10 @@ impl PaymentIntent {
     ///
     /// Learn more about [the available payment flows with the Payment Intents API](https://stripe.com/docs/payments/payment-intents).  When you use `confirm=true` during creation, it’s equivalent to creating and confirming the PaymentIntent in the same call.
     /// You can use any parameters available in the [confirm API](https://stripe.com/docs/api/payment_intents/confirm) when you supply `confirm=true`.
-    pub fn create(client: &Client, params: CreatePaymentIntent<'_>) -> Response<PaymentIntent> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreatePaymentIntent<'_>,
+    ) -> C::Response<PaymentIntent> {
         client.post_form("/payment_intents", &params)
     }
 
@@ -217,11 +223,11 @@ impl PaymentIntent {
     /// You can retrieve a PaymentIntent client-side using a publishable key when the `client_secret` is in the query string.
     /// If you retrieve a PaymentIntent with a publishable key, it only returns a subset of properties.
     /// Refer to the [payment intent](https://stripe.com/docs/api#payment_intent_object) object reference for more details.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &PaymentIntentId,
         expand: &[&str],
-    ) -> Response<PaymentIntent> {
+    ) -> C::Response<PaymentIntent> {
         client.get_query(&format!("/payment_intents/{}", id), &Expand { expand })
     }
 
@@ -232,11 +238,11 @@ impl PaymentIntent {
     ///
     /// For example, updating the `payment_method` always requires you to confirm the PaymentIntent again.
     /// If you prefer to update and confirm at the same time, we recommend updating properties through the [confirm API](https://stripe.com/docs/api/payment_intents/confirm) instead.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &PaymentIntentId,
         params: UpdatePaymentIntent<'_>,
-    ) -> Response<PaymentIntent> {
+    ) -> C::Response<PaymentIntent> {
         client.post_form(&format!("/payment_intents/{}", id), &params)
     }
 }
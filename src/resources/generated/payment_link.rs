@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::PaymentLinkId;
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable};
 use crate::resources::{
@@ -126,26 +126,36 @@ pub struct PaymentLink {
 
 impl PaymentLink {
     /// Returns a list of your payment links.
-    pub fn list(client: &Client, params: &ListPaymentLinks<'_>) -> Response<List<PaymentLink>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListPaymentLinks<'_>,
+    ) -> C::Response<List<PaymentLink>> {
         client.get_query("/payment_links", &params)
     }
 
     /// Creates a payment link.
-    pub fn create(client: &Client, params: CreatePaymentLink<'_>) -> Response<PaymentLink> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreatePaymentLink<'_>,
+    ) -> C::Response<PaymentLink> {
         client.post_form("/payment_links", &params)
     }
 
     /// Retrieve a payment link.
-    pub fn retrieve(client: &Client, id: &PaymentLinkId, expand: &[&str]) -> Response<PaymentLink> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &PaymentLinkId,
+        expand: &[&str],
+    ) -> C::Response<PaymentLink> {
         client.get_query(&format!("/payment_links/{}", id), &Expand { expand })
     }
 
     /// Updates a payment link.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &PaymentLinkId,
         params: UpdatePaymentLink<'_>,
-    ) -> Response<PaymentLink> {
+    ) -> C::Response<PaymentLink> {
         client.post_form(&format!("/payment_links/{}", id), &params)
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerId, PaymentMethodId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
 use crate::resources::{
@@ -160,36 +160,42 @@ impl PaymentMethod {
     /// Returns a list of PaymentMethods for Treasury flows.
     ///
     /// If you want to list the PaymentMethods attached to a Customer for payments, you should use the [List a Customer’s PaymentMethods](https://stripe.com/docs/api/payment_methods/customer_list) API instead.
-    pub fn list(client: &Client, params: &ListPaymentMethods<'_>) -> Response<List<PaymentMethod>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListPaymentMethods<'_>,
+    ) -> C::Response<List<PaymentMethod>> {
         client.get_query("/payment_methods", &params)
     }
 
     /// Creates a PaymentMethod object.
     ///
     /// Read the [Stripe.js reference](https://stripe.com/docs/stripe-js/reference#stripe-create-payment-method) to learn how to create PaymentMethods via Stripe.js.  Instead of creating a PaymentMethod directly, we recommend using the [PaymentIntents](https://stripe.com/docs/payments/accept-a-payment) API to accept a payment immediately or the [SetupIntent](https://stripe.com/docs/payments/save-and-reuse) API to collect payment method details ahead of a future payment.
-    pub fn create(client: &Client, params: CreatePaymentMethod<'_>) -> Response<PaymentMethod> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreatePaymentMethod<'_>,
+    ) -> C::Response<PaymentMethod> {
         client.post_form("/payment_methods", &params)
     }
 
     /// Retrieves a PaymentMethod object attached to the StripeAccount.
     ///
     /// To retrieve a payment method attached to a Customer, you should use [Retrieve a Customer’s PaymentMethods](https://stripe.com/docs/api/payment_methods/customer).
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &PaymentMethodId,
         expand: &[&str],
-    ) -> Response<PaymentMethod> {
+    ) -> C::Response<PaymentMethod> {
         client.get_query(&format!("/payment_methods/{}", id), &Expand { expand })
     }
 
     /// Updates a PaymentMethod object.
     ///
     /// A PaymentMethod must be attached a customer to be updated.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &PaymentMethodId,
         params: UpdatePaymentMethod<'_>,
-    ) -> Response<PaymentMethod> {
+    ) -> C::Response<PaymentMethod> {
         client.post_form(&format!("/payment_methods/{}", id), &params)
     }
 }
@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{PaymentMethodConfigurationId};
 use crate::params::{Expand, List, Object, Paginable};
 use serde::{Deserialize, Serialize};
@@ -139,23 +139,37 @@ pub struct PaymentMethodConfiguration {
 impl PaymentMethodConfiguration {
 
     /// List payment method configurations.
-pub fn list(client: &Client, params: &ListPaymentMethodConfigurations<'_>) -> Response<List<PaymentMethodConfiguration>> {
+pub fn list<C: StripeClient>(
+    client: &C,
+    params: &ListPaymentMethodConfigurations<'_>,
+) -> C::Response<List<PaymentMethodConfiguration>> {
    client.get_query("/payment_method_configurations", &params)
 }
 
 
     /// Creates a payment method configuration.
-    pub fn create(client: &Client, params: CreatePaymentMethodConfiguration<'_>) -> Response<PaymentMethodConfiguration> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreatePaymentMethodConfiguration<'_>,
+    ) -> C::Response<PaymentMethodConfiguration> {
         client.post_form("/payment_method_configurations", &params)
     }
 
     /// Retrieve payment method configuration.
-    pub fn retrieve(client: &Client, id: &PaymentMethodConfigurationId, expand: &[&str]) -> Response<PaymentMethodConfiguration> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &PaymentMethodConfigurationId,
+        expand: &[&str],
+    ) -> C::Response<PaymentMethodConfiguration> {
         client.get_query(&format!("/payment_method_configurations/{}", id), &Expand { expand })
     }
 
     /// Update payment method configuration.
-    pub fn update(client: &Client, id: &PaymentMethodConfigurationId, params: UpdatePaymentMethodConfiguration<'_>) -> Response<PaymentMethodConfiguration> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &PaymentMethodConfigurationId,
+        params: UpdatePaymentMethodConfiguration<'_>,
+    ) -> C::Response<PaymentMethodConfiguration> {
         client.post_form(&format!("/payment_method_configurations/{}", id), &params)
     }
 }
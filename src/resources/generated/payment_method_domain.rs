@@ -2,7 +2,7 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{PaymentMethodDomainId};
 use crate::params::{Expand, List, Object, Paginable, Timestamp};
 use serde::{Deserialize, Serialize};
@@ -43,23 +43,37 @@ pub struct PaymentMethodDomain {
 impl PaymentMethodDomain {
 
     /// Lists the details of existing payment method domains.
-pub fn list(client: &Client, params: &ListPaymentMethodDomains<'_>) -> Response<List<PaymentMethodDomain>> {
+pub fn list<C: StripeClient>(
+    client: &C,
+    params: &ListPaymentMethodDomains<'_>,
+) -> C::Response<List<PaymentMethodDomain>> {
    client.get_query("/payment_method_domains", &params)
 }
 
 
     /// Creates a payment method domain.
-    pub fn create(client: &Client, params: CreatePaymentMethodDomain<'_>) -> Response<PaymentMethodDomain> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreatePaymentMethodDomain<'_>,
+    ) -> C::Response<PaymentMethodDomain> {
         client.post_form("/payment_method_domains", &params)
     }
 
     /// Retrieves the details of an existing payment method domain.
-    pub fn retrieve(client: &Client, id: &PaymentMethodDomainId, expand: &[&str]) -> Response<PaymentMethodDomain> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &PaymentMethodDomainId,
+        expand: &[&str],
+    ) -> C::Response<PaymentMethodDomain> {
         client.get_query(&format!("/payment_method_domains/{}", id), &Expand { expand })
     }
 
     /// Updates an existing payment method domain.
-    pub fn update(client: &Client, id: &PaymentMethodDomainId, params: UpdatePaymentMethodDomain<'_>) -> Response<PaymentMethodDomain> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &PaymentMethodDomainId,
+        params: UpdatePaymentMethodDomain<'_>,
+    ) -> C::Response<PaymentMethodDomain> {
         client.post_form(&format!("/payment_method_domains/{}", id), &params)
     }
 }
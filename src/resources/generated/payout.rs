@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::PayoutId;
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{BalanceTransaction, Currency, PayoutDestinationUnion};
@@ -105,7 +105,10 @@ impl Payout {
     /// Returns a list of existing payouts sent to third-party bank accounts or payouts that Stripe sent to you.
     ///
     /// The payouts return in sorted order, with the most recently created payouts appearing first.
-    pub fn list(client: &Client, params: &ListPayouts<'_>) -> Response<List<Payout>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListPayouts<'_>,
+    ) -> C::Response<List<Payout>> {
         client.get_query("/payouts", &params)
     }
 
@@ -114,7 +117,7 @@ impl Payout {
     /// Your [Stripe balance](https://stripe.com/docs/api#balance) must cover the payout amount.
     /// If it doesn’t, you receive an “Insufficient Funds” error.  If your API key is in test mode, money won’t actually be sent, though every other action occurs as if you’re in live mode.  If you create a manual payout on a Stripe account that uses multiple payment source types, you need to specify the source type balance that the payout draws from.
     /// The [balance object](https://stripe.com/docs/api#balance_object) details available and pending amounts by source type.
-    pub fn create(client: &Client, params: CreatePayout<'_>) -> Response<Payout> {
+    pub fn create<C: StripeClient>(client: &C, params: CreatePayout<'_>) -> C::Response<Payout> {
         client.post_form("/payouts", &params)
     }
 
@@ -122,7 +125,11 @@ impl Payout {
     ///
     /// Supply the unique payout ID from either a payout creation request or the payout list.
     /// Stripe returns the corresponding payout information.
-    pub fn retrieve(client: &Client, id: &PayoutId, expand: &[&str]) -> Response<Payout> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &PayoutId,
+        expand: &[&str],
+    ) -> C::Response<Payout> {
         client.get_query(&format!("/payouts/{}", id), &Expand { expand })
     }
 
@@ -130,7 +137,11 @@ impl Payout {
     ///
     /// We don’t change parameters that you don’t provide.
     /// This request only accepts the metadata as arguments.
-    pub fn update(client: &Client, id: &PayoutId, params: UpdatePayout<'_>) -> Response<Payout> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &PayoutId,
+        params: UpdatePayout<'_>,
+    ) -> C::Response<Payout> {
         client.post_form(&format!("/payouts/{}", id), &params)
     }
 }
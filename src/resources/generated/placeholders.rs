@@ -54,6 +54,57 @@ impl Object for ApplicationFee {
     }
 }
 
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BillingPortalConfiguration {
+    pub id: BillingPortalConfigurationId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for BillingPortalConfiguration {
+    type Id = BillingPortalConfigurationId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "billing_portal.configuration"
+    }
+}
+
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct BillingPortalSession {
+    pub id: BillingPortalSessionId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for BillingPortalSession {
+    type Id = BillingPortalSessionId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "billing_portal.session"
+    }
+}
+
+#[cfg(not(feature = "connect"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Capability {
+    pub id: CapabilityId,
+}
+
+#[cfg(not(feature = "connect"))]
+impl Object for Capability {
+    type Id = CapabilityId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "capability"
+    }
+}
+
 #[cfg(not(feature = "checkout"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CheckoutSession {
@@ -71,6 +122,40 @@ impl Object for CheckoutSession {
     }
 }
 
+#[cfg(not(feature = "climate"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClimateOrder {
+    pub id: ClimateOrderId,
+}
+
+#[cfg(not(feature = "climate"))]
+impl Object for ClimateOrder {
+    type Id = ClimateOrderId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "climate.order"
+    }
+}
+
+#[cfg(not(feature = "climate"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClimateProduct {
+    pub id: ClimateProductId,
+}
+
+#[cfg(not(feature = "climate"))]
+impl Object for ClimateProduct {
+    type Id = ClimateProductId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "climate.product"
+    }
+}
+
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ConnectCollectionTransfer {
@@ -105,6 +190,23 @@ impl Object for Coupon {
     }
 }
 
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CreditNote {
+    pub id: CreditNoteId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for CreditNote {
+    type Id = CreditNoteId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "credit_note"
+    }
+}
+
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Discount {
@@ -139,6 +241,40 @@ impl Object for ApplicationFeeRefund {
     }
 }
 
+#[cfg(not(feature = "financial-connections"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FinancialConnectionsAccount {
+    pub id: FinancialConnectionsAccountId,
+}
+
+#[cfg(not(feature = "financial-connections"))]
+impl Object for FinancialConnectionsAccount {
+    type Id = FinancialConnectionsAccountId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "financial_connections.account"
+    }
+}
+
+#[cfg(not(feature = "identity"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IdentityVerificationSession {
+    pub id: IdentityVerificationSessionId,
+}
+
+#[cfg(not(feature = "identity"))]
+impl Object for IdentityVerificationSession {
+    type Id = IdentityVerificationSessionId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "identity.verification_session"
+    }
+}
+
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Invoice {
@@ -241,6 +377,23 @@ impl Object for IssuingDispute {
     }
 }
 
+#[cfg(not(feature = "issuing"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct IssuingToken {
+    pub id: IssuingTokenId,
+}
+
+#[cfg(not(feature = "issuing"))]
+impl Object for IssuingToken {
+    type Id = IssuingTokenId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "issuing.token"
+    }
+}
+
 #[cfg(not(feature = "issuing"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct IssuingTransaction {
@@ -326,6 +479,23 @@ impl Object for OrderReturn {
     }
 }
 
+#[cfg(not(feature = "checkout"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentLink {
+    pub id: PaymentLinkId,
+}
+
+#[cfg(not(feature = "checkout"))]
+impl Object for PaymentLink {
+    type Id = PaymentLinkId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "payment_link"
+    }
+}
+
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Person {
@@ -360,6 +530,91 @@ impl Object for Plan {
     }
 }
 
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PromotionCode {
+    pub id: PromotionCodeId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for PromotionCode {
+    type Id = PromotionCodeId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "promotion_code"
+    }
+}
+
+#[cfg(not(feature = "billing"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Quote {
+    pub id: QuoteId,
+}
+
+#[cfg(not(feature = "billing"))]
+impl Object for Quote {
+    type Id = QuoteId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "quote"
+    }
+}
+
+#[cfg(not(feature = "fraud"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RadarEarlyFraudWarning {
+    pub id: RadarEarlyFraudWarningId,
+}
+
+#[cfg(not(feature = "fraud"))]
+impl Object for RadarEarlyFraudWarning {
+    type Id = RadarEarlyFraudWarningId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "radar.early_fraud_warning"
+    }
+}
+
+#[cfg(not(feature = "reporting"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ReportingReportRun {
+    pub id: ReportingReportRunId,
+}
+
+#[cfg(not(feature = "reporting"))]
+impl Object for ReportingReportRun {
+    type Id = ReportingReportRunId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "reporting.report_run"
+    }
+}
+
+#[cfg(not(feature = "reporting"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ReportingReportType {
+    pub id: ReportingReportTypeId,
+}
+
+#[cfg(not(feature = "reporting"))]
+impl Object for ReportingReportType {
+    type Id = ReportingReportTypeId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "reporting.report_type"
+    }
+}
+
 #[cfg(not(feature = "fraud"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Review {
@@ -479,6 +734,23 @@ impl Object for SubscriptionScheduleRevision {
     }
 }
 
+#[cfg(not(feature = "tax-calculation"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TaxSettings {
+    pub id: (),
+}
+
+#[cfg(not(feature = "tax-calculation"))]
+impl Object for TaxSettings {
+    type Id = ();
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+    fn object(&self) -> &'static str {
+        "tax.settings"
+    }
+}
+
 #[cfg(not(feature = "billing"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxId {
@@ -513,6 +785,23 @@ impl Object for TaxRate {
     }
 }
 
+#[cfg(not(feature = "terminal"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TerminalReader {
+    pub id: TerminalReaderId,
+}
+
+#[cfg(not(feature = "terminal"))]
+impl Object for TerminalReader {
+    type Id = TerminalReaderId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "terminal.reader"
+    }
+}
+
 #[cfg(not(feature = "connect"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Topup {
@@ -564,6 +853,142 @@ impl Object for TransferReversal {
     }
 }
 
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryCreditReversal {
+    pub id: TreasuryCreditReversalId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryCreditReversal {
+    type Id = TreasuryCreditReversalId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.credit_reversal"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryDebitReversal {
+    pub id: TreasuryDebitReversalId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryDebitReversal {
+    type Id = TreasuryDebitReversalId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.debit_reversal"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryFinancialAccount {
+    pub id: TreasuryFinancialAccountId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryFinancialAccount {
+    type Id = TreasuryFinancialAccountId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.financial_account"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryInboundTransfer {
+    pub id: TreasuryInboundTransferId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryInboundTransfer {
+    type Id = TreasuryInboundTransferId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.inbound_transfer"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryOutboundPayment {
+    pub id: TreasuryOutboundPaymentId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryOutboundPayment {
+    type Id = TreasuryOutboundPaymentId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.outbound_payment"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryOutboundTransfer {
+    pub id: TreasuryOutboundTransferId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryOutboundTransfer {
+    type Id = TreasuryOutboundTransferId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.outbound_transfer"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryReceivedCredit {
+    pub id: TreasuryReceivedCreditId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryReceivedCredit {
+    type Id = TreasuryReceivedCreditId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.received_credit"
+    }
+}
+
+#[cfg(not(feature = "treasury"))]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TreasuryReceivedDebit {
+    pub id: TreasuryReceivedDebitId,
+}
+
+#[cfg(not(feature = "treasury"))]
+impl Object for TreasuryReceivedDebit {
+    type Id = TreasuryReceivedDebitId;
+    fn id(&self) -> Self::Id {
+        self.id.clone()
+    }
+    fn object(&self) -> &'static str {
+        "treasury.received_debit"
+    }
+}
+
 #[cfg(not(feature = "webhook-endpoints"))]
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct WebhookEndpoint {
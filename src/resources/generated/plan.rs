@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::PlanId;
 use crate::params::{
     Deleted, Expand, Expandable, IdOrCreate, List, Metadata, Object, Paginable, RangeQuery,
@@ -133,12 +133,16 @@ pub struct Plan {
 
 impl Plan {
     /// Returns a list of your plans.
-    pub fn list(client: &Client, params: &ListPlans<'_>) -> Response<List<Plan>> {
+    pub fn list<C: StripeClient>(client: &C, params: &ListPlans<'_>) -> C::Response<List<Plan>> {
         client.get_query("/plans", &params)
     }
 
     /// Retrieves the plan with the given ID.
-    pub fn retrieve(client: &Client, id: &PlanId, expand: &[&str]) -> Response<Plan> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &PlanId,
+        expand: &[&str],
+    ) -> C::Response<Plan> {
         client.get_query(&format!("/plans/{}", id), &Expand { expand })
     }
 
@@ -146,14 +150,18 @@ impl Plan {
     ///
     /// Any parameters not provided are left unchanged.
     /// By design, you cannot change a plan’s ID, amount, currency, or billing cycle.
-    pub fn update(client: &Client, id: &PlanId, params: UpdatePlan<'_>) -> Response<Plan> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &PlanId,
+        params: UpdatePlan<'_>,
+    ) -> C::Response<Plan> {
         client.post_form(&format!("/plans/{}", id), &params)
     }
 
     /// Deleting plans means new subscribers can’t be added.
     ///
     /// Existing subscribers aren’t affected.
-    pub fn delete(client: &Client, id: &PlanId) -> Response<Deleted<PlanId>> {
+    pub fn delete<C: StripeClient>(client: &C, id: &PlanId) -> C::Response<Deleted<PlanId>> {
         client.delete(&format!("/plans/{}", id))
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::PriceId;
 use crate::params::{
     CurrencyMap, Expand, Expandable, IdOrCreate, List, Metadata, Object, Paginable, RangeQuery,
@@ -136,26 +136,34 @@ impl Price {
     /// Returns a list of your active prices, excluding [inline prices](https://stripe.com/docs/products-prices/pricing-models#inline-pricing).
     ///
     /// For the list of inactive prices, set `active` to false.
-    pub fn list(client: &Client, params: &ListPrices<'_>) -> Response<List<Price>> {
+    pub fn list<C: StripeClient>(client: &C, params: &ListPrices<'_>) -> C::Response<List<Price>> {
         client.get_query("/prices", &params)
     }
 
     /// Creates a new price for an existing product.
     ///
     /// The price can be recurring or one-time.
-    pub fn create(client: &Client, params: CreatePrice<'_>) -> Response<Price> {
+    pub fn create<C: StripeClient>(client: &C, params: CreatePrice<'_>) -> C::Response<Price> {
         client.post_form("/prices", &params)
     }
 
     /// Retrieves the price with the given ID.
-    pub fn retrieve(client: &Client, id: &PriceId, expand: &[&str]) -> Response<Price> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &PriceId,
+        expand: &[&str],
+    ) -> C::Response<Price> {
         client.get_query(&format!("/prices/{}", id), &Expand { expand })
     }
 
     /// Updates the specified price by setting the values of the parameters passed.
     ///
     /// Any parameters not provided are left unchanged.
-    pub fn update(client: &Client, id: &PriceId, params: UpdatePrice<'_>) -> Response<Price> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &PriceId,
+        params: UpdatePrice<'_>,
+    ) -> C::Response<Price> {
         client.post_form(&format!("/prices/{}", id), &params)
     }
 }
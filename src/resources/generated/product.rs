@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{ProductId, TaxCodeId};
 use crate::params::{
     CurrencyMap, Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery,
@@ -114,26 +114,37 @@ impl Product {
     /// Returns a list of your products.
     ///
     /// The products are returned sorted by creation date, with the most recently created products appearing first.
-    pub fn list(client: &Client, params: &ListProducts<'_>) -> Response<List<Product>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListProducts<'_>,
+    ) -> C::Response<List<Product>> {
         client.get_query("/products", &params)
     }
 
     /// Creates a new product object.
-    pub fn create(client: &Client, params: CreateProduct<'_>) -> Response<Product> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateProduct<'_>) -> C::Response<Product> {
         client.post_form("/products", &params)
     }
 
     /// Retrieves the details of an existing product.
     ///
     /// Supply the unique product ID from either a product creation request or the product list, and Stripe will return the corresponding product information.
-    pub fn retrieve(client: &Client, id: &ProductId, expand: &[&str]) -> Response<Product> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &ProductId,
+        expand: &[&str],
+    ) -> C::Response<Product> {
         client.get_query(&format!("/products/{}", id), &Expand { expand })
     }
 
     /// Updates the specific product by setting the values of the parameters passed.
     ///
     /// Any parameters not provided will be left unchanged.
-    pub fn update(client: &Client, id: &ProductId, params: UpdateProduct<'_>) -> Response<Product> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &ProductId,
+        params: UpdateProduct<'_>,
+    ) -> C::Response<Product> {
         client.post_form(&format!("/products/{}", id), &params)
     }
 
@@ -141,7 +152,7 @@ impl Product {
     ///
     /// Deleting a product is only possible if it has no prices associated with it.
     /// Additionally, deleting a product with `type=good` is only possible if it has no SKUs associated with it.
-    pub fn delete(client: &Client, id: &ProductId) -> Response<Deleted<ProductId>> {
+    pub fn delete<C: StripeClient>(client: &C, id: &ProductId) -> C::Response<Deleted<ProductId>> {
         client.delete(&format!("/products/{}", id))
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CouponId, CustomerId, PromotionCodeId};
 use crate::params::{
     CurrencyMap, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
@@ -61,29 +61,32 @@ pub struct PromotionCode {
 
 impl PromotionCode {
     /// Returns a list of your promotion codes.
-    pub fn list(client: &Client, params: &ListPromotionCodes<'_>) -> Response<List<PromotionCode>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListPromotionCodes<'_>,
+    ) -> C::Response<List<PromotionCode>> {
         client.get_query("/promotion_codes", &params)
     }
 
     /// Retrieves the promotion code with the given ID.
     ///
     /// In order to retrieve a promotion code by the customer-facing `code` use [list](https://stripe.com/docs/api/promotion_codes/list) with the desired `code`.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &PromotionCodeId,
         expand: &[&str],
-    ) -> Response<PromotionCode> {
+    ) -> C::Response<PromotionCode> {
         client.get_query(&format!("/promotion_codes/{}", id), &Expand { expand })
     }
 
     /// Updates the specified promotion code by setting the values of the parameters passed.
     ///
     /// Most fields are, by design, not editable.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &PromotionCodeId,
         params: UpdatePromotionCode<'_>,
-    ) -> Response<PromotionCode> {
+    ) -> C::Response<PromotionCode> {
         client.post_form(&format!("/promotion_codes/{}", id), &params)
     }
 }
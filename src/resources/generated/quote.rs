@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerId, QuoteId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, Timestamp};
 use crate::resources::{
@@ -145,12 +145,16 @@ pub struct Quote {
 
 impl Quote {
     /// Returns a list of your quotes.
-    pub fn list(client: &Client, params: &ListQuotes<'_>) -> Response<List<Quote>> {
+    pub fn list<C: StripeClient>(client: &C, params: &ListQuotes<'_>) -> C::Response<List<Quote>> {
         client.get_query("/quotes", &params)
     }
 
     /// Retrieves the quote with the given ID.
-    pub fn retrieve(client: &Client, id: &QuoteId, expand: &[&str]) -> Response<Quote> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &QuoteId,
+        expand: &[&str],
+    ) -> C::Response<Quote> {
         client.get_query(&format!("/quotes/{}", id), &Expand { expand })
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{ChargeId, CustomerId, PaymentIntentId, RefundId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{BalanceTransaction, Charge, Currency, PaymentIntent, TransferReversal};
@@ -97,7 +97,10 @@ impl Refund {
     /// Returns a list of all refunds you created.
     ///
     /// We return the refunds in sorted order, with the most recent refunds appearing first The 10 most recent refunds are always available by default on the Charge object.
-    pub fn list(client: &Client, params: &ListRefunds<'_>) -> Response<List<Refund>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListRefunds<'_>,
+    ) -> C::Response<List<Refund>> {
         client.get_query("/refunds", &params)
     }
 
@@ -112,19 +115,27 @@ impl Refund {
     /// Once entirely refunded, a charge can’t be refunded again.
     /// This method will raise an error when called on an already-refunded charge,
     /// or when trying to refund more money than is left on a charge.
-    pub fn create(client: &Client, params: CreateRefund<'_>) -> Response<Refund> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateRefund<'_>) -> C::Response<Refund> {
         client.post_form("/refunds", &params)
     }
 
     /// Retrieves the details of an existing refund.
-    pub fn retrieve(client: &Client, id: &RefundId, expand: &[&str]) -> Response<Refund> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &RefundId,
+        expand: &[&str],
+    ) -> C::Response<Refund> {
         client.get_query(&format!("/refunds/{}", id), &Expand { expand })
     }
 
     /// Updates the refund that you specify by setting the values of the passed parameters.
     ///
     /// Any parameters that you don’t provide remain unchanged.  This request only accepts `metadata` as an argument.
-    pub fn update(client: &Client, id: &RefundId, params: UpdateRefund<'_>) -> Response<Refund> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &RefundId,
+        params: UpdateRefund<'_>,
+    ) -> C::Response<Refund> {
         client.post_form(&format!("/refunds/{}", id), &params)
     }
 }
@@ -2,10 +2,11 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{ReportingReportTypeId};
-use crate::params::{Object, Timestamp};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::ReportingReportTypeId;
+use crate::params::{Object, Timestamp};
+
 /// The resource representing a Stripe "reporting_report_type".
 ///
 /// For more details see <https://stripe.com/docs/api/reporting/report_type/object>
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::ReviewId;
 use crate::params::{Expand, Expandable, List, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{Charge, PaymentIntent, ReviewReason};
@@ -69,12 +69,19 @@ impl Review {
     /// Returns a list of `Review` objects that have `open` set to `true`.
     ///
     /// The objects are sorted in descending order by creation date, with the most recently created object appearing first.
-    pub fn list(client: &Client, params: &ListReviews<'_>) -> Response<List<Review>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListReviews<'_>,
+    ) -> C::Response<List<Review>> {
         client.get_query("/reviews", &params)
     }
 
     /// Retrieves a `Review` object.
-    pub fn retrieve(client: &Client, id: &ReviewId, expand: &[&str]) -> Response<Review> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &ReviewId,
+        expand: &[&str],
+    ) -> C::Response<Review> {
         client.get_query(&format!("/reviews/{}", id), &Expand { expand })
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{SetupAttemptId, SetupIntentId};
 use crate::params::{Expand, Expandable, List, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{
@@ -73,7 +73,10 @@ pub struct SetupAttempt {
 
 impl SetupAttempt {
     /// Returns a list of SetupAttempts that associate with a provided SetupIntent.
-    pub fn list(client: &Client, params: &ListSetupAttempts<'_>) -> Response<List<SetupAttempt>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListSetupAttempts<'_>,
+    ) -> C::Response<List<SetupAttempt>> {
         client.get_query("/setup_attempts", &params)
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerId, PaymentMethodConfigurationId, PaymentMethodId, SetupIntentId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{
@@ -126,7 +126,10 @@ pub struct SetupIntent {
 
 impl SetupIntent {
     /// Returns a list of SetupIntents.
-    pub fn list(client: &Client, params: &ListSetupIntents<'_>) -> Response<List<SetupIntent>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListSetupIntents<'_>,
+    ) -> C::Response<List<SetupIntent>> {
         client.get_query("/setup_intents", &params)
     }
 
@@ -134,7 +137,10 @@ impl SetupIntent {
     ///
     /// After you create the SetupIntent, attach a payment method and [confirm](https://stripe.com/docs/api/setup_intents/confirm)
     /// it to collect any required permissions to charge the payment method later.
-    pub fn create(client: &Client, params: CreateSetupIntent<'_>) -> Response<SetupIntent> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateSetupIntent<'_>,
+    ) -> C::Response<SetupIntent> {
         client.post_form("/setup_intents", &params)
     }
 
@@ -143,16 +149,20 @@ impl SetupIntent {
     /// Client-side retrieval using a publishable key is allowed when the `client_secret` is provided in the query string.
     /// When retrieved with a publishable key, only a subset of properties will be returned.
     /// Please refer to the [SetupIntent](https://stripe.com/docs/api#setup_intent_object) object reference for more details.
-    pub fn retrieve(client: &Client, id: &SetupIntentId, expand: &[&str]) -> Response<SetupIntent> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &SetupIntentId,
+        expand: &[&str],
+    ) -> C::Response<SetupIntent> {
         client.get_query(&format!("/setup_intents/{}", id), &Expand { expand })
     }
 
     /// Updates a SetupIntent object.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &SetupIntentId,
         params: UpdateSetupIntent<'_>,
-    ) -> Response<SetupIntent> {
+    ) -> C::Response<SetupIntent> {
         client.post_form(&format!("/setup_intents/{}", id), &params)
     }
 }
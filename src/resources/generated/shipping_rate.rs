@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{ShippingRateId, TaxCodeId};
 use crate::params::{
     CurrencyMap, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
@@ -69,30 +69,36 @@ pub struct ShippingRate {
 
 impl ShippingRate {
     /// Returns a list of your shipping rates.
-    pub fn list(client: &Client, params: &ListShippingRates<'_>) -> Response<List<ShippingRate>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListShippingRates<'_>,
+    ) -> C::Response<List<ShippingRate>> {
         client.get_query("/shipping_rates", &params)
     }
 
     /// Creates a new shipping rate object.
-    pub fn create(client: &Client, params: CreateShippingRate<'_>) -> Response<ShippingRate> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateShippingRate<'_>,
+    ) -> C::Response<ShippingRate> {
         client.post_form("/shipping_rates", &params)
     }
 
     /// Returns the shipping rate object with the given ID.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &ShippingRateId,
         expand: &[&str],
-    ) -> Response<ShippingRate> {
+    ) -> C::Response<ShippingRate> {
         client.get_query(&format!("/shipping_rates/{}", id), &Expand { expand })
     }
 
     /// Updates an existing shipping rate object.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &ShippingRateId,
         params: UpdateShippingRate<'_>,
-    ) -> Response<ShippingRate> {
+    ) -> C::Response<ShippingRate> {
         client.post_form(&format!("/shipping_rates/{}", id), &params)
     }
 }
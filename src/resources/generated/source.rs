@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerId, SourceId, TokenId};
 use crate::params::{Expand, List, Metadata, Object, Paginable, Timestamp};
 use crate::resources::{
@@ -161,19 +161,26 @@ pub struct Source {
 
 impl Source {
     /// List source transactions for a given source.
-    pub fn list(client: &Client, params: &ListSources<'_>) -> Response<List<Source>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListSources<'_>,
+    ) -> C::Response<List<Source>> {
         client.get_query("/sources/{source}/source_transactions", &params)
     }
 
     /// Creates a new source object.
-    pub fn create(client: &Client, params: CreateSource<'_>) -> Response<Source> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateSource<'_>) -> C::Response<Source> {
         client.post_form("/sources", &params)
     }
 
     /// Retrieves an existing source object.
     ///
     /// Supply the unique source ID from a source creation request and Stripe will return the corresponding up-to-date source object information.
-    pub fn retrieve(client: &Client, id: &SourceId, expand: &[&str]) -> Response<Source> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &SourceId,
+        expand: &[&str],
+    ) -> C::Response<Source> {
         client.get_query(&format!("/sources/{}", id), &Expand { expand })
     }
 
@@ -182,7 +189,11 @@ impl Source {
     /// Any parameters not provided will be left unchanged.  This request accepts the `metadata` and `owner` as arguments.
     /// It is also possible to update type specific information for selected payment methods.
     /// Please refer to our [payment method guides](https://stripe.com/docs/sources) for more detail.
-    pub fn update(client: &Client, id: &SourceId, params: UpdateSource<'_>) -> Response<Source> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &SourceId,
+        params: UpdateSource<'_>,
+    ) -> C::Response<Source> {
         client.post_form(&format!("/sources/{}", id), &params)
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CouponId, CustomerId, PlanId, PriceId, PromotionCodeId, SubscriptionId};
 use crate::params::{
     Deleted, Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp,
@@ -214,23 +214,29 @@ impl Subscription {
     /// By default, returns a list of subscriptions that have not been canceled.
     ///
     /// In order to list canceled subscriptions, specify `status=canceled`.
-    pub fn list(client: &Client, params: &ListSubscriptions<'_>) -> Response<List<Subscription>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListSubscriptions<'_>,
+    ) -> C::Response<List<Subscription>> {
         client.get_query("/subscriptions", &params)
     }
 
     /// Creates a new subscription on an existing customer.
     ///
     /// Each customer can have up to 500 active or scheduled subscriptions.  When you create a subscription with `collection_method=charge_automatically`, the first invoice is finalized as part of the request. The `payment_behavior` parameter determines the exact behavior of the initial payment.  To start subscriptions where the first invoice always begins in a `draft` status, use [subscription schedules](https://stripe.com/docs/billing/subscriptions/subscription-schedules#managing) instead. Schedules provide the flexibility to model more complex billing configurations that change over time.
-    pub fn create(client: &Client, params: CreateSubscription<'_>) -> Response<Subscription> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateSubscription<'_>,
+    ) -> C::Response<Subscription> {
         client.post_form("/subscriptions", &params)
     }
 
     /// Retrieves the subscription with the given ID.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &SubscriptionId,
         expand: &[&str],
-    ) -> Response<Subscription> {
+    ) -> C::Response<Subscription> {
         client.get_query(&format!("/subscriptions/{}", id), &Expand { expand })
     }
 
@@ -250,11 +256,11 @@ impl Subscription {
     /// Similarly, if you set `proration_behavior` to `none` when switching between different billing intervals (for example, from monthly to yearly), we don’t generate any credits for the old subscription’s unused time.
     /// We still reset the billing date and bill immediately for the new subscription.  Updating the quantity on a subscription many times in an hour may result in [rate limiting](https://stripe.com/docs/rate-limits).
     /// If you need to bill for a frequently changing quantity, consider integrating [usage-based billing](https://stripe.com/docs/billing/subscriptions/usage-based) instead.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &SubscriptionId,
         params: UpdateSubscription<'_>,
-    ) -> Response<Subscription> {
+    ) -> C::Response<Subscription> {
         client.post_form(&format!("/subscriptions/{}", id), &params)
     }
 
@@ -266,7 +272,10 @@ impl Subscription {
     /// This is intended to prevent unexpected payment attempts after the customer has canceled a subscription.
     /// However, you can resume automatic collection of the invoices manually after subscription cancellation to have us proceed.
     /// Or, you could check for unpaid invoices before allowing the customer to cancel the subscription at all.
-    pub fn delete(client: &Client, id: &SubscriptionId) -> Response<Deleted<SubscriptionId>> {
+    pub fn delete<C: StripeClient>(
+        client: &C,
+        id: &SubscriptionId,
+    ) -> C::Response<Deleted<SubscriptionId>> {
         client.delete(&format!("/subscriptions/{}", id))
     }
 }
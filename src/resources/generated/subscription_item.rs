@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{PlanId, PriceId, SubscriptionId, SubscriptionItemId};
 use crate::params::{Deleted, Expand, List, Metadata, Object, Paginable, Timestamp};
 use crate::resources::{Currency, Plan, Price, SubscriptionItemBillingThresholds, TaxRate};
@@ -60,48 +60,48 @@ pub struct SubscriptionItem {
 
 impl SubscriptionItem {
     /// Returns a list of your subscription items for a given subscription.
-    pub fn list(
-        client: &Client,
+    pub fn list<C: StripeClient>(
+        client: &C,
         params: &ListSubscriptionItems<'_>,
-    ) -> Response<List<SubscriptionItem>> {
+    ) -> C::Response<List<SubscriptionItem>> {
         client.get_query("/subscription_items", &params)
     }
 
     /// Adds a new item to an existing subscription.
     ///
     /// No existing items will be changed or replaced.
-    pub fn create(
-        client: &Client,
+    pub fn create<C: StripeClient>(
+        client: &C,
         params: CreateSubscriptionItem<'_>,
-    ) -> Response<SubscriptionItem> {
+    ) -> C::Response<SubscriptionItem> {
         client.post_form("/subscription_items", &params)
     }
 
     /// Retrieves the subscription item with the given ID.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &SubscriptionItemId,
         expand: &[&str],
-    ) -> Response<SubscriptionItem> {
+    ) -> C::Response<SubscriptionItem> {
         client.get_query(&format!("/subscription_items/{}", id), &Expand { expand })
     }
 
     /// Updates the plan or quantity of an item on a current subscription.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &SubscriptionItemId,
         params: UpdateSubscriptionItem<'_>,
-    ) -> Response<SubscriptionItem> {
+    ) -> C::Response<SubscriptionItem> {
         client.post_form(&format!("/subscription_items/{}", id), &params)
     }
 
     /// Deletes an item from the subscription.
     ///
     /// Removing a subscription item from a subscription will not cancel the subscription.
-    pub fn delete(
-        client: &Client,
+    pub fn delete<C: StripeClient>(
+        client: &C,
         id: &SubscriptionItemId,
-    ) -> Response<Deleted<SubscriptionItemId>> {
+    ) -> C::Response<Deleted<SubscriptionItemId>> {
         client.delete(&format!("/subscription_items/{}", id))
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerId, SubscriptionScheduleId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{
@@ -89,40 +89,40 @@ pub struct SubscriptionSchedule {
 
 impl SubscriptionSchedule {
     /// Retrieves the list of your subscription schedules.
-    pub fn list(
-        client: &Client,
+    pub fn list<C: StripeClient>(
+        client: &C,
         params: &ListSubscriptionSchedules<'_>,
-    ) -> Response<List<SubscriptionSchedule>> {
+    ) -> C::Response<List<SubscriptionSchedule>> {
         client.get_query("/subscription_schedules", &params)
     }
 
     /// Creates a new subscription schedule object.
     ///
     /// Each customer can have up to 500 active or scheduled subscriptions.
-    pub fn create(
-        client: &Client,
+    pub fn create<C: StripeClient>(
+        client: &C,
         params: CreateSubscriptionSchedule<'_>,
-    ) -> Response<SubscriptionSchedule> {
+    ) -> C::Response<SubscriptionSchedule> {
         client.post_form("/subscription_schedules", &params)
     }
 
     /// Retrieves the details of an existing subscription schedule.
     ///
     /// You only need to supply the unique subscription schedule identifier that was returned upon subscription schedule creation.
-    pub fn retrieve(
-        client: &Client,
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
         id: &SubscriptionScheduleId,
         expand: &[&str],
-    ) -> Response<SubscriptionSchedule> {
+    ) -> C::Response<SubscriptionSchedule> {
         client.get_query(&format!("/subscription_schedules/{}", id), &Expand { expand })
     }
 
     /// Updates an existing subscription schedule.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &SubscriptionScheduleId,
         params: UpdateSubscriptionSchedule<'_>,
-    ) -> Response<SubscriptionSchedule> {
+    ) -> C::Response<SubscriptionSchedule> {
         client.post_form(&format!("/subscription_schedules/{}", id), &params)
     }
 }
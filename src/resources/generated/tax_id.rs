@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::TaxIdId;
 use crate::params::{Deleted, Expand, Expandable, List, Object, Paginable, Timestamp};
 use crate::resources::{Account, Application, Customer};
@@ -61,22 +61,26 @@ pub struct TaxId {
 
 impl TaxId {
     /// Returns a list of tax IDs.
-    pub fn list(client: &Client, params: &ListTaxIds<'_>) -> Response<List<TaxId>> {
+    pub fn list<C: StripeClient>(client: &C, params: &ListTaxIds<'_>) -> C::Response<List<TaxId>> {
         client.get_query("/tax_ids", &params)
     }
 
     /// Creates a new account or customer `tax_id` object.
-    pub fn create(client: &Client, params: CreateTaxId<'_>) -> Response<TaxId> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateTaxId<'_>) -> C::Response<TaxId> {
         client.post_form("/tax_ids", &params)
     }
 
     /// Retrieves an account or customer `tax_id` object.
-    pub fn retrieve(client: &Client, id: &TaxIdId, expand: &[&str]) -> Response<TaxId> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TaxIdId,
+        expand: &[&str],
+    ) -> C::Response<TaxId> {
         client.get_query(&format!("/tax_ids/{}", id), &Expand { expand })
     }
 
     /// Deletes an existing account or customer `tax_id` object.
-    pub fn delete(client: &Client, id: &TaxIdId) -> Response<Deleted<TaxIdId>> {
+    pub fn delete<C: StripeClient>(client: &C, id: &TaxIdId) -> C::Response<Deleted<TaxIdId>> {
         client.delete(&format!("/tax_ids/{}", id))
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::TaxRateId;
 use crate::params::{Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
 
@@ -82,22 +82,33 @@ impl TaxRate {
     /// Returns a list of your tax rates.
     ///
     /// Tax rates are returned sorted by creation date, with the most recently created tax rates appearing first.
-    pub fn list(client: &Client, params: &ListTaxRates<'_>) -> Response<List<TaxRate>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTaxRates<'_>,
+    ) -> C::Response<List<TaxRate>> {
         client.get_query("/tax_rates", &params)
     }
 
     /// Creates a new tax rate.
-    pub fn create(client: &Client, params: CreateTaxRate<'_>) -> Response<TaxRate> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateTaxRate<'_>) -> C::Response<TaxRate> {
         client.post_form("/tax_rates", &params)
     }
 
     /// Retrieves a tax rate with the given ID.
-    pub fn retrieve(client: &Client, id: &TaxRateId, expand: &[&str]) -> Response<TaxRate> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TaxRateId,
+        expand: &[&str],
+    ) -> C::Response<TaxRate> {
         client.get_query(&format!("/tax_rates/{}", id), &Expand { expand })
     }
 
     /// Updates an existing tax rate.
-    pub fn update(client: &Client, id: &TaxRateId, params: UpdateTaxRate<'_>) -> Response<TaxRate> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &TaxRateId,
+        params: UpdateTaxRate<'_>,
+    ) -> C::Response<TaxRate> {
         client.post_form(&format!("/tax_rates/{}", id), &params)
     }
 }
@@ -2,16 +2,16 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::params::{Object};
-use crate::resources::{Address};
 use serde::{Deserialize, Serialize};
 
+use crate::params::Object;
+use crate::resources::Address;
+
 /// The resource representing a Stripe "TaxProductResourceTaxSettings".
 ///
 /// For more details see <https://stripe.com/docs/api/tax/settings/object>
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxSettings {
-
     pub defaults: TaxProductResourceTaxSettingsDefaults,
 
     /// The place where your business is located.
@@ -38,7 +38,6 @@ impl Object for TaxSettings {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourceTaxSettingsDefaults {
-
     /// Default [tax behavior](https://stripe.com/docs/tax/products-prices-tax-categories-tax-behavior#tax-behavior) used to specify whether the price is considered inclusive of taxes or exclusive of taxes.
     ///
     /// If the item's price has a tax behavior set, it will take precedence over the default tax behavior.
@@ -50,13 +49,11 @@ pub struct TaxProductResourceTaxSettingsDefaults {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourceTaxSettingsHeadOffice {
-
     pub address: Address,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourceTaxSettingsStatusDetails {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active: Option<TaxProductResourceTaxSettingsStatusDetailsResourceActive>,
 
@@ -65,12 +62,10 @@ pub struct TaxProductResourceTaxSettingsStatusDetails {
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct TaxProductResourceTaxSettingsStatusDetailsResourceActive {
-}
+pub struct TaxProductResourceTaxSettingsStatusDetailsResourceActive {}
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxProductResourceTaxSettingsStatusDetailsResourcePending {
-
     /// The list of missing fields that are required to perform calculations.
     ///
     /// It includes the entry `head_office` when the status is `pending`.
@@ -93,7 +88,9 @@ impl TaxProductResourceTaxSettingsDefaultsTaxBehavior {
         match self {
             TaxProductResourceTaxSettingsDefaultsTaxBehavior::Exclusive => "exclusive",
             TaxProductResourceTaxSettingsDefaultsTaxBehavior::Inclusive => "inclusive",
-            TaxProductResourceTaxSettingsDefaultsTaxBehavior::InferredByCurrency => "inferred_by_currency",
+            TaxProductResourceTaxSettingsDefaultsTaxBehavior::InferredByCurrency => {
+                "inferred_by_currency"
+            }
         }
     }
 }
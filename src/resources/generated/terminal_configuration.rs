@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::TerminalConfigurationId;
 use crate::params::{Expand, Expandable, List, Object, Paginable};
 use crate::resources::File;
@@ -44,18 +44,18 @@ pub struct TerminalConfiguration {
 
 impl TerminalConfiguration {
     /// Returns a list of `Configuration` objects.
-    pub fn list(
-        client: &Client,
+    pub fn list<C: StripeClient>(
+        client: &C,
         params: &ListTerminalConfigurations<'_>,
-    ) -> Response<List<TerminalConfiguration>> {
+    ) -> C::Response<List<TerminalConfiguration>> {
         client.get_query("/terminal/configurations", &params)
     }
 
     /// Creates a new `Configuration` object.
-    pub fn create(
-        client: &Client,
+    pub fn create<C: StripeClient>(
+        client: &C,
         params: CreateTerminalConfiguration<'_>,
-    ) -> Response<TerminalConfiguration> {
+    ) -> C::Response<TerminalConfiguration> {
         client.post_form("/terminal/configurations", &params)
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::params::{Expand, Object};
 
 /// The resource representing a Stripe "TerminalConnectionToken".
@@ -27,10 +27,10 @@ impl TerminalConnectionToken {
     /// To connect to a reader the Stripe Terminal SDK needs to retrieve a short-lived connection token from Stripe, proxied through your server.
     ///
     /// On your backend, add an endpoint that creates and returns a connection token.
-    pub fn create(
-        client: &Client,
+    pub fn create<C: StripeClient>(
+        client: &C,
         params: CreateTerminalConnectionToken<'_>,
-    ) -> Response<TerminalConnectionToken> {
+    ) -> C::Response<TerminalConnectionToken> {
         client.post_form("/terminal/connection_tokens", &params)
     }
 }
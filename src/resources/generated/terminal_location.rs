@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::TerminalLocationId;
 use crate::params::{Expand, List, Metadata, Object, Paginable};
 use crate::resources::Address;
@@ -45,19 +45,19 @@ pub struct TerminalLocation {
 
 impl TerminalLocation {
     /// Returns a list of `Location` objects.
-    pub fn list(
-        client: &Client,
+    pub fn list<C: StripeClient>(
+        client: &C,
         params: &ListTerminalLocations<'_>,
-    ) -> Response<List<TerminalLocation>> {
+    ) -> C::Response<List<TerminalLocation>> {
         client.get_query("/terminal/locations", &params)
     }
 
     /// Creates a new `Location` object.
     /// For further details, including which address fields are required in each country, see the [Manage locations](https://stripe.com/docs/terminal/fleet/locations) guide.
-    pub fn create(
-        client: &Client,
+    pub fn create<C: StripeClient>(
+        client: &C,
         params: CreateTerminalLocation<'_>,
-    ) -> Response<TerminalLocation> {
+    ) -> C::Response<TerminalLocation> {
         client.post_form("/terminal/locations", &params)
     }
 }
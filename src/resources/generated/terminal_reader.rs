@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::TerminalReaderId;
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable};
 use crate::resources::{Charge, Currency, PaymentIntent, Refund, SetupIntent, TerminalLocation};
@@ -66,15 +66,18 @@ pub struct TerminalReader {
 
 impl TerminalReader {
     /// Returns a list of `Reader` objects.
-    pub fn list(
-        client: &Client,
+    pub fn list<C: StripeClient>(
+        client: &C,
         params: &ListTerminalReaders<'_>,
-    ) -> Response<List<TerminalReader>> {
+    ) -> C::Response<List<TerminalReader>> {
         client.get_query("/terminal/readers", &params)
     }
 
     /// Creates a new `Reader` object.
-    pub fn create(client: &Client, params: CreateTerminalReader<'_>) -> Response<TerminalReader> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateTerminalReader<'_>,
+    ) -> C::Response<TerminalReader> {
         client.post_form("/terminal/readers", &params)
     }
 }
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerId, TokenId};
 use crate::params::{Expand, Metadata, Object, Timestamp};
 use crate::resources::{Address, BankAccount, Card, CompanyParams, PersonParams, TokenType};
@@ -48,12 +48,16 @@ impl Token {
     ///
     /// You can only use this token once.
     /// To do so, attach it to a [Custom account](https://stripe.com/docs/api#accounts).
-    pub fn create(client: &Client, params: CreateToken<'_>) -> Response<Token> {
+    pub fn create<C: StripeClient>(client: &C, params: CreateToken<'_>) -> C::Response<Token> {
         client.post_form("/tokens", &params)
     }
 
     /// Retrieves the token with the given ID.
-    pub fn retrieve(client: &Client, id: &TokenId, expand: &[&str]) -> Response<Token> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TokenId,
+        expand: &[&str],
+    ) -> C::Response<Token> {
         client.get_query(&format!("/tokens/{}", id), &Expand { expand })
     }
 }
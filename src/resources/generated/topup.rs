@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::TopupId;
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{BalanceTransaction, Currency, Source};
@@ -80,21 +80,29 @@ pub struct Topup {
 
 impl Topup {
     /// Returns a list of top-ups.
-    pub fn list(client: &Client, params: &ListTopups<'_>) -> Response<List<Topup>> {
+    pub fn list<C: StripeClient>(client: &C, params: &ListTopups<'_>) -> C::Response<List<Topup>> {
         client.get_query("/topups", &params)
     }
 
     /// Retrieves the details of a top-up that has previously been created.
     ///
     /// Supply the unique top-up ID that was returned from your previous request, and Stripe will return the corresponding top-up information.
-    pub fn retrieve(client: &Client, id: &TopupId, expand: &[&str]) -> Response<Topup> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TopupId,
+        expand: &[&str],
+    ) -> C::Response<Topup> {
         client.get_query(&format!("/topups/{}", id), &Expand { expand })
     }
 
     /// Updates the metadata of a top-up.
     ///
     /// Other top-up details are not editable by design.
-    pub fn update(client: &Client, id: &TopupId, params: UpdateTopup<'_>) -> Response<Topup> {
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &TopupId,
+        params: UpdateTopup<'_>,
+    ) -> C::Response<Topup> {
         client.post_form(&format!("/topups/{}", id), &params)
     }
 }
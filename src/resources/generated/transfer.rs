@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{ChargeId, TransferId};
 use crate::params::{Expand, Expandable, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
 use crate::resources::{Account, BalanceTransaction, Charge, Currency, TransferReversal};
@@ -83,32 +83,42 @@ impl Transfer {
     /// Returns a list of existing transfers sent to connected accounts.
     ///
     /// The transfers are returned in sorted order, with the most recently created transfers appearing first.
-    pub fn list(client: &Client, params: &ListTransfers<'_>) -> Response<List<Transfer>> {
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTransfers<'_>,
+    ) -> C::Response<List<Transfer>> {
         client.get_query("/transfers", &params)
     }
 
     /// To send funds from your Stripe account to a connected account, you create a new transfer object.
     ///
     /// Your [Stripe balance](https://stripe.com/docs/api#balance) must be able to cover the transfer amount, or you’ll receive an “Insufficient Funds” error.
-    pub fn create(client: &Client, params: CreateTransfer<'_>) -> Response<Transfer> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateTransfer<'_>,
+    ) -> C::Response<Transfer> {
         client.post_form("/transfers", &params)
     }
 
     /// Retrieves the details of an existing transfer.
     ///
     /// Supply the unique transfer ID from either a transfer creation request or the transfer list, and Stripe will return the corresponding transfer information.
-    pub fn retrieve(client: &Client, id: &TransferId, expand: &[&str]) -> Response<Transfer> {
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TransferId,
+        expand: &[&str],
+    ) -> C::Response<Transfer> {
         client.get_query(&format!("/transfers/{}", id), &Expand { expand })
     }
 
     /// Updates the specified transfer by setting the values of the parameters passed.
     ///
     /// Any parameters not provided will be left unchanged.  This request accepts only metadata as an argument.
-    pub fn update(
-        client: &Client,
+    pub fn update<C: StripeClient>(
+        client: &C,
         id: &TransferId,
         params: UpdateTransfer<'_>,
-    ) -> Response<Transfer> {
+    ) -> C::Response<Transfer> {
         client.post_form(&format!("/transfers/{}", id), &params)
     }
 }
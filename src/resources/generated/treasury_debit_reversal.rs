@@ -2,10 +2,11 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryDebitReversalId};
+use serde::{Deserialize, Serialize};
+
+use crate::ids::TreasuryDebitReversalId;
 use crate::params::{Expandable, Metadata, Object, Timestamp};
 use crate::resources::{Currency, TreasuryTransaction};
-use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "TreasuryReceivedDebitsResourceDebitReversal".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -70,14 +71,12 @@ impl Object for TreasuryDebitReversal {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedDebitsResourceDebitReversalLinkedFlows {
-
     /// Set if there is an Issuing dispute associated with the DebitReversal.
     pub issuing_dispute: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedDebitsResourceStatusTransitions {
-
     /// Timestamp describing when the DebitReversal changed status to `completed`.
     pub completed_at: Option<Timestamp>,
 }
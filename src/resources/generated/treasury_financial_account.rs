@@ -2,11 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryFinancialAccountId};
-use crate::params::{Metadata, Object, Timestamp};
-use crate::resources::{TreasuryFinancialAccountFeatures};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::TreasuryFinancialAccountId;
+use crate::params::{Metadata, Object, Timestamp};
+use crate::resources::TreasuryFinancialAccountFeatures;
+
 /// The resource representing a Stripe "TreasuryFinancialAccountsResourceFinancialAccount".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccount {
@@ -76,7 +77,6 @@ impl Object for TreasuryFinancialAccount {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceBalance {
-
     /// Funds the user can spend right now.
     pub cash: i64,
 
@@ -89,13 +89,13 @@ pub struct TreasuryFinancialAccountsResourceBalance {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceFinancialAddress {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub aba: Option<TreasuryFinancialAccountsResourceAbaRecord>,
 
     /// The list of networks that the address supports.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_networks: Option<Vec<TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks>>,
+    pub supported_networks:
+        Option<Vec<TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks>>,
 
     /// The type of financial address.
     #[serde(rename = "type")]
@@ -104,7 +104,6 @@ pub struct TreasuryFinancialAccountsResourceFinancialAddress {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceAbaRecord {
-
     /// The name of the person or business that owns the bank account.
     pub account_holder_name: String,
 
@@ -123,7 +122,6 @@ pub struct TreasuryFinancialAccountsResourceAbaRecord {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourcePlatformRestrictions {
-
     /// Restricts all inbound money movement.
     pub inbound_flows: Option<TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows>,
 
@@ -133,14 +131,12 @@ pub struct TreasuryFinancialAccountsResourcePlatformRestrictions {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceStatusDetails {
-
     /// Details related to the closure of this FinancialAccount.
     pub closed: Option<TreasuryFinancialAccountsResourceClosedStatusDetails>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceClosedStatusDetails {
-
     /// The array that contains reasons for a FinancialAccount closure.
     pub reasons: Vec<TreasuryFinancialAccountsResourceClosedStatusDetailsReasons>,
 }
@@ -172,14 +168,24 @@ impl TreasuryFinancialAccountActiveFeatures {
         match self {
             TreasuryFinancialAccountActiveFeatures::CardIssuing => "card_issuing",
             TreasuryFinancialAccountActiveFeatures::DepositInsurance => "deposit_insurance",
-            TreasuryFinancialAccountActiveFeatures::FinancialAddressesAba => "financial_addresses.aba",
+            TreasuryFinancialAccountActiveFeatures::FinancialAddressesAba => {
+                "financial_addresses.aba"
+            }
             TreasuryFinancialAccountActiveFeatures::InboundTransfersAch => "inbound_transfers.ach",
             TreasuryFinancialAccountActiveFeatures::IntraStripeFlows => "intra_stripe_flows",
             TreasuryFinancialAccountActiveFeatures::OutboundPaymentsAch => "outbound_payments.ach",
-            TreasuryFinancialAccountActiveFeatures::OutboundPaymentsUsDomesticWire => "outbound_payments.us_domestic_wire",
-            TreasuryFinancialAccountActiveFeatures::OutboundTransfersAch => "outbound_transfers.ach",
-            TreasuryFinancialAccountActiveFeatures::OutboundTransfersUsDomesticWire => "outbound_transfers.us_domestic_wire",
-            TreasuryFinancialAccountActiveFeatures::RemoteDepositCapture => "remote_deposit_capture",
+            TreasuryFinancialAccountActiveFeatures::OutboundPaymentsUsDomesticWire => {
+                "outbound_payments.us_domestic_wire"
+            }
+            TreasuryFinancialAccountActiveFeatures::OutboundTransfersAch => {
+                "outbound_transfers.ach"
+            }
+            TreasuryFinancialAccountActiveFeatures::OutboundTransfersUsDomesticWire => {
+                "outbound_transfers.us_domestic_wire"
+            }
+            TreasuryFinancialAccountActiveFeatures::RemoteDepositCapture => {
+                "remote_deposit_capture"
+            }
         }
     }
 }
@@ -228,14 +234,24 @@ impl TreasuryFinancialAccountPendingFeatures {
         match self {
             TreasuryFinancialAccountPendingFeatures::CardIssuing => "card_issuing",
             TreasuryFinancialAccountPendingFeatures::DepositInsurance => "deposit_insurance",
-            TreasuryFinancialAccountPendingFeatures::FinancialAddressesAba => "financial_addresses.aba",
+            TreasuryFinancialAccountPendingFeatures::FinancialAddressesAba => {
+                "financial_addresses.aba"
+            }
             TreasuryFinancialAccountPendingFeatures::InboundTransfersAch => "inbound_transfers.ach",
             TreasuryFinancialAccountPendingFeatures::IntraStripeFlows => "intra_stripe_flows",
             TreasuryFinancialAccountPendingFeatures::OutboundPaymentsAch => "outbound_payments.ach",
-            TreasuryFinancialAccountPendingFeatures::OutboundPaymentsUsDomesticWire => "outbound_payments.us_domestic_wire",
-            TreasuryFinancialAccountPendingFeatures::OutboundTransfersAch => "outbound_transfers.ach",
-            TreasuryFinancialAccountPendingFeatures::OutboundTransfersUsDomesticWire => "outbound_transfers.us_domestic_wire",
-            TreasuryFinancialAccountPendingFeatures::RemoteDepositCapture => "remote_deposit_capture",
+            TreasuryFinancialAccountPendingFeatures::OutboundPaymentsUsDomesticWire => {
+                "outbound_payments.us_domestic_wire"
+            }
+            TreasuryFinancialAccountPendingFeatures::OutboundTransfersAch => {
+                "outbound_transfers.ach"
+            }
+            TreasuryFinancialAccountPendingFeatures::OutboundTransfersUsDomesticWire => {
+                "outbound_transfers.us_domestic_wire"
+            }
+            TreasuryFinancialAccountPendingFeatures::RemoteDepositCapture => {
+                "remote_deposit_capture"
+            }
         }
     }
 }
@@ -284,14 +300,28 @@ impl TreasuryFinancialAccountRestrictedFeatures {
         match self {
             TreasuryFinancialAccountRestrictedFeatures::CardIssuing => "card_issuing",
             TreasuryFinancialAccountRestrictedFeatures::DepositInsurance => "deposit_insurance",
-            TreasuryFinancialAccountRestrictedFeatures::FinancialAddressesAba => "financial_addresses.aba",
-            TreasuryFinancialAccountRestrictedFeatures::InboundTransfersAch => "inbound_transfers.ach",
+            TreasuryFinancialAccountRestrictedFeatures::FinancialAddressesAba => {
+                "financial_addresses.aba"
+            }
+            TreasuryFinancialAccountRestrictedFeatures::InboundTransfersAch => {
+                "inbound_transfers.ach"
+            }
             TreasuryFinancialAccountRestrictedFeatures::IntraStripeFlows => "intra_stripe_flows",
-            TreasuryFinancialAccountRestrictedFeatures::OutboundPaymentsAch => "outbound_payments.ach",
-            TreasuryFinancialAccountRestrictedFeatures::OutboundPaymentsUsDomesticWire => "outbound_payments.us_domestic_wire",
-            TreasuryFinancialAccountRestrictedFeatures::OutboundTransfersAch => "outbound_transfers.ach",
-            TreasuryFinancialAccountRestrictedFeatures::OutboundTransfersUsDomesticWire => "outbound_transfers.us_domestic_wire",
-            TreasuryFinancialAccountRestrictedFeatures::RemoteDepositCapture => "remote_deposit_capture",
+            TreasuryFinancialAccountRestrictedFeatures::OutboundPaymentsAch => {
+                "outbound_payments.ach"
+            }
+            TreasuryFinancialAccountRestrictedFeatures::OutboundPaymentsUsDomesticWire => {
+                "outbound_payments.us_domestic_wire"
+            }
+            TreasuryFinancialAccountRestrictedFeatures::OutboundTransfersAch => {
+                "outbound_transfers.ach"
+            }
+            TreasuryFinancialAccountRestrictedFeatures::OutboundTransfersUsDomesticWire => {
+                "outbound_transfers.us_domestic_wire"
+            }
+            TreasuryFinancialAccountRestrictedFeatures::RemoteDepositCapture => {
+                "remote_deposit_capture"
+            }
         }
     }
 }
@@ -359,8 +389,12 @@ pub enum TreasuryFinancialAccountsResourceClosedStatusDetailsReasons {
 impl TreasuryFinancialAccountsResourceClosedStatusDetailsReasons {
     pub fn as_str(self) -> &'static str {
         match self {
-            TreasuryFinancialAccountsResourceClosedStatusDetailsReasons::AccountRejected => "account_rejected",
-            TreasuryFinancialAccountsResourceClosedStatusDetailsReasons::ClosedByPlatform => "closed_by_platform",
+            TreasuryFinancialAccountsResourceClosedStatusDetailsReasons::AccountRejected => {
+                "account_rejected"
+            }
+            TreasuryFinancialAccountsResourceClosedStatusDetailsReasons::ClosedByPlatform => {
+                "closed_by_platform"
+            }
             TreasuryFinancialAccountsResourceClosedStatusDetailsReasons::Other => "other",
         }
     }
@@ -395,7 +429,9 @@ impl TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks {
     pub fn as_str(self) -> &'static str {
         match self {
             TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks::Ach => "ach",
-            TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks::UsDomesticWire => "us_domestic_wire",
+            TreasuryFinancialAccountsResourceFinancialAddressSupportedNetworks::UsDomesticWire => {
+                "us_domestic_wire"
+            }
         }
     }
 }
@@ -460,8 +496,12 @@ pub enum TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows {
 impl TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows {
     pub fn as_str(self) -> &'static str {
         match self {
-            TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows::Restricted => "restricted",
-            TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows::Unrestricted => "unrestricted",
+            TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows::Restricted => {
+                "restricted"
+            }
+            TreasuryFinancialAccountsResourcePlatformRestrictionsInboundFlows::Unrestricted => {
+                "unrestricted"
+            }
         }
     }
 }
@@ -494,8 +534,12 @@ pub enum TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows {
 impl TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows {
     pub fn as_str(self) -> &'static str {
         match self {
-            TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows::Restricted => "restricted",
-            TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows::Unrestricted => "unrestricted",
+            TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows::Restricted => {
+                "restricted"
+            }
+            TreasuryFinancialAccountsResourcePlatformRestrictionsOutboundFlows::Unrestricted => {
+                "unrestricted"
+            }
         }
     }
 }
@@ -2,14 +2,17 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::params::{Object};
-use crate::resources::{TreasuryFinancialAccountsResourceAchToggleSettings, TreasuryFinancialAccountsResourceToggleSettings};
 use serde::{Deserialize, Serialize};
 
+use crate::params::Object;
+use crate::resources::{
+    TreasuryFinancialAccountsResourceAchToggleSettings,
+    TreasuryFinancialAccountsResourceToggleSettings,
+};
+
 /// The resource representing a Stripe "TreasuryFinancialAccountsResourceFinancialAccountFeatures".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountFeatures {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub card_issuing: Option<TreasuryFinancialAccountsResourceToggleSettings>,
 
@@ -42,14 +45,12 @@ impl Object for TreasuryFinancialAccountFeatures {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceFinancialAddressesFeatures {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub aba: Option<TreasuryFinancialAccountsResourceAbaToggleSettings>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceAbaToggleSettings {
-
     /// Whether the FinancialAccount should have the Feature.
     pub requested: bool,
 
@@ -62,14 +63,12 @@ pub struct TreasuryFinancialAccountsResourceAbaToggleSettings {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceInboundTransfers {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ach: Option<TreasuryFinancialAccountsResourceAchToggleSettings>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceOutboundPayments {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ach: Option<TreasuryFinancialAccountsResourceAchToggleSettings>,
 
@@ -79,7 +78,6 @@ pub struct TreasuryFinancialAccountsResourceOutboundPayments {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceOutboundTransfers {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ach: Option<TreasuryFinancialAccountsResourceAchToggleSettings>,
 
@@ -89,7 +87,6 @@ pub struct TreasuryFinancialAccountsResourceOutboundTransfers {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceTogglesSettingStatusDetails {
-
     /// Represents the reason why the status is `pending` or `restricted`.
     pub code: TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsCode,
 
@@ -98,7 +95,8 @@ pub struct TreasuryFinancialAccountsResourceTogglesSettingStatusDetails {
 
     /// The `platform_restrictions` that are restricting this Feature.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub restriction: Option<TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsRestriction>,
+    pub restriction:
+        Option<TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsRestriction>,
 }
 
 /// An enum representing the possible values of an `TreasuryFinancialAccountsResourceAbaToggleSettings`'s `status` field.
@@ -215,7 +213,9 @@ impl std::fmt::Display for TreasuryFinancialAccountsResourceTogglesSettingStatus
         self.as_str().fmt(f)
     }
 }
-impl std::default::Default for TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsResolution {
+impl std::default::Default
+    for TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsResolution
+{
     fn default() -> Self {
         Self::ContactStripe
     }
@@ -249,7 +249,9 @@ impl std::fmt::Display for TreasuryFinancialAccountsResourceTogglesSettingStatus
         self.as_str().fmt(f)
     }
 }
-impl std::default::Default for TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsRestriction {
+impl std::default::Default
+    for TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsRestriction
+{
     fn default() -> Self {
         Self::InboundFlows
     }
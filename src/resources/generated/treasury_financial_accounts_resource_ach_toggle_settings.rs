@@ -7,7 +7,6 @@ use serde::{Deserialize, Serialize};
 /// The resource representing a Stripe "TreasuryFinancialAccountsResourceAchToggleSettings".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceAchToggleSettings {
-
     /// Whether the FinancialAccount should have the Feature.
     pub requested: bool,
 
@@ -20,7 +19,6 @@ pub struct TreasuryFinancialAccountsResourceAchToggleSettings {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountsResourceTogglesSettingStatusDetails {
-
     /// Represents the reason why the status is `pending` or `restricted`.
     pub code: TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsCode,
 
@@ -29,7 +27,8 @@ pub struct TreasuryFinancialAccountsResourceTogglesSettingStatusDetails {
 
     /// The `platform_restrictions` that are restricting this Feature.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub restriction: Option<TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsRestriction>,
+    pub restriction:
+        Option<TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsRestriction>,
 }
 
 /// An enum representing the possible values of an `TreasuryFinancialAccountsResourceAchToggleSettings`'s `status` field.
@@ -146,7 +145,9 @@ impl std::fmt::Display for TreasuryFinancialAccountsResourceTogglesSettingStatus
         self.as_str().fmt(f)
     }
 }
-impl std::default::Default for TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsResolution {
+impl std::default::Default
+    for TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsResolution
+{
     fn default() -> Self {
         Self::ContactStripe
     }
@@ -180,7 +181,9 @@ impl std::fmt::Display for TreasuryFinancialAccountsResourceTogglesSettingStatus
         self.as_str().fmt(f)
     }
 }
-impl std::default::Default for TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsRestriction {
+impl std::default::Default
+    for TreasuryFinancialAccountsResourceTogglesSettingStatusDetailsRestriction
+{
     fn default() -> Self {
         Self::InboundFlows
     }
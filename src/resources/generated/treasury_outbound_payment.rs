@@ -2,10 +2,11 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryOutboundPaymentId};
+use serde::{Deserialize, Serialize};
+
+use crate::ids::TreasuryOutboundPaymentId;
 use crate::params::{Expandable, Metadata, Object, Timestamp};
 use crate::resources::{Currency, TreasurySharedResourceBillingDetails, TreasuryTransaction};
-use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "TreasuryOutboundPaymentsResourceOutboundPayment".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -46,7 +47,8 @@ pub struct TreasuryOutboundPayment {
     pub destination_payment_method_details: Option<OutboundPaymentsPaymentMethodDetails>,
 
     /// Details about the end user.
-    pub end_user_details: Option<TreasuryOutboundPaymentsResourceOutboundPaymentResourceEndUserDetails>,
+    pub end_user_details:
+        Option<TreasuryOutboundPaymentsResourceOutboundPaymentResourceEndUserDetails>,
 
     /// The date when funds are expected to arrive in the destination account.
     pub expected_arrival_date: Timestamp,
@@ -80,7 +82,8 @@ pub struct TreasuryOutboundPayment {
     /// If an OutboundPayment fails to arrive at its destination, its status will change to `returned`.
     pub status: TreasuryOutboundPaymentStatus,
 
-    pub status_transitions: TreasuryOutboundPaymentsResourceOutboundPaymentResourceStatusTransitions,
+    pub status_transitions:
+        TreasuryOutboundPaymentsResourceOutboundPaymentResourceStatusTransitions,
 
     /// The Transaction associated with this object.
     pub transaction: Expandable<TreasuryTransaction>,
@@ -98,7 +101,6 @@ impl Object for TreasuryOutboundPayment {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct OutboundPaymentsPaymentMethodDetails {
-
     pub billing_details: TreasurySharedResourceBillingDetails,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -114,7 +116,6 @@ pub struct OutboundPaymentsPaymentMethodDetails {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct OutboundPaymentsPaymentMethodDetailsFinancialAccount {
-
     /// Token of the FinancialAccount.
     pub id: String,
 
@@ -124,9 +125,9 @@ pub struct OutboundPaymentsPaymentMethodDetailsFinancialAccount {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct OutboundPaymentsPaymentMethodDetailsUsBankAccount {
-
     /// Account holder type: individual or company.
-    pub account_holder_type: Option<OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType>,
+    pub account_holder_type:
+        Option<OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType>,
 
     /// Account type: checkings or savings.
     ///
@@ -153,7 +154,6 @@ pub struct OutboundPaymentsPaymentMethodDetailsUsBankAccount {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundPaymentsResourceOutboundPaymentResourceEndUserDetails {
-
     /// IP address of the user initiating the OutboundPayment.
     ///
     /// Set if `present` is set to `true`.
@@ -169,7 +169,6 @@ pub struct TreasuryOutboundPaymentsResourceOutboundPaymentResourceEndUserDetails
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundPaymentsResourceOutboundPaymentResourceStatusTransitions {
-
     /// Timestamp describing when an OutboundPayment changed status to `canceled`.
     pub canceled_at: Option<Timestamp>,
 
@@ -185,7 +184,6 @@ pub struct TreasuryOutboundPaymentsResourceOutboundPaymentResourceStatusTransiti
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundPaymentsResourceReturnedStatus {
-
     /// Reason for the return.
     pub code: TreasuryOutboundPaymentsResourceReturnedStatusCode,
 
@@ -270,8 +268,12 @@ pub enum OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType {
 impl OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType {
     pub fn as_str(self) -> &'static str {
         match self {
-            OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType::Company => "company",
-            OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType::Individual => "individual",
+            OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType::Company => {
+                "company"
+            }
+            OutboundPaymentsPaymentMethodDetailsUsBankAccountAccountHolderType::Individual => {
+                "individual"
+            }
         }
     }
 }
@@ -339,7 +341,9 @@ impl OutboundPaymentsPaymentMethodDetailsUsBankAccountNetwork {
     pub fn as_str(self) -> &'static str {
         match self {
             OutboundPaymentsPaymentMethodDetailsUsBankAccountNetwork::Ach => "ach",
-            OutboundPaymentsPaymentMethodDetailsUsBankAccountNetwork::UsDomesticWire => "us_domestic_wire",
+            OutboundPaymentsPaymentMethodDetailsUsBankAccountNetwork::UsDomesticWire => {
+                "us_domestic_wire"
+            }
         }
     }
 }
@@ -422,12 +426,22 @@ impl TreasuryOutboundPaymentsResourceReturnedStatusCode {
         match self {
             TreasuryOutboundPaymentsResourceReturnedStatusCode::AccountClosed => "account_closed",
             TreasuryOutboundPaymentsResourceReturnedStatusCode::AccountFrozen => "account_frozen",
-            TreasuryOutboundPaymentsResourceReturnedStatusCode::BankAccountRestricted => "bank_account_restricted",
-            TreasuryOutboundPaymentsResourceReturnedStatusCode::BankOwnershipChanged => "bank_ownership_changed",
+            TreasuryOutboundPaymentsResourceReturnedStatusCode::BankAccountRestricted => {
+                "bank_account_restricted"
+            }
+            TreasuryOutboundPaymentsResourceReturnedStatusCode::BankOwnershipChanged => {
+                "bank_ownership_changed"
+            }
             TreasuryOutboundPaymentsResourceReturnedStatusCode::Declined => "declined",
-            TreasuryOutboundPaymentsResourceReturnedStatusCode::IncorrectAccountHolderName => "incorrect_account_holder_name",
-            TreasuryOutboundPaymentsResourceReturnedStatusCode::InvalidAccountNumber => "invalid_account_number",
-            TreasuryOutboundPaymentsResourceReturnedStatusCode::InvalidCurrency => "invalid_currency",
+            TreasuryOutboundPaymentsResourceReturnedStatusCode::IncorrectAccountHolderName => {
+                "incorrect_account_holder_name"
+            }
+            TreasuryOutboundPaymentsResourceReturnedStatusCode::InvalidAccountNumber => {
+                "invalid_account_number"
+            }
+            TreasuryOutboundPaymentsResourceReturnedStatusCode::InvalidCurrency => {
+                "invalid_currency"
+            }
             TreasuryOutboundPaymentsResourceReturnedStatusCode::NoAccount => "no_account",
             TreasuryOutboundPaymentsResourceReturnedStatusCode::Other => "other",
         }
@@ -2,11 +2,16 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryReceivedCreditId};
-use crate::params::{Expandable, Object, Timestamp};
-use crate::resources::{Currency, Payout, TreasuryCreditReversal, TreasuryOutboundPayment, TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails, TreasuryTransaction};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::TreasuryReceivedCreditId;
+use crate::params::{Expandable, Object, Timestamp};
+use crate::resources::{
+    Currency, Payout, TreasuryCreditReversal, TreasuryOutboundPayment,
+    TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails,
+    TreasuryTransaction,
+};
+
 /// The resource representing a Stripe "TreasuryReceivedCreditsResourceReceivedCredit".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedCredit {
@@ -42,7 +47,8 @@ pub struct TreasuryReceivedCredit {
     /// A [hosted transaction receipt](https://stripe.com/docs/treasury/moving-money/regulatory-receipts) URL that is provided when money movement is considered regulated under Stripe's money transmission licenses.
     pub hosted_regulatory_receipt_url: Option<String>,
 
-    pub initiating_payment_method_details: TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails,
+    pub initiating_payment_method_details:
+        TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails,
 
     pub linked_flows: TreasuryReceivedCreditsResourceLinkedFlows,
 
@@ -77,7 +83,6 @@ impl Object for TreasuryReceivedCredit {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedCreditsResourceLinkedFlows {
-
     /// The CreditReversal created as a result of this ReceivedCredit being reversed.
     pub credit_reversal: Option<String>,
 
@@ -102,7 +107,6 @@ pub struct TreasuryReceivedCreditsResourceLinkedFlows {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedCreditsResourceReversalDetails {
-
     /// Time before which a ReceivedCredit can be reversed.
     pub deadline: Option<Timestamp>,
 
@@ -112,7 +116,6 @@ pub struct TreasuryReceivedCreditsResourceReversalDetails {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedCreditsResourceSourceFlowsDetails {
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credit_reversal: Option<TreasuryCreditReversal>,
 
@@ -288,9 +291,13 @@ pub enum TreasuryReceivedCreditsResourceSourceFlowsDetailsType {
 impl TreasuryReceivedCreditsResourceSourceFlowsDetailsType {
     pub fn as_str(self) -> &'static str {
         match self {
-            TreasuryReceivedCreditsResourceSourceFlowsDetailsType::CreditReversal => "credit_reversal",
+            TreasuryReceivedCreditsResourceSourceFlowsDetailsType::CreditReversal => {
+                "credit_reversal"
+            }
             TreasuryReceivedCreditsResourceSourceFlowsDetailsType::Other => "other",
-            TreasuryReceivedCreditsResourceSourceFlowsDetailsType::OutboundPayment => "outbound_payment",
+            TreasuryReceivedCreditsResourceSourceFlowsDetailsType::OutboundPayment => {
+                "outbound_payment"
+            }
             TreasuryReceivedCreditsResourceSourceFlowsDetailsType::Payout => "payout",
         }
     }
@@ -2,11 +2,15 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryReceivedDebitId};
-use crate::params::{Expandable, Object, Timestamp};
-use crate::resources::{Currency, TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails, TreasuryTransaction};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::TreasuryReceivedDebitId;
+use crate::params::{Expandable, Object, Timestamp};
+use crate::resources::{
+    Currency, TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails,
+    TreasuryTransaction,
+};
+
 /// The resource representing a Stripe "TreasuryReceivedDebitsResourceReceivedDebit".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedDebit {
@@ -43,7 +47,8 @@ pub struct TreasuryReceivedDebit {
     pub hosted_regulatory_receipt_url: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub initiating_payment_method_details: Option<TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails>,
+    pub initiating_payment_method_details:
+        Option<TreasurySharedResourceInitiatingPaymentMethodDetailsInitiatingPaymentMethodDetails>,
 
     pub linked_flows: TreasuryReceivedDebitsResourceLinkedFlows,
 
@@ -78,7 +83,6 @@ impl Object for TreasuryReceivedDebit {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedDebitsResourceLinkedFlows {
-
     /// The DebitReversal created as a result of this ReceivedDebit being reversed.
     pub debit_reversal: Option<String>,
 
@@ -94,7 +98,6 @@ pub struct TreasuryReceivedDebitsResourceLinkedFlows {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedDebitsResourceReversalDetails {
-
     /// Time before which a ReceivedDebit can be reversed.
     pub deadline: Option<Timestamp>,
 
@@ -224,11 +227,19 @@ pub enum TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason {
 impl TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason {
     pub fn as_str(self) -> &'static str {
         match self {
-            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::AlreadyReversed => "already_reversed",
-            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::DeadlinePassed => "deadline_passed",
-            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::NetworkRestricted => "network_restricted",
+            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::AlreadyReversed => {
+                "already_reversed"
+            }
+            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::DeadlinePassed => {
+                "deadline_passed"
+            }
+            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::NetworkRestricted => {
+                "network_restricted"
+            }
             TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::Other => "other",
-            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::SourceFlowRestricted => "source_flow_restricted",
+            TreasuryReceivedDebitsResourceReversalDetailsRestrictedReason::SourceFlowRestricted => {
+                "source_flow_restricted"
+            }
         }
     }
 }
@@ -2,13 +2,13 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::resources::{Address};
 use serde::{Deserialize, Serialize};
 
+use crate::resources::Address;
+
 /// The resource representing a Stripe "TreasurySharedResourceBillingDetails".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasurySharedResourceBillingDetails {
-
     pub address: Address,
 
     /// Email address.
@@ -2,11 +2,15 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::ids::{TreasuryTransactionId};
-use crate::params::{List, Object, Timestamp};
-use crate::resources::{Currency, TreasuryTransactionEntry, TreasuryTransactionsResourceBalanceImpact, TreasuryTransactionsResourceFlowDetails};
 use serde::{Deserialize, Serialize};
 
+use crate::ids::TreasuryTransactionId;
+use crate::params::{List, Object, Timestamp};
+use crate::resources::{
+    Currency, TreasuryTransactionEntry, TreasuryTransactionsResourceBalanceImpact,
+    TreasuryTransactionsResourceFlowDetails,
+};
+
 /// The resource representing a Stripe "TreasuryTransactionsResourceTransaction".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryTransaction {
@@ -56,7 +60,8 @@ pub struct TreasuryTransaction {
     /// Status of the Transaction.
     pub status: TreasuryTransactionStatus,
 
-    pub status_transitions: TreasuryTransactionsResourceAbstractTransactionResourceStatusTransitions,
+    pub status_transitions:
+        TreasuryTransactionsResourceAbstractTransactionResourceStatusTransitions,
 }
 
 impl Object for TreasuryTransaction {
@@ -71,7 +76,6 @@ impl Object for TreasuryTransaction {
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryTransactionsResourceAbstractTransactionResourceStatusTransitions {
-
     /// Timestamp describing when the Transaction changed status to `posted`.
     pub posted_at: Option<Timestamp>,
 
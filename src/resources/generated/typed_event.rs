@@ -0,0 +1,1673 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::resources::*;
+
+/// An [`Event`], with the object of the type that its [`EventType`] is about.
+///
+/// Created with [`Event::into_typed`]. Events of a type which this crate does not know of, or whose
+/// object does not match the type of the event, are kept as [`TypedEvent::Unknown`].
+#[derive(Clone, Debug)]
+pub enum TypedEvent {
+    /// `account.application.authorized`
+    AccountApplicationAuthorized(Application),
+    /// `account.application.deauthorized`
+    AccountApplicationDeauthorized(Application),
+    /// `account.external_account.created`
+    AccountExternalAccountCreated(EventObject),
+    /// `account.external_account.deleted`
+    AccountExternalAccountDeleted(EventObject),
+    /// `account.external_account.updated`
+    AccountExternalAccountUpdated {
+        object: EventObject,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `account.updated`
+    AccountUpdated { object: Account, previous_attributes: Option<HashMap<String, Value>> },
+    /// `application_fee.created`
+    ApplicationFeeCreated(ApplicationFee),
+    /// `application_fee.refund.updated`
+    ApplicationFeeRefundUpdated {
+        object: ApplicationFeeRefund,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `application_fee.refunded`
+    ApplicationFeeRefunded(ApplicationFee),
+    /// `balance.available`
+    BalanceAvailable(Balance),
+    /// `billing_portal.configuration.created`
+    BillingPortalConfigurationCreated(BillingPortalConfiguration),
+    /// `billing_portal.configuration.updated`
+    BillingPortalConfigurationUpdated {
+        object: BillingPortalConfiguration,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `billing_portal.session.created`
+    BillingPortalSessionCreated(BillingPortalSession),
+    /// `capability.updated`
+    CapabilityUpdated { object: Capability, previous_attributes: Option<HashMap<String, Value>> },
+    /// `cash_balance.funds_available`
+    CashBalanceFundsAvailable(CashBalance),
+    /// `charge.captured`
+    ChargeCaptured(Charge),
+    /// `charge.dispute.closed`
+    ChargeDisputeClosed(Dispute),
+    /// `charge.dispute.created`
+    ChargeDisputeCreated(Dispute),
+    /// `charge.dispute.funds_reinstated`
+    ChargeDisputeFundsReinstated(Dispute),
+    /// `charge.dispute.funds_withdrawn`
+    ChargeDisputeFundsWithdrawn(Dispute),
+    /// `charge.dispute.updated`
+    ChargeDisputeUpdated { object: Dispute, previous_attributes: Option<HashMap<String, Value>> },
+    /// `charge.expired`
+    ChargeExpired(Charge),
+    /// `charge.failed`
+    ChargeFailed(Charge),
+    /// `charge.pending`
+    ChargePending(Charge),
+    /// `charge.refund.updated`
+    ChargeRefundUpdated { object: Refund, previous_attributes: Option<HashMap<String, Value>> },
+    /// `charge.refunded`
+    ChargeRefunded(Charge),
+    /// `charge.succeeded`
+    ChargeSucceeded(Charge),
+    /// `charge.updated`
+    ChargeUpdated { object: Charge, previous_attributes: Option<HashMap<String, Value>> },
+    /// `checkout.session.async_payment_failed`
+    CheckoutSessionAsyncPaymentFailed(CheckoutSession),
+    /// `checkout.session.async_payment_succeeded`
+    CheckoutSessionAsyncPaymentSucceeded(CheckoutSession),
+    /// `checkout.session.completed`
+    CheckoutSessionCompleted(CheckoutSession),
+    /// `checkout.session.expired`
+    CheckoutSessionExpired(CheckoutSession),
+    /// `climate.order.canceled`
+    ClimateOrderCanceled(ClimateOrder),
+    /// `climate.order.created`
+    ClimateOrderCreated(ClimateOrder),
+    /// `climate.order.delayed`
+    ClimateOrderDelayed(ClimateOrder),
+    /// `climate.order.delivered`
+    ClimateOrderDelivered(ClimateOrder),
+    /// `climate.order.product_substituted`
+    ClimateOrderProductSubstituted(ClimateOrder),
+    /// `climate.product.created`
+    ClimateProductCreated(ClimateProduct),
+    /// `climate.product.pricing_updated`
+    ClimateProductPricingUpdated(ClimateProduct),
+    /// `coupon.created`
+    CouponCreated(Coupon),
+    /// `coupon.deleted`
+    CouponDeleted(Coupon),
+    /// `coupon.updated`
+    CouponUpdated { object: Coupon, previous_attributes: Option<HashMap<String, Value>> },
+    /// `credit_note.created`
+    CreditNoteCreated(CreditNote),
+    /// `credit_note.updated`
+    CreditNoteUpdated { object: CreditNote, previous_attributes: Option<HashMap<String, Value>> },
+    /// `credit_note.voided`
+    CreditNoteVoided(CreditNote),
+    /// `customer.created`
+    CustomerCreated(Customer),
+    /// `customer.deleted`
+    CustomerDeleted(Customer),
+    /// `customer.discount.created`
+    CustomerDiscountCreated(Discount),
+    /// `customer.discount.deleted`
+    CustomerDiscountDeleted(Discount),
+    /// `customer.discount.updated`
+    CustomerDiscountUpdated {
+        object: Discount,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `customer.source.created`
+    CustomerSourceCreated(EventObject),
+    /// `customer.source.deleted`
+    CustomerSourceDeleted(EventObject),
+    /// `customer.source.expiring`
+    CustomerSourceExpiring(EventObject),
+    /// `customer.source.updated`
+    CustomerSourceUpdated {
+        object: EventObject,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `customer.subscription.created`
+    CustomerSubscriptionCreated(Subscription),
+    /// `customer.subscription.deleted`
+    CustomerSubscriptionDeleted(Subscription),
+    /// `customer.subscription.paused`
+    CustomerSubscriptionPaused(Subscription),
+    /// `customer.subscription.pending_update_applied`
+    CustomerSubscriptionPendingUpdateApplied(Subscription),
+    /// `customer.subscription.pending_update_expired`
+    CustomerSubscriptionPendingUpdateExpired(Subscription),
+    /// `customer.subscription.resumed`
+    CustomerSubscriptionResumed(Subscription),
+    /// `customer.subscription.trial_will_end`
+    CustomerSubscriptionTrialWillEnd(Subscription),
+    /// `customer.subscription.updated`
+    CustomerSubscriptionUpdated {
+        object: Subscription,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `customer.tax_id.created`
+    CustomerTaxIdCreated(TaxId),
+    /// `customer.tax_id.deleted`
+    CustomerTaxIdDeleted(TaxId),
+    /// `customer.tax_id.updated`
+    CustomerTaxIdUpdated { object: TaxId, previous_attributes: Option<HashMap<String, Value>> },
+    /// `customer.updated`
+    CustomerUpdated { object: Customer, previous_attributes: Option<HashMap<String, Value>> },
+    /// `customer_cash_balance_transaction.created`
+    CustomerCashBalanceTransactionCreated(CustomerCashBalanceTransaction),
+    /// `file.created`
+    FileCreated(File),
+    /// `financial_connections.account.created`
+    FinancialConnectionsAccountCreated(FinancialConnectionsAccount),
+    /// `financial_connections.account.deactivated`
+    FinancialConnectionsAccountDeactivated(FinancialConnectionsAccount),
+    /// `financial_connections.account.disconnected`
+    FinancialConnectionsAccountDisconnected(FinancialConnectionsAccount),
+    /// `financial_connections.account.reactivated`
+    FinancialConnectionsAccountReactivated(FinancialConnectionsAccount),
+    /// `financial_connections.account.refreshed_balance`
+    FinancialConnectionsAccountRefreshedBalance(FinancialConnectionsAccount),
+    /// `financial_connections.account.refreshed_transactions`
+    FinancialConnectionsAccountRefreshedTransactions(FinancialConnectionsAccount),
+    /// `identity.verification_session.canceled`
+    IdentityVerificationSessionCanceled(IdentityVerificationSession),
+    /// `identity.verification_session.created`
+    IdentityVerificationSessionCreated(IdentityVerificationSession),
+    /// `identity.verification_session.processing`
+    IdentityVerificationSessionProcessing(IdentityVerificationSession),
+    /// `identity.verification_session.redacted`
+    IdentityVerificationSessionRedacted(IdentityVerificationSession),
+    /// `identity.verification_session.requires_input`
+    IdentityVerificationSessionRequiresInput(IdentityVerificationSession),
+    /// `identity.verification_session.verified`
+    IdentityVerificationSessionVerified(IdentityVerificationSession),
+    /// `invoice.created`
+    InvoiceCreated(Invoice),
+    /// `invoice.deleted`
+    InvoiceDeleted(Invoice),
+    /// `invoice.finalization_failed`
+    InvoiceFinalizationFailed(Invoice),
+    /// `invoice.finalized`
+    InvoiceFinalized(Invoice),
+    /// `invoice.marked_uncollectible`
+    InvoiceMarkedUncollectible(Invoice),
+    /// `invoice.paid`
+    InvoicePaid(Invoice),
+    /// `invoice.payment_action_required`
+    InvoicePaymentActionRequired(Invoice),
+    /// `invoice.payment_failed`
+    InvoicePaymentFailed(Invoice),
+    /// `invoice.payment_succeeded`
+    InvoicePaymentSucceeded(Invoice),
+    /// `invoice.sent`
+    InvoiceSent(Invoice),
+    /// `invoice.upcoming`
+    InvoiceUpcoming(Invoice),
+    /// `invoice.updated`
+    InvoiceUpdated { object: Invoice, previous_attributes: Option<HashMap<String, Value>> },
+    /// `invoice.voided`
+    InvoiceVoided(Invoice),
+    /// `invoiceitem.created`
+    InvoiceItemCreated(InvoiceItem),
+    /// `invoiceitem.deleted`
+    InvoiceItemDeleted(InvoiceItem),
+    /// `issuing_authorization.created`
+    IssuingAuthorizationCreated(IssuingAuthorization),
+    /// `issuing_authorization.request`
+    IssuingAuthorizationRequest(IssuingAuthorization),
+    /// `issuing_authorization.updated`
+    IssuingAuthorizationUpdated {
+        object: IssuingAuthorization,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `issuing_card.created`
+    IssuingCardCreated(IssuingCard),
+    /// `issuing_card.updated`
+    IssuingCardUpdated { object: IssuingCard, previous_attributes: Option<HashMap<String, Value>> },
+    /// `issuing_cardholder.created`
+    IssuingCardholderCreated(IssuingCardholder),
+    /// `issuing_cardholder.updated`
+    IssuingCardholderUpdated {
+        object: IssuingCardholder,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `issuing_dispute.closed`
+    IssuingDisputeClosed(IssuingDispute),
+    /// `issuing_dispute.created`
+    IssuingDisputeCreated(IssuingDispute),
+    /// `issuing_dispute.funds_reinstated`
+    IssuingDisputeFundsReinstated(IssuingDispute),
+    /// `issuing_dispute.submitted`
+    IssuingDisputeSubmitted(IssuingDispute),
+    /// `issuing_dispute.updated`
+    IssuingDisputeUpdated {
+        object: IssuingDispute,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `issuing_token.created`
+    IssuingTokenCreated(IssuingToken),
+    /// `issuing_token.updated`
+    IssuingTokenUpdated {
+        object: IssuingToken,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `issuing_transaction.created`
+    IssuingTransactionCreated(IssuingTransaction),
+    /// `issuing_transaction.updated`
+    IssuingTransactionUpdated {
+        object: IssuingTransaction,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `mandate.updated`
+    MandateUpdated { object: Mandate, previous_attributes: Option<HashMap<String, Value>> },
+    /// `payment_intent.amount_capturable_updated`
+    PaymentIntentAmountCapturableUpdated(PaymentIntent),
+    /// `payment_intent.canceled`
+    PaymentIntentCanceled(PaymentIntent),
+    /// `payment_intent.created`
+    PaymentIntentCreated(PaymentIntent),
+    /// `payment_intent.partially_funded`
+    PaymentIntentPartiallyFunded(PaymentIntent),
+    /// `payment_intent.payment_failed`
+    PaymentIntentPaymentFailed(PaymentIntent),
+    /// `payment_intent.processing`
+    PaymentIntentProcessing(PaymentIntent),
+    /// `payment_intent.requires_action`
+    PaymentIntentRequiresAction(PaymentIntent),
+    /// `payment_intent.succeeded`
+    PaymentIntentSucceeded(PaymentIntent),
+    /// `payment_link.created`
+    PaymentLinkCreated(PaymentLink),
+    /// `payment_link.updated`
+    PaymentLinkUpdated { object: PaymentLink, previous_attributes: Option<HashMap<String, Value>> },
+    /// `payment_method.attached`
+    PaymentMethodAttached(PaymentMethod),
+    /// `payment_method.automatically_updated`
+    PaymentMethodAutomaticallyUpdated(PaymentMethod),
+    /// `payment_method.detached`
+    PaymentMethodDetached(PaymentMethod),
+    /// `payment_method.updated`
+    PaymentMethodUpdated {
+        object: PaymentMethod,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `payout.canceled`
+    PayoutCanceled(Payout),
+    /// `payout.created`
+    PayoutCreated(Payout),
+    /// `payout.failed`
+    PayoutFailed(Payout),
+    /// `payout.paid`
+    PayoutPaid(Payout),
+    /// `payout.reconciliation_completed`
+    PayoutReconciliationCompleted(Payout),
+    /// `payout.updated`
+    PayoutUpdated { object: Payout, previous_attributes: Option<HashMap<String, Value>> },
+    /// `person.created`
+    PersonCreated(Person),
+    /// `person.deleted`
+    PersonDeleted(Person),
+    /// `person.updated`
+    PersonUpdated { object: Person, previous_attributes: Option<HashMap<String, Value>> },
+    /// `plan.created`
+    PlanCreated(Plan),
+    /// `plan.deleted`
+    PlanDeleted(Plan),
+    /// `plan.updated`
+    PlanUpdated { object: Plan, previous_attributes: Option<HashMap<String, Value>> },
+    /// `price.created`
+    PriceCreated(Price),
+    /// `price.deleted`
+    PriceDeleted(Price),
+    /// `price.updated`
+    PriceUpdated { object: Price, previous_attributes: Option<HashMap<String, Value>> },
+    /// `product.created`
+    ProductCreated(Product),
+    /// `product.deleted`
+    ProductDeleted(Product),
+    /// `product.updated`
+    ProductUpdated { object: Product, previous_attributes: Option<HashMap<String, Value>> },
+    /// `promotion_code.created`
+    PromotionCodeCreated(PromotionCode),
+    /// `promotion_code.updated`
+    PromotionCodeUpdated {
+        object: PromotionCode,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `quote.accepted`
+    QuoteAccepted(Quote),
+    /// `quote.canceled`
+    QuoteCanceled(Quote),
+    /// `quote.created`
+    QuoteCreated(Quote),
+    /// `quote.finalized`
+    QuoteFinalized(Quote),
+    /// `radar.early_fraud_warning.created`
+    RadarEarlyFraudWarningCreated(RadarEarlyFraudWarning),
+    /// `radar.early_fraud_warning.updated`
+    RadarEarlyFraudWarningUpdated {
+        object: RadarEarlyFraudWarning,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `refund.created`
+    RefundCreated(Refund),
+    /// `refund.updated`
+    RefundUpdated { object: Refund, previous_attributes: Option<HashMap<String, Value>> },
+    /// `reporting.report_run.failed`
+    ReportingReportRunFailed(ReportingReportRun),
+    /// `reporting.report_run.succeeded`
+    ReportingReportRunSucceeded(ReportingReportRun),
+    /// `reporting.report_type.updated`
+    ReportingReportTypeUpdated {
+        object: ReportingReportType,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `review.closed`
+    ReviewClosed(Review),
+    /// `review.opened`
+    ReviewOpened(Review),
+    /// `setup_intent.canceled`
+    SetupIntentCanceled(SetupIntent),
+    /// `setup_intent.created`
+    SetupIntentCreated(SetupIntent),
+    /// `setup_intent.requires_action`
+    SetupIntentRequiresAction(SetupIntent),
+    /// `setup_intent.setup_failed`
+    SetupIntentSetupFailed(SetupIntent),
+    /// `setup_intent.succeeded`
+    SetupIntentSucceeded(SetupIntent),
+    /// `sigma.scheduled_query_run.created`
+    SigmaScheduledQueryRunCreated(ScheduledQueryRun),
+    /// `source.canceled`
+    SourceCanceled(Source),
+    /// `source.chargeable`
+    SourceChargeable(Source),
+    /// `source.failed`
+    SourceFailed(Source),
+    /// `source.refund_attributes_required`
+    SourceRefundAttributesRequired(Source),
+    /// `source.transaction.created`
+    SourceTransactionCreated(SourceTransaction),
+    /// `source.transaction.updated`
+    SourceTransactionUpdated {
+        object: SourceTransaction,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `subscription_schedule.aborted`
+    SubscriptionScheduleAborted(SubscriptionSchedule),
+    /// `subscription_schedule.canceled`
+    SubscriptionScheduleCanceled(SubscriptionSchedule),
+    /// `subscription_schedule.completed`
+    SubscriptionScheduleCompleted(SubscriptionSchedule),
+    /// `subscription_schedule.created`
+    SubscriptionScheduleCreated(SubscriptionSchedule),
+    /// `subscription_schedule.expiring`
+    SubscriptionScheduleExpiring(SubscriptionSchedule),
+    /// `subscription_schedule.released`
+    SubscriptionScheduleReleased(SubscriptionSchedule),
+    /// `subscription_schedule.updated`
+    SubscriptionScheduleUpdated {
+        object: SubscriptionSchedule,
+        previous_attributes: Option<HashMap<String, Value>>,
+    },
+    /// `tax.settings.updated`
+    TaxSettingsUpdated { object: TaxSettings, previous_attributes: Option<HashMap<String, Value>> },
+    /// `tax_rate.created`
+    TaxRateCreated(TaxRate),
+    /// `tax_rate.updated`
+    TaxRateUpdated { object: TaxRate, previous_attributes: Option<HashMap<String, Value>> },
+    /// `terminal.reader.action_failed`
+    TerminalReaderActionFailed(TerminalReader),
+    /// `terminal.reader.action_succeeded`
+    TerminalReaderActionSucceeded(TerminalReader),
+    /// `test_helpers.test_clock.advancing`
+    TestHelpersTestClockAdvancing(TestHelpersTestClock),
+    /// `test_helpers.test_clock.created`
+    TestHelpersTestClockCreated(TestHelpersTestClock),
+    /// `test_helpers.test_clock.deleted`
+    TestHelpersTestClockDeleted(TestHelpersTestClock),
+    /// `test_helpers.test_clock.internal_failure`
+    TestHelpersTestClockInternalFailure(TestHelpersTestClock),
+    /// `test_helpers.test_clock.ready`
+    TestHelpersTestClockReady(TestHelpersTestClock),
+    /// `topup.canceled`
+    TopupCanceled(Topup),
+    /// `topup.created`
+    TopupCreated(Topup),
+    /// `topup.failed`
+    TopupFailed(Topup),
+    /// `topup.reversed`
+    TopupReversed(Topup),
+    /// `topup.succeeded`
+    TopupSucceeded(Topup),
+    /// `transfer.created`
+    TransferCreated(Transfer),
+    /// `transfer.reversed`
+    TransferReversed(Transfer),
+    /// `transfer.updated`
+    TransferUpdated { object: Transfer, previous_attributes: Option<HashMap<String, Value>> },
+    /// `treasury.credit_reversal.created`
+    TreasuryCreditReversalCreated(TreasuryCreditReversal),
+    /// `treasury.credit_reversal.posted`
+    TreasuryCreditReversalPosted(TreasuryCreditReversal),
+    /// `treasury.debit_reversal.completed`
+    TreasuryDebitReversalCompleted(TreasuryDebitReversal),
+    /// `treasury.debit_reversal.created`
+    TreasuryDebitReversalCreated(TreasuryDebitReversal),
+    /// `treasury.debit_reversal.initial_credit_granted`
+    TreasuryDebitReversalInitialCreditGranted(TreasuryDebitReversal),
+    /// `treasury.financial_account.closed`
+    TreasuryFinancialAccountClosed(TreasuryFinancialAccount),
+    /// `treasury.financial_account.created`
+    TreasuryFinancialAccountCreated(TreasuryFinancialAccount),
+    /// `treasury.financial_account.features_status_updated`
+    TreasuryFinancialAccountFeaturesStatusUpdated(TreasuryFinancialAccount),
+    /// `treasury.inbound_transfer.canceled`
+    TreasuryInboundTransferCanceled(TreasuryInboundTransfer),
+    /// `treasury.inbound_transfer.created`
+    TreasuryInboundTransferCreated(TreasuryInboundTransfer),
+    /// `treasury.inbound_transfer.failed`
+    TreasuryInboundTransferFailed(TreasuryInboundTransfer),
+    /// `treasury.inbound_transfer.succeeded`
+    TreasuryInboundTransferSucceeded(TreasuryInboundTransfer),
+    /// `treasury.outbound_payment.canceled`
+    TreasuryOutboundPaymentCanceled(TreasuryOutboundPayment),
+    /// `treasury.outbound_payment.created`
+    TreasuryOutboundPaymentCreated(TreasuryOutboundPayment),
+    /// `treasury.outbound_payment.expected_arrival_date_updated`
+    TreasuryOutboundPaymentExpectedArrivalDateUpdated(TreasuryOutboundPayment),
+    /// `treasury.outbound_payment.failed`
+    TreasuryOutboundPaymentFailed(TreasuryOutboundPayment),
+    /// `treasury.outbound_payment.posted`
+    TreasuryOutboundPaymentPosted(TreasuryOutboundPayment),
+    /// `treasury.outbound_payment.returned`
+    TreasuryOutboundPaymentReturned(TreasuryOutboundPayment),
+    /// `treasury.outbound_transfer.canceled`
+    TreasuryOutboundTransferCanceled(TreasuryOutboundTransfer),
+    /// `treasury.outbound_transfer.created`
+    TreasuryOutboundTransferCreated(TreasuryOutboundTransfer),
+    /// `treasury.outbound_transfer.expected_arrival_date_updated`
+    TreasuryOutboundTransferExpectedArrivalDateUpdated(TreasuryOutboundTransfer),
+    /// `treasury.outbound_transfer.failed`
+    TreasuryOutboundTransferFailed(TreasuryOutboundTransfer),
+    /// `treasury.outbound_transfer.posted`
+    TreasuryOutboundTransferPosted(TreasuryOutboundTransfer),
+    /// `treasury.outbound_transfer.returned`
+    TreasuryOutboundTransferReturned(TreasuryOutboundTransfer),
+    /// `treasury.received_credit.created`
+    TreasuryReceivedCreditCreated(TreasuryReceivedCredit),
+    /// `treasury.received_credit.failed`
+    TreasuryReceivedCreditFailed(TreasuryReceivedCredit),
+    /// `treasury.received_credit.succeeded`
+    TreasuryReceivedCreditSucceeded(TreasuryReceivedCredit),
+    /// `treasury.received_debit.created`
+    TreasuryReceivedDebitCreated(TreasuryReceivedDebit),
+    /// An event of an unknown type, or with an unexpected object.
+    Unknown(Event),
+}
+
+impl TypedEvent {
+    /// The type of the event.
+    pub fn event_type(&self) -> EventType {
+        match self {
+            TypedEvent::AccountApplicationAuthorized { .. } => {
+                EventType::AccountApplicationAuthorized
+            }
+            TypedEvent::AccountApplicationDeauthorized { .. } => {
+                EventType::AccountApplicationDeauthorized
+            }
+            TypedEvent::AccountExternalAccountCreated { .. } => {
+                EventType::AccountExternalAccountCreated
+            }
+            TypedEvent::AccountExternalAccountDeleted { .. } => {
+                EventType::AccountExternalAccountDeleted
+            }
+            TypedEvent::AccountExternalAccountUpdated { .. } => {
+                EventType::AccountExternalAccountUpdated
+            }
+            TypedEvent::AccountUpdated { .. } => EventType::AccountUpdated,
+            TypedEvent::ApplicationFeeCreated { .. } => EventType::ApplicationFeeCreated,
+            TypedEvent::ApplicationFeeRefundUpdated { .. } => {
+                EventType::ApplicationFeeRefundUpdated
+            }
+            TypedEvent::ApplicationFeeRefunded { .. } => EventType::ApplicationFeeRefunded,
+            TypedEvent::BalanceAvailable { .. } => EventType::BalanceAvailable,
+            TypedEvent::BillingPortalConfigurationCreated { .. } => {
+                EventType::BillingPortalConfigurationCreated
+            }
+            TypedEvent::BillingPortalConfigurationUpdated { .. } => {
+                EventType::BillingPortalConfigurationUpdated
+            }
+            TypedEvent::BillingPortalSessionCreated { .. } => {
+                EventType::BillingPortalSessionCreated
+            }
+            TypedEvent::CapabilityUpdated { .. } => EventType::CapabilityUpdated,
+            TypedEvent::CashBalanceFundsAvailable { .. } => EventType::CashBalanceFundsAvailable,
+            TypedEvent::ChargeCaptured { .. } => EventType::ChargeCaptured,
+            TypedEvent::ChargeDisputeClosed { .. } => EventType::ChargeDisputeClosed,
+            TypedEvent::ChargeDisputeCreated { .. } => EventType::ChargeDisputeCreated,
+            TypedEvent::ChargeDisputeFundsReinstated { .. } => {
+                EventType::ChargeDisputeFundsReinstated
+            }
+            TypedEvent::ChargeDisputeFundsWithdrawn { .. } => {
+                EventType::ChargeDisputeFundsWithdrawn
+            }
+            TypedEvent::ChargeDisputeUpdated { .. } => EventType::ChargeDisputeUpdated,
+            TypedEvent::ChargeExpired { .. } => EventType::ChargeExpired,
+            TypedEvent::ChargeFailed { .. } => EventType::ChargeFailed,
+            TypedEvent::ChargePending { .. } => EventType::ChargePending,
+            TypedEvent::ChargeRefundUpdated { .. } => EventType::ChargeRefundUpdated,
+            TypedEvent::ChargeRefunded { .. } => EventType::ChargeRefunded,
+            TypedEvent::ChargeSucceeded { .. } => EventType::ChargeSucceeded,
+            TypedEvent::ChargeUpdated { .. } => EventType::ChargeUpdated,
+            TypedEvent::CheckoutSessionAsyncPaymentFailed { .. } => {
+                EventType::CheckoutSessionAsyncPaymentFailed
+            }
+            TypedEvent::CheckoutSessionAsyncPaymentSucceeded { .. } => {
+                EventType::CheckoutSessionAsyncPaymentSucceeded
+            }
+            TypedEvent::CheckoutSessionCompleted { .. } => EventType::CheckoutSessionCompleted,
+            TypedEvent::CheckoutSessionExpired { .. } => EventType::CheckoutSessionExpired,
+            TypedEvent::ClimateOrderCanceled { .. } => EventType::ClimateOrderCanceled,
+            TypedEvent::ClimateOrderCreated { .. } => EventType::ClimateOrderCreated,
+            TypedEvent::ClimateOrderDelayed { .. } => EventType::ClimateOrderDelayed,
+            TypedEvent::ClimateOrderDelivered { .. } => EventType::ClimateOrderDelivered,
+            TypedEvent::ClimateOrderProductSubstituted { .. } => {
+                EventType::ClimateOrderProductSubstituted
+            }
+            TypedEvent::ClimateProductCreated { .. } => EventType::ClimateProductCreated,
+            TypedEvent::ClimateProductPricingUpdated { .. } => {
+                EventType::ClimateProductPricingUpdated
+            }
+            TypedEvent::CouponCreated { .. } => EventType::CouponCreated,
+            TypedEvent::CouponDeleted { .. } => EventType::CouponDeleted,
+            TypedEvent::CouponUpdated { .. } => EventType::CouponUpdated,
+            TypedEvent::CreditNoteCreated { .. } => EventType::CreditNoteCreated,
+            TypedEvent::CreditNoteUpdated { .. } => EventType::CreditNoteUpdated,
+            TypedEvent::CreditNoteVoided { .. } => EventType::CreditNoteVoided,
+            TypedEvent::CustomerCreated { .. } => EventType::CustomerCreated,
+            TypedEvent::CustomerDeleted { .. } => EventType::CustomerDeleted,
+            TypedEvent::CustomerDiscountCreated { .. } => EventType::CustomerDiscountCreated,
+            TypedEvent::CustomerDiscountDeleted { .. } => EventType::CustomerDiscountDeleted,
+            TypedEvent::CustomerDiscountUpdated { .. } => EventType::CustomerDiscountUpdated,
+            TypedEvent::CustomerSourceCreated { .. } => EventType::CustomerSourceCreated,
+            TypedEvent::CustomerSourceDeleted { .. } => EventType::CustomerSourceDeleted,
+            TypedEvent::CustomerSourceExpiring { .. } => EventType::CustomerSourceExpiring,
+            TypedEvent::CustomerSourceUpdated { .. } => EventType::CustomerSourceUpdated,
+            TypedEvent::CustomerSubscriptionCreated { .. } => {
+                EventType::CustomerSubscriptionCreated
+            }
+            TypedEvent::CustomerSubscriptionDeleted { .. } => {
+                EventType::CustomerSubscriptionDeleted
+            }
+            TypedEvent::CustomerSubscriptionPaused { .. } => EventType::CustomerSubscriptionPaused,
+            TypedEvent::CustomerSubscriptionPendingUpdateApplied { .. } => {
+                EventType::CustomerSubscriptionPendingUpdateApplied
+            }
+            TypedEvent::CustomerSubscriptionPendingUpdateExpired { .. } => {
+                EventType::CustomerSubscriptionPendingUpdateExpired
+            }
+            TypedEvent::CustomerSubscriptionResumed { .. } => {
+                EventType::CustomerSubscriptionResumed
+            }
+            TypedEvent::CustomerSubscriptionTrialWillEnd { .. } => {
+                EventType::CustomerSubscriptionTrialWillEnd
+            }
+            TypedEvent::CustomerSubscriptionUpdated { .. } => {
+                EventType::CustomerSubscriptionUpdated
+            }
+            TypedEvent::CustomerTaxIdCreated { .. } => EventType::CustomerTaxIdCreated,
+            TypedEvent::CustomerTaxIdDeleted { .. } => EventType::CustomerTaxIdDeleted,
+            TypedEvent::CustomerTaxIdUpdated { .. } => EventType::CustomerTaxIdUpdated,
+            TypedEvent::CustomerUpdated { .. } => EventType::CustomerUpdated,
+            TypedEvent::CustomerCashBalanceTransactionCreated { .. } => {
+                EventType::CustomerCashBalanceTransactionCreated
+            }
+            TypedEvent::FileCreated { .. } => EventType::FileCreated,
+            TypedEvent::FinancialConnectionsAccountCreated { .. } => {
+                EventType::FinancialConnectionsAccountCreated
+            }
+            TypedEvent::FinancialConnectionsAccountDeactivated { .. } => {
+                EventType::FinancialConnectionsAccountDeactivated
+            }
+            TypedEvent::FinancialConnectionsAccountDisconnected { .. } => {
+                EventType::FinancialConnectionsAccountDisconnected
+            }
+            TypedEvent::FinancialConnectionsAccountReactivated { .. } => {
+                EventType::FinancialConnectionsAccountReactivated
+            }
+            TypedEvent::FinancialConnectionsAccountRefreshedBalance { .. } => {
+                EventType::FinancialConnectionsAccountRefreshedBalance
+            }
+            TypedEvent::FinancialConnectionsAccountRefreshedTransactions { .. } => {
+                EventType::FinancialConnectionsAccountRefreshedTransactions
+            }
+            TypedEvent::IdentityVerificationSessionCanceled { .. } => {
+                EventType::IdentityVerificationSessionCanceled
+            }
+            TypedEvent::IdentityVerificationSessionCreated { .. } => {
+                EventType::IdentityVerificationSessionCreated
+            }
+            TypedEvent::IdentityVerificationSessionProcessing { .. } => {
+                EventType::IdentityVerificationSessionProcessing
+            }
+            TypedEvent::IdentityVerificationSessionRedacted { .. } => {
+                EventType::IdentityVerificationSessionRedacted
+            }
+            TypedEvent::IdentityVerificationSessionRequiresInput { .. } => {
+                EventType::IdentityVerificationSessionRequiresInput
+            }
+            TypedEvent::IdentityVerificationSessionVerified { .. } => {
+                EventType::IdentityVerificationSessionVerified
+            }
+            TypedEvent::InvoiceCreated { .. } => EventType::InvoiceCreated,
+            TypedEvent::InvoiceDeleted { .. } => EventType::InvoiceDeleted,
+            TypedEvent::InvoiceFinalizationFailed { .. } => EventType::InvoiceFinalizationFailed,
+            TypedEvent::InvoiceFinalized { .. } => EventType::InvoiceFinalized,
+            TypedEvent::InvoiceMarkedUncollectible { .. } => EventType::InvoiceMarkedUncollectible,
+            TypedEvent::InvoicePaid { .. } => EventType::InvoicePaid,
+            TypedEvent::InvoicePaymentActionRequired { .. } => {
+                EventType::InvoicePaymentActionRequired
+            }
+            TypedEvent::InvoicePaymentFailed { .. } => EventType::InvoicePaymentFailed,
+            TypedEvent::InvoicePaymentSucceeded { .. } => EventType::InvoicePaymentSucceeded,
+            TypedEvent::InvoiceSent { .. } => EventType::InvoiceSent,
+            TypedEvent::InvoiceUpcoming { .. } => EventType::InvoiceUpcoming,
+            TypedEvent::InvoiceUpdated { .. } => EventType::InvoiceUpdated,
+            TypedEvent::InvoiceVoided { .. } => EventType::InvoiceVoided,
+            TypedEvent::InvoiceItemCreated { .. } => EventType::InvoiceItemCreated,
+            TypedEvent::InvoiceItemDeleted { .. } => EventType::InvoiceItemDeleted,
+            TypedEvent::IssuingAuthorizationCreated { .. } => {
+                EventType::IssuingAuthorizationCreated
+            }
+            TypedEvent::IssuingAuthorizationRequest { .. } => {
+                EventType::IssuingAuthorizationRequest
+            }
+            TypedEvent::IssuingAuthorizationUpdated { .. } => {
+                EventType::IssuingAuthorizationUpdated
+            }
+            TypedEvent::IssuingCardCreated { .. } => EventType::IssuingCardCreated,
+            TypedEvent::IssuingCardUpdated { .. } => EventType::IssuingCardUpdated,
+            TypedEvent::IssuingCardholderCreated { .. } => EventType::IssuingCardholderCreated,
+            TypedEvent::IssuingCardholderUpdated { .. } => EventType::IssuingCardholderUpdated,
+            TypedEvent::IssuingDisputeClosed { .. } => EventType::IssuingDisputeClosed,
+            TypedEvent::IssuingDisputeCreated { .. } => EventType::IssuingDisputeCreated,
+            TypedEvent::IssuingDisputeFundsReinstated { .. } => {
+                EventType::IssuingDisputeFundsReinstated
+            }
+            TypedEvent::IssuingDisputeSubmitted { .. } => EventType::IssuingDisputeSubmitted,
+            TypedEvent::IssuingDisputeUpdated { .. } => EventType::IssuingDisputeUpdated,
+            TypedEvent::IssuingTokenCreated { .. } => EventType::IssuingTokenCreated,
+            TypedEvent::IssuingTokenUpdated { .. } => EventType::IssuingTokenUpdated,
+            TypedEvent::IssuingTransactionCreated { .. } => EventType::IssuingTransactionCreated,
+            TypedEvent::IssuingTransactionUpdated { .. } => EventType::IssuingTransactionUpdated,
+            TypedEvent::MandateUpdated { .. } => EventType::MandateUpdated,
+            TypedEvent::PaymentIntentAmountCapturableUpdated { .. } => {
+                EventType::PaymentIntentAmountCapturableUpdated
+            }
+            TypedEvent::PaymentIntentCanceled { .. } => EventType::PaymentIntentCanceled,
+            TypedEvent::PaymentIntentCreated { .. } => EventType::PaymentIntentCreated,
+            TypedEvent::PaymentIntentPartiallyFunded { .. } => {
+                EventType::PaymentIntentPartiallyFunded
+            }
+            TypedEvent::PaymentIntentPaymentFailed { .. } => EventType::PaymentIntentPaymentFailed,
+            TypedEvent::PaymentIntentProcessing { .. } => EventType::PaymentIntentProcessing,
+            TypedEvent::PaymentIntentRequiresAction { .. } => {
+                EventType::PaymentIntentRequiresAction
+            }
+            TypedEvent::PaymentIntentSucceeded { .. } => EventType::PaymentIntentSucceeded,
+            TypedEvent::PaymentLinkCreated { .. } => EventType::PaymentLinkCreated,
+            TypedEvent::PaymentLinkUpdated { .. } => EventType::PaymentLinkUpdated,
+            TypedEvent::PaymentMethodAttached { .. } => EventType::PaymentMethodAttached,
+            TypedEvent::PaymentMethodAutomaticallyUpdated { .. } => {
+                EventType::PaymentMethodAutomaticallyUpdated
+            }
+            TypedEvent::PaymentMethodDetached { .. } => EventType::PaymentMethodDetached,
+            TypedEvent::PaymentMethodUpdated { .. } => EventType::PaymentMethodUpdated,
+            TypedEvent::PayoutCanceled { .. } => EventType::PayoutCanceled,
+            TypedEvent::PayoutCreated { .. } => EventType::PayoutCreated,
+            TypedEvent::PayoutFailed { .. } => EventType::PayoutFailed,
+            TypedEvent::PayoutPaid { .. } => EventType::PayoutPaid,
+            TypedEvent::PayoutReconciliationCompleted { .. } => {
+                EventType::PayoutReconciliationCompleted
+            }
+            TypedEvent::PayoutUpdated { .. } => EventType::PayoutUpdated,
+            TypedEvent::PersonCreated { .. } => EventType::PersonCreated,
+            TypedEvent::PersonDeleted { .. } => EventType::PersonDeleted,
+            TypedEvent::PersonUpdated { .. } => EventType::PersonUpdated,
+            TypedEvent::PlanCreated { .. } => EventType::PlanCreated,
+            TypedEvent::PlanDeleted { .. } => EventType::PlanDeleted,
+            TypedEvent::PlanUpdated { .. } => EventType::PlanUpdated,
+            TypedEvent::PriceCreated { .. } => EventType::PriceCreated,
+            TypedEvent::PriceDeleted { .. } => EventType::PriceDeleted,
+            TypedEvent::PriceUpdated { .. } => EventType::PriceUpdated,
+            TypedEvent::ProductCreated { .. } => EventType::ProductCreated,
+            TypedEvent::ProductDeleted { .. } => EventType::ProductDeleted,
+            TypedEvent::ProductUpdated { .. } => EventType::ProductUpdated,
+            TypedEvent::PromotionCodeCreated { .. } => EventType::PromotionCodeCreated,
+            TypedEvent::PromotionCodeUpdated { .. } => EventType::PromotionCodeUpdated,
+            TypedEvent::QuoteAccepted { .. } => EventType::QuoteAccepted,
+            TypedEvent::QuoteCanceled { .. } => EventType::QuoteCanceled,
+            TypedEvent::QuoteCreated { .. } => EventType::QuoteCreated,
+            TypedEvent::QuoteFinalized { .. } => EventType::QuoteFinalized,
+            TypedEvent::RadarEarlyFraudWarningCreated { .. } => {
+                EventType::RadarEarlyFraudWarningCreated
+            }
+            TypedEvent::RadarEarlyFraudWarningUpdated { .. } => {
+                EventType::RadarEarlyFraudWarningUpdated
+            }
+            TypedEvent::RefundCreated { .. } => EventType::RefundCreated,
+            TypedEvent::RefundUpdated { .. } => EventType::RefundUpdated,
+            TypedEvent::ReportingReportRunFailed { .. } => EventType::ReportingReportRunFailed,
+            TypedEvent::ReportingReportRunSucceeded { .. } => {
+                EventType::ReportingReportRunSucceeded
+            }
+            TypedEvent::ReportingReportTypeUpdated { .. } => EventType::ReportingReportTypeUpdated,
+            TypedEvent::ReviewClosed { .. } => EventType::ReviewClosed,
+            TypedEvent::ReviewOpened { .. } => EventType::ReviewOpened,
+            TypedEvent::SetupIntentCanceled { .. } => EventType::SetupIntentCanceled,
+            TypedEvent::SetupIntentCreated { .. } => EventType::SetupIntentCreated,
+            TypedEvent::SetupIntentRequiresAction { .. } => EventType::SetupIntentRequiresAction,
+            TypedEvent::SetupIntentSetupFailed { .. } => EventType::SetupIntentSetupFailed,
+            TypedEvent::SetupIntentSucceeded { .. } => EventType::SetupIntentSucceeded,
+            TypedEvent::SigmaScheduledQueryRunCreated { .. } => {
+                EventType::SigmaScheduledQueryRunCreated
+            }
+            TypedEvent::SourceCanceled { .. } => EventType::SourceCanceled,
+            TypedEvent::SourceChargeable { .. } => EventType::SourceChargeable,
+            TypedEvent::SourceFailed { .. } => EventType::SourceFailed,
+            TypedEvent::SourceRefundAttributesRequired { .. } => {
+                EventType::SourceRefundAttributesRequired
+            }
+            TypedEvent::SourceTransactionCreated { .. } => EventType::SourceTransactionCreated,
+            TypedEvent::SourceTransactionUpdated { .. } => EventType::SourceTransactionUpdated,
+            TypedEvent::SubscriptionScheduleAborted { .. } => {
+                EventType::SubscriptionScheduleAborted
+            }
+            TypedEvent::SubscriptionScheduleCanceled { .. } => {
+                EventType::SubscriptionScheduleCanceled
+            }
+            TypedEvent::SubscriptionScheduleCompleted { .. } => {
+                EventType::SubscriptionScheduleCompleted
+            }
+            TypedEvent::SubscriptionScheduleCreated { .. } => {
+                EventType::SubscriptionScheduleCreated
+            }
+            TypedEvent::SubscriptionScheduleExpiring { .. } => {
+                EventType::SubscriptionScheduleExpiring
+            }
+            TypedEvent::SubscriptionScheduleReleased { .. } => {
+                EventType::SubscriptionScheduleReleased
+            }
+            TypedEvent::SubscriptionScheduleUpdated { .. } => {
+                EventType::SubscriptionScheduleUpdated
+            }
+            TypedEvent::TaxSettingsUpdated { .. } => EventType::TaxSettingsUpdated,
+            TypedEvent::TaxRateCreated { .. } => EventType::TaxRateCreated,
+            TypedEvent::TaxRateUpdated { .. } => EventType::TaxRateUpdated,
+            TypedEvent::TerminalReaderActionFailed { .. } => EventType::TerminalReaderActionFailed,
+            TypedEvent::TerminalReaderActionSucceeded { .. } => {
+                EventType::TerminalReaderActionSucceeded
+            }
+            TypedEvent::TestHelpersTestClockAdvancing { .. } => {
+                EventType::TestHelpersTestClockAdvancing
+            }
+            TypedEvent::TestHelpersTestClockCreated { .. } => {
+                EventType::TestHelpersTestClockCreated
+            }
+            TypedEvent::TestHelpersTestClockDeleted { .. } => {
+                EventType::TestHelpersTestClockDeleted
+            }
+            TypedEvent::TestHelpersTestClockInternalFailure { .. } => {
+                EventType::TestHelpersTestClockInternalFailure
+            }
+            TypedEvent::TestHelpersTestClockReady { .. } => EventType::TestHelpersTestClockReady,
+            TypedEvent::TopupCanceled { .. } => EventType::TopupCanceled,
+            TypedEvent::TopupCreated { .. } => EventType::TopupCreated,
+            TypedEvent::TopupFailed { .. } => EventType::TopupFailed,
+            TypedEvent::TopupReversed { .. } => EventType::TopupReversed,
+            TypedEvent::TopupSucceeded { .. } => EventType::TopupSucceeded,
+            TypedEvent::TransferCreated { .. } => EventType::TransferCreated,
+            TypedEvent::TransferReversed { .. } => EventType::TransferReversed,
+            TypedEvent::TransferUpdated { .. } => EventType::TransferUpdated,
+            TypedEvent::TreasuryCreditReversalCreated { .. } => {
+                EventType::TreasuryCreditReversalCreated
+            }
+            TypedEvent::TreasuryCreditReversalPosted { .. } => {
+                EventType::TreasuryCreditReversalPosted
+            }
+            TypedEvent::TreasuryDebitReversalCompleted { .. } => {
+                EventType::TreasuryDebitReversalCompleted
+            }
+            TypedEvent::TreasuryDebitReversalCreated { .. } => {
+                EventType::TreasuryDebitReversalCreated
+            }
+            TypedEvent::TreasuryDebitReversalInitialCreditGranted { .. } => {
+                EventType::TreasuryDebitReversalInitialCreditGranted
+            }
+            TypedEvent::TreasuryFinancialAccountClosed { .. } => {
+                EventType::TreasuryFinancialAccountClosed
+            }
+            TypedEvent::TreasuryFinancialAccountCreated { .. } => {
+                EventType::TreasuryFinancialAccountCreated
+            }
+            TypedEvent::TreasuryFinancialAccountFeaturesStatusUpdated { .. } => {
+                EventType::TreasuryFinancialAccountFeaturesStatusUpdated
+            }
+            TypedEvent::TreasuryInboundTransferCanceled { .. } => {
+                EventType::TreasuryInboundTransferCanceled
+            }
+            TypedEvent::TreasuryInboundTransferCreated { .. } => {
+                EventType::TreasuryInboundTransferCreated
+            }
+            TypedEvent::TreasuryInboundTransferFailed { .. } => {
+                EventType::TreasuryInboundTransferFailed
+            }
+            TypedEvent::TreasuryInboundTransferSucceeded { .. } => {
+                EventType::TreasuryInboundTransferSucceeded
+            }
+            TypedEvent::TreasuryOutboundPaymentCanceled { .. } => {
+                EventType::TreasuryOutboundPaymentCanceled
+            }
+            TypedEvent::TreasuryOutboundPaymentCreated { .. } => {
+                EventType::TreasuryOutboundPaymentCreated
+            }
+            TypedEvent::TreasuryOutboundPaymentExpectedArrivalDateUpdated { .. } => {
+                EventType::TreasuryOutboundPaymentExpectedArrivalDateUpdated
+            }
+            TypedEvent::TreasuryOutboundPaymentFailed { .. } => {
+                EventType::TreasuryOutboundPaymentFailed
+            }
+            TypedEvent::TreasuryOutboundPaymentPosted { .. } => {
+                EventType::TreasuryOutboundPaymentPosted
+            }
+            TypedEvent::TreasuryOutboundPaymentReturned { .. } => {
+                EventType::TreasuryOutboundPaymentReturned
+            }
+            TypedEvent::TreasuryOutboundTransferCanceled { .. } => {
+                EventType::TreasuryOutboundTransferCanceled
+            }
+            TypedEvent::TreasuryOutboundTransferCreated { .. } => {
+                EventType::TreasuryOutboundTransferCreated
+            }
+            TypedEvent::TreasuryOutboundTransferExpectedArrivalDateUpdated { .. } => {
+                EventType::TreasuryOutboundTransferExpectedArrivalDateUpdated
+            }
+            TypedEvent::TreasuryOutboundTransferFailed { .. } => {
+                EventType::TreasuryOutboundTransferFailed
+            }
+            TypedEvent::TreasuryOutboundTransferPosted { .. } => {
+                EventType::TreasuryOutboundTransferPosted
+            }
+            TypedEvent::TreasuryOutboundTransferReturned { .. } => {
+                EventType::TreasuryOutboundTransferReturned
+            }
+            TypedEvent::TreasuryReceivedCreditCreated { .. } => {
+                EventType::TreasuryReceivedCreditCreated
+            }
+            TypedEvent::TreasuryReceivedCreditFailed { .. } => {
+                EventType::TreasuryReceivedCreditFailed
+            }
+            TypedEvent::TreasuryReceivedCreditSucceeded { .. } => {
+                EventType::TreasuryReceivedCreditSucceeded
+            }
+            TypedEvent::TreasuryReceivedDebitCreated { .. } => {
+                EventType::TreasuryReceivedDebitCreated
+            }
+            TypedEvent::Unknown(event) => event.type_,
+        }
+    }
+}
+
+impl Event {
+    /// Convert the event into a [`TypedEvent`], so that its object may be matched on together with its type.
+    pub fn into_typed(self) -> TypedEvent {
+        let previous_attributes = self.data.previous_attributes;
+        match (self.type_, self.data.object) {
+            (EventType::AccountApplicationAuthorized, EventObject::Application(object)) => {
+                TypedEvent::AccountApplicationAuthorized(object)
+            }
+            (EventType::AccountApplicationDeauthorized, EventObject::Application(object)) => {
+                TypedEvent::AccountApplicationDeauthorized(object)
+            }
+            (
+                EventType::AccountExternalAccountCreated,
+                object @ (EventObject::BankAccount(_) | EventObject::Card(_)),
+            ) => TypedEvent::AccountExternalAccountCreated(object),
+            (
+                EventType::AccountExternalAccountDeleted,
+                object @ (EventObject::BankAccount(_) | EventObject::Card(_)),
+            ) => TypedEvent::AccountExternalAccountDeleted(object),
+            (
+                EventType::AccountExternalAccountUpdated,
+                object @ (EventObject::BankAccount(_) | EventObject::Card(_)),
+            ) => TypedEvent::AccountExternalAccountUpdated { object, previous_attributes },
+            (EventType::AccountUpdated, EventObject::Account(object)) => {
+                TypedEvent::AccountUpdated { object, previous_attributes }
+            }
+            (EventType::ApplicationFeeCreated, EventObject::ApplicationFee(object)) => {
+                TypedEvent::ApplicationFeeCreated(object)
+            }
+            (EventType::ApplicationFeeRefundUpdated, EventObject::ApplicationFeeRefund(object)) => {
+                TypedEvent::ApplicationFeeRefundUpdated { object, previous_attributes }
+            }
+            (EventType::ApplicationFeeRefunded, EventObject::ApplicationFee(object)) => {
+                TypedEvent::ApplicationFeeRefunded(object)
+            }
+            (EventType::BalanceAvailable, EventObject::Balance(object)) => {
+                TypedEvent::BalanceAvailable(object)
+            }
+            (
+                EventType::BillingPortalConfigurationCreated,
+                EventObject::BillingPortalConfiguration(object),
+            ) => TypedEvent::BillingPortalConfigurationCreated(object),
+            (
+                EventType::BillingPortalConfigurationUpdated,
+                EventObject::BillingPortalConfiguration(object),
+            ) => TypedEvent::BillingPortalConfigurationUpdated { object, previous_attributes },
+            (EventType::BillingPortalSessionCreated, EventObject::BillingPortalSession(object)) => {
+                TypedEvent::BillingPortalSessionCreated(object)
+            }
+            (EventType::CapabilityUpdated, EventObject::Capability(object)) => {
+                TypedEvent::CapabilityUpdated { object, previous_attributes }
+            }
+            (EventType::CashBalanceFundsAvailable, EventObject::CashBalance(object)) => {
+                TypedEvent::CashBalanceFundsAvailable(object)
+            }
+            (EventType::ChargeCaptured, EventObject::Charge(object)) => {
+                TypedEvent::ChargeCaptured(object)
+            }
+            (EventType::ChargeDisputeClosed, EventObject::Dispute(object)) => {
+                TypedEvent::ChargeDisputeClosed(object)
+            }
+            (EventType::ChargeDisputeCreated, EventObject::Dispute(object)) => {
+                TypedEvent::ChargeDisputeCreated(object)
+            }
+            (EventType::ChargeDisputeFundsReinstated, EventObject::Dispute(object)) => {
+                TypedEvent::ChargeDisputeFundsReinstated(object)
+            }
+            (EventType::ChargeDisputeFundsWithdrawn, EventObject::Dispute(object)) => {
+                TypedEvent::ChargeDisputeFundsWithdrawn(object)
+            }
+            (EventType::ChargeDisputeUpdated, EventObject::Dispute(object)) => {
+                TypedEvent::ChargeDisputeUpdated { object, previous_attributes }
+            }
+            (EventType::ChargeExpired, EventObject::Charge(object)) => {
+                TypedEvent::ChargeExpired(object)
+            }
+            (EventType::ChargeFailed, EventObject::Charge(object)) => {
+                TypedEvent::ChargeFailed(object)
+            }
+            (EventType::ChargePending, EventObject::Charge(object)) => {
+                TypedEvent::ChargePending(object)
+            }
+            (EventType::ChargeRefundUpdated, EventObject::Refund(object)) => {
+                TypedEvent::ChargeRefundUpdated { object, previous_attributes }
+            }
+            (EventType::ChargeRefunded, EventObject::Charge(object)) => {
+                TypedEvent::ChargeRefunded(object)
+            }
+            (EventType::ChargeSucceeded, EventObject::Charge(object)) => {
+                TypedEvent::ChargeSucceeded(object)
+            }
+            (EventType::ChargeUpdated, EventObject::Charge(object)) => {
+                TypedEvent::ChargeUpdated { object, previous_attributes }
+            }
+            (
+                EventType::CheckoutSessionAsyncPaymentFailed,
+                EventObject::CheckoutSession(object),
+            ) => TypedEvent::CheckoutSessionAsyncPaymentFailed(object),
+            (
+                EventType::CheckoutSessionAsyncPaymentSucceeded,
+                EventObject::CheckoutSession(object),
+            ) => TypedEvent::CheckoutSessionAsyncPaymentSucceeded(object),
+            (EventType::CheckoutSessionCompleted, EventObject::CheckoutSession(object)) => {
+                TypedEvent::CheckoutSessionCompleted(object)
+            }
+            (EventType::CheckoutSessionExpired, EventObject::CheckoutSession(object)) => {
+                TypedEvent::CheckoutSessionExpired(object)
+            }
+            (EventType::ClimateOrderCanceled, EventObject::ClimateOrder(object)) => {
+                TypedEvent::ClimateOrderCanceled(object)
+            }
+            (EventType::ClimateOrderCreated, EventObject::ClimateOrder(object)) => {
+                TypedEvent::ClimateOrderCreated(object)
+            }
+            (EventType::ClimateOrderDelayed, EventObject::ClimateOrder(object)) => {
+                TypedEvent::ClimateOrderDelayed(object)
+            }
+            (EventType::ClimateOrderDelivered, EventObject::ClimateOrder(object)) => {
+                TypedEvent::ClimateOrderDelivered(object)
+            }
+            (EventType::ClimateOrderProductSubstituted, EventObject::ClimateOrder(object)) => {
+                TypedEvent::ClimateOrderProductSubstituted(object)
+            }
+            (EventType::ClimateProductCreated, EventObject::ClimateProduct(object)) => {
+                TypedEvent::ClimateProductCreated(object)
+            }
+            (EventType::ClimateProductPricingUpdated, EventObject::ClimateProduct(object)) => {
+                TypedEvent::ClimateProductPricingUpdated(object)
+            }
+            (EventType::CouponCreated, EventObject::Coupon(object)) => {
+                TypedEvent::CouponCreated(object)
+            }
+            (EventType::CouponDeleted, EventObject::Coupon(object)) => {
+                TypedEvent::CouponDeleted(object)
+            }
+            (EventType::CouponUpdated, EventObject::Coupon(object)) => {
+                TypedEvent::CouponUpdated { object, previous_attributes }
+            }
+            (EventType::CreditNoteCreated, EventObject::CreditNote(object)) => {
+                TypedEvent::CreditNoteCreated(object)
+            }
+            (EventType::CreditNoteUpdated, EventObject::CreditNote(object)) => {
+                TypedEvent::CreditNoteUpdated { object, previous_attributes }
+            }
+            (EventType::CreditNoteVoided, EventObject::CreditNote(object)) => {
+                TypedEvent::CreditNoteVoided(object)
+            }
+            (EventType::CustomerCreated, EventObject::Customer(object)) => {
+                TypedEvent::CustomerCreated(object)
+            }
+            (EventType::CustomerDeleted, EventObject::Customer(object)) => {
+                TypedEvent::CustomerDeleted(object)
+            }
+            (EventType::CustomerDiscountCreated, EventObject::Discount(object)) => {
+                TypedEvent::CustomerDiscountCreated(object)
+            }
+            (EventType::CustomerDiscountDeleted, EventObject::Discount(object)) => {
+                TypedEvent::CustomerDiscountDeleted(object)
+            }
+            (EventType::CustomerDiscountUpdated, EventObject::Discount(object)) => {
+                TypedEvent::CustomerDiscountUpdated { object, previous_attributes }
+            }
+            (
+                EventType::CustomerSourceCreated,
+                object @ (EventObject::BankAccount(_)
+                | EventObject::Card(_)
+                | EventObject::Source(_)),
+            ) => TypedEvent::CustomerSourceCreated(object),
+            (
+                EventType::CustomerSourceDeleted,
+                object @ (EventObject::BankAccount(_)
+                | EventObject::Card(_)
+                | EventObject::Source(_)),
+            ) => TypedEvent::CustomerSourceDeleted(object),
+            (
+                EventType::CustomerSourceExpiring,
+                object @ (EventObject::BankAccount(_)
+                | EventObject::Card(_)
+                | EventObject::Source(_)),
+            ) => TypedEvent::CustomerSourceExpiring(object),
+            (
+                EventType::CustomerSourceUpdated,
+                object @ (EventObject::BankAccount(_)
+                | EventObject::Card(_)
+                | EventObject::Source(_)),
+            ) => TypedEvent::CustomerSourceUpdated { object, previous_attributes },
+            (EventType::CustomerSubscriptionCreated, EventObject::Subscription(object)) => {
+                TypedEvent::CustomerSubscriptionCreated(object)
+            }
+            (EventType::CustomerSubscriptionDeleted, EventObject::Subscription(object)) => {
+                TypedEvent::CustomerSubscriptionDeleted(object)
+            }
+            (EventType::CustomerSubscriptionPaused, EventObject::Subscription(object)) => {
+                TypedEvent::CustomerSubscriptionPaused(object)
+            }
+            (
+                EventType::CustomerSubscriptionPendingUpdateApplied,
+                EventObject::Subscription(object),
+            ) => TypedEvent::CustomerSubscriptionPendingUpdateApplied(object),
+            (
+                EventType::CustomerSubscriptionPendingUpdateExpired,
+                EventObject::Subscription(object),
+            ) => TypedEvent::CustomerSubscriptionPendingUpdateExpired(object),
+            (EventType::CustomerSubscriptionResumed, EventObject::Subscription(object)) => {
+                TypedEvent::CustomerSubscriptionResumed(object)
+            }
+            (EventType::CustomerSubscriptionTrialWillEnd, EventObject::Subscription(object)) => {
+                TypedEvent::CustomerSubscriptionTrialWillEnd(object)
+            }
+            (EventType::CustomerSubscriptionUpdated, EventObject::Subscription(object)) => {
+                TypedEvent::CustomerSubscriptionUpdated { object, previous_attributes }
+            }
+            (EventType::CustomerTaxIdCreated, EventObject::TaxId(object)) => {
+                TypedEvent::CustomerTaxIdCreated(object)
+            }
+            (EventType::CustomerTaxIdDeleted, EventObject::TaxId(object)) => {
+                TypedEvent::CustomerTaxIdDeleted(object)
+            }
+            (EventType::CustomerTaxIdUpdated, EventObject::TaxId(object)) => {
+                TypedEvent::CustomerTaxIdUpdated { object, previous_attributes }
+            }
+            (EventType::CustomerUpdated, EventObject::Customer(object)) => {
+                TypedEvent::CustomerUpdated { object, previous_attributes }
+            }
+            (
+                EventType::CustomerCashBalanceTransactionCreated,
+                EventObject::CustomerCashBalanceTransaction(object),
+            ) => TypedEvent::CustomerCashBalanceTransactionCreated(object),
+            (EventType::FileCreated, EventObject::File(object)) => TypedEvent::FileCreated(object),
+            (
+                EventType::FinancialConnectionsAccountCreated,
+                EventObject::FinancialConnectionsAccount(object),
+            ) => TypedEvent::FinancialConnectionsAccountCreated(object),
+            (
+                EventType::FinancialConnectionsAccountDeactivated,
+                EventObject::FinancialConnectionsAccount(object),
+            ) => TypedEvent::FinancialConnectionsAccountDeactivated(object),
+            (
+                EventType::FinancialConnectionsAccountDisconnected,
+                EventObject::FinancialConnectionsAccount(object),
+            ) => TypedEvent::FinancialConnectionsAccountDisconnected(object),
+            (
+                EventType::FinancialConnectionsAccountReactivated,
+                EventObject::FinancialConnectionsAccount(object),
+            ) => TypedEvent::FinancialConnectionsAccountReactivated(object),
+            (
+                EventType::FinancialConnectionsAccountRefreshedBalance,
+                EventObject::FinancialConnectionsAccount(object),
+            ) => TypedEvent::FinancialConnectionsAccountRefreshedBalance(object),
+            (
+                EventType::FinancialConnectionsAccountRefreshedTransactions,
+                EventObject::FinancialConnectionsAccount(object),
+            ) => TypedEvent::FinancialConnectionsAccountRefreshedTransactions(object),
+            (
+                EventType::IdentityVerificationSessionCanceled,
+                EventObject::IdentityVerificationSession(object),
+            ) => TypedEvent::IdentityVerificationSessionCanceled(object),
+            (
+                EventType::IdentityVerificationSessionCreated,
+                EventObject::IdentityVerificationSession(object),
+            ) => TypedEvent::IdentityVerificationSessionCreated(object),
+            (
+                EventType::IdentityVerificationSessionProcessing,
+                EventObject::IdentityVerificationSession(object),
+            ) => TypedEvent::IdentityVerificationSessionProcessing(object),
+            (
+                EventType::IdentityVerificationSessionRedacted,
+                EventObject::IdentityVerificationSession(object),
+            ) => TypedEvent::IdentityVerificationSessionRedacted(object),
+            (
+                EventType::IdentityVerificationSessionRequiresInput,
+                EventObject::IdentityVerificationSession(object),
+            ) => TypedEvent::IdentityVerificationSessionRequiresInput(object),
+            (
+                EventType::IdentityVerificationSessionVerified,
+                EventObject::IdentityVerificationSession(object),
+            ) => TypedEvent::IdentityVerificationSessionVerified(object),
+            (EventType::InvoiceCreated, EventObject::Invoice(object)) => {
+                TypedEvent::InvoiceCreated(object)
+            }
+            (EventType::InvoiceDeleted, EventObject::Invoice(object)) => {
+                TypedEvent::InvoiceDeleted(object)
+            }
+            (EventType::InvoiceFinalizationFailed, EventObject::Invoice(object)) => {
+                TypedEvent::InvoiceFinalizationFailed(object)
+            }
+            (EventType::InvoiceFinalized, EventObject::Invoice(object)) => {
+                TypedEvent::InvoiceFinalized(object)
+            }
+            (EventType::InvoiceMarkedUncollectible, EventObject::Invoice(object)) => {
+                TypedEvent::InvoiceMarkedUncollectible(object)
+            }
+            (EventType::InvoicePaid, EventObject::Invoice(object)) => {
+                TypedEvent::InvoicePaid(object)
+            }
+            (EventType::InvoicePaymentActionRequired, EventObject::Invoice(object)) => {
+                TypedEvent::InvoicePaymentActionRequired(object)
+            }
+            (EventType::InvoicePaymentFailed, EventObject::Invoice(object)) => {
+                TypedEvent::InvoicePaymentFailed(object)
+            }
+            (EventType::InvoicePaymentSucceeded, EventObject::Invoice(object)) => {
+                TypedEvent::InvoicePaymentSucceeded(object)
+            }
+            (EventType::InvoiceSent, EventObject::Invoice(object)) => {
+                TypedEvent::InvoiceSent(object)
+            }
+            (EventType::InvoiceUpcoming, EventObject::Invoice(object)) => {
+                TypedEvent::InvoiceUpcoming(object)
+            }
+            (EventType::InvoiceUpdated, EventObject::Invoice(object)) => {
+                TypedEvent::InvoiceUpdated { object, previous_attributes }
+            }
+            (EventType::InvoiceVoided, EventObject::Invoice(object)) => {
+                TypedEvent::InvoiceVoided(object)
+            }
+            (EventType::InvoiceItemCreated, EventObject::InvoiceItem(object)) => {
+                TypedEvent::InvoiceItemCreated(object)
+            }
+            (EventType::InvoiceItemDeleted, EventObject::InvoiceItem(object)) => {
+                TypedEvent::InvoiceItemDeleted(object)
+            }
+            (EventType::IssuingAuthorizationCreated, EventObject::IssuingAuthorization(object)) => {
+                TypedEvent::IssuingAuthorizationCreated(object)
+            }
+            (EventType::IssuingAuthorizationRequest, EventObject::IssuingAuthorization(object)) => {
+                TypedEvent::IssuingAuthorizationRequest(object)
+            }
+            (EventType::IssuingAuthorizationUpdated, EventObject::IssuingAuthorization(object)) => {
+                TypedEvent::IssuingAuthorizationUpdated { object, previous_attributes }
+            }
+            (EventType::IssuingCardCreated, EventObject::IssuingCard(object)) => {
+                TypedEvent::IssuingCardCreated(object)
+            }
+            (EventType::IssuingCardUpdated, EventObject::IssuingCard(object)) => {
+                TypedEvent::IssuingCardUpdated { object, previous_attributes }
+            }
+            (EventType::IssuingCardholderCreated, EventObject::IssuingCardholder(object)) => {
+                TypedEvent::IssuingCardholderCreated(object)
+            }
+            (EventType::IssuingCardholderUpdated, EventObject::IssuingCardholder(object)) => {
+                TypedEvent::IssuingCardholderUpdated { object, previous_attributes }
+            }
+            (EventType::IssuingDisputeClosed, EventObject::IssuingDispute(object)) => {
+                TypedEvent::IssuingDisputeClosed(object)
+            }
+            (EventType::IssuingDisputeCreated, EventObject::IssuingDispute(object)) => {
+                TypedEvent::IssuingDisputeCreated(object)
+            }
+            (EventType::IssuingDisputeFundsReinstated, EventObject::IssuingDispute(object)) => {
+                TypedEvent::IssuingDisputeFundsReinstated(object)
+            }
+            (EventType::IssuingDisputeSubmitted, EventObject::IssuingDispute(object)) => {
+                TypedEvent::IssuingDisputeSubmitted(object)
+            }
+            (EventType::IssuingDisputeUpdated, EventObject::IssuingDispute(object)) => {
+                TypedEvent::IssuingDisputeUpdated { object, previous_attributes }
+            }
+            (EventType::IssuingTokenCreated, EventObject::IssuingToken(object)) => {
+                TypedEvent::IssuingTokenCreated(object)
+            }
+            (EventType::IssuingTokenUpdated, EventObject::IssuingToken(object)) => {
+                TypedEvent::IssuingTokenUpdated { object, previous_attributes }
+            }
+            (EventType::IssuingTransactionCreated, EventObject::IssuingTransaction(object)) => {
+                TypedEvent::IssuingTransactionCreated(object)
+            }
+            (EventType::IssuingTransactionUpdated, EventObject::IssuingTransaction(object)) => {
+                TypedEvent::IssuingTransactionUpdated { object, previous_attributes }
+            }
+            (EventType::MandateUpdated, EventObject::Mandate(object)) => {
+                TypedEvent::MandateUpdated { object, previous_attributes }
+            }
+            (
+                EventType::PaymentIntentAmountCapturableUpdated,
+                EventObject::PaymentIntent(object),
+            ) => TypedEvent::PaymentIntentAmountCapturableUpdated(object),
+            (EventType::PaymentIntentCanceled, EventObject::PaymentIntent(object)) => {
+                TypedEvent::PaymentIntentCanceled(object)
+            }
+            (EventType::PaymentIntentCreated, EventObject::PaymentIntent(object)) => {
+                TypedEvent::PaymentIntentCreated(object)
+            }
+            (EventType::PaymentIntentPartiallyFunded, EventObject::PaymentIntent(object)) => {
+                TypedEvent::PaymentIntentPartiallyFunded(object)
+            }
+            (EventType::PaymentIntentPaymentFailed, EventObject::PaymentIntent(object)) => {
+                TypedEvent::PaymentIntentPaymentFailed(object)
+            }
+            (EventType::PaymentIntentProcessing, EventObject::PaymentIntent(object)) => {
+                TypedEvent::PaymentIntentProcessing(object)
+            }
+            (EventType::PaymentIntentRequiresAction, EventObject::PaymentIntent(object)) => {
+                TypedEvent::PaymentIntentRequiresAction(object)
+            }
+            (EventType::PaymentIntentSucceeded, EventObject::PaymentIntent(object)) => {
+                TypedEvent::PaymentIntentSucceeded(object)
+            }
+            (EventType::PaymentLinkCreated, EventObject::PaymentLink(object)) => {
+                TypedEvent::PaymentLinkCreated(object)
+            }
+            (EventType::PaymentLinkUpdated, EventObject::PaymentLink(object)) => {
+                TypedEvent::PaymentLinkUpdated { object, previous_attributes }
+            }
+            (EventType::PaymentMethodAttached, EventObject::PaymentMethod(object)) => {
+                TypedEvent::PaymentMethodAttached(object)
+            }
+            (EventType::PaymentMethodAutomaticallyUpdated, EventObject::PaymentMethod(object)) => {
+                TypedEvent::PaymentMethodAutomaticallyUpdated(object)
+            }
+            (EventType::PaymentMethodDetached, EventObject::PaymentMethod(object)) => {
+                TypedEvent::PaymentMethodDetached(object)
+            }
+            (EventType::PaymentMethodUpdated, EventObject::PaymentMethod(object)) => {
+                TypedEvent::PaymentMethodUpdated { object, previous_attributes }
+            }
+            (EventType::PayoutCanceled, EventObject::Payout(object)) => {
+                TypedEvent::PayoutCanceled(object)
+            }
+            (EventType::PayoutCreated, EventObject::Payout(object)) => {
+                TypedEvent::PayoutCreated(object)
+            }
+            (EventType::PayoutFailed, EventObject::Payout(object)) => {
+                TypedEvent::PayoutFailed(object)
+            }
+            (EventType::PayoutPaid, EventObject::Payout(object)) => TypedEvent::PayoutPaid(object),
+            (EventType::PayoutReconciliationCompleted, EventObject::Payout(object)) => {
+                TypedEvent::PayoutReconciliationCompleted(object)
+            }
+            (EventType::PayoutUpdated, EventObject::Payout(object)) => {
+                TypedEvent::PayoutUpdated { object, previous_attributes }
+            }
+            (EventType::PersonCreated, EventObject::Person(object)) => {
+                TypedEvent::PersonCreated(object)
+            }
+            (EventType::PersonDeleted, EventObject::Person(object)) => {
+                TypedEvent::PersonDeleted(object)
+            }
+            (EventType::PersonUpdated, EventObject::Person(object)) => {
+                TypedEvent::PersonUpdated { object, previous_attributes }
+            }
+            (EventType::PlanCreated, EventObject::Plan(object)) => TypedEvent::PlanCreated(object),
+            (EventType::PlanDeleted, EventObject::Plan(object)) => TypedEvent::PlanDeleted(object),
+            (EventType::PlanUpdated, EventObject::Plan(object)) => {
+                TypedEvent::PlanUpdated { object, previous_attributes }
+            }
+            (EventType::PriceCreated, EventObject::Price(object)) => {
+                TypedEvent::PriceCreated(object)
+            }
+            (EventType::PriceDeleted, EventObject::Price(object)) => {
+                TypedEvent::PriceDeleted(object)
+            }
+            (EventType::PriceUpdated, EventObject::Price(object)) => {
+                TypedEvent::PriceUpdated { object, previous_attributes }
+            }
+            (EventType::ProductCreated, EventObject::Product(object)) => {
+                TypedEvent::ProductCreated(object)
+            }
+            (EventType::ProductDeleted, EventObject::Product(object)) => {
+                TypedEvent::ProductDeleted(object)
+            }
+            (EventType::ProductUpdated, EventObject::Product(object)) => {
+                TypedEvent::ProductUpdated { object, previous_attributes }
+            }
+            (EventType::PromotionCodeCreated, EventObject::PromotionCode(object)) => {
+                TypedEvent::PromotionCodeCreated(object)
+            }
+            (EventType::PromotionCodeUpdated, EventObject::PromotionCode(object)) => {
+                TypedEvent::PromotionCodeUpdated { object, previous_attributes }
+            }
+            (EventType::QuoteAccepted, EventObject::Quote(object)) => {
+                TypedEvent::QuoteAccepted(object)
+            }
+            (EventType::QuoteCanceled, EventObject::Quote(object)) => {
+                TypedEvent::QuoteCanceled(object)
+            }
+            (EventType::QuoteCreated, EventObject::Quote(object)) => {
+                TypedEvent::QuoteCreated(object)
+            }
+            (EventType::QuoteFinalized, EventObject::Quote(object)) => {
+                TypedEvent::QuoteFinalized(object)
+            }
+            (
+                EventType::RadarEarlyFraudWarningCreated,
+                EventObject::RadarEarlyFraudWarning(object),
+            ) => TypedEvent::RadarEarlyFraudWarningCreated(object),
+            (
+                EventType::RadarEarlyFraudWarningUpdated,
+                EventObject::RadarEarlyFraudWarning(object),
+            ) => TypedEvent::RadarEarlyFraudWarningUpdated { object, previous_attributes },
+            (EventType::RefundCreated, EventObject::Refund(object)) => {
+                TypedEvent::RefundCreated(object)
+            }
+            (EventType::RefundUpdated, EventObject::Refund(object)) => {
+                TypedEvent::RefundUpdated { object, previous_attributes }
+            }
+            (EventType::ReportingReportRunFailed, EventObject::ReportingReportRun(object)) => {
+                TypedEvent::ReportingReportRunFailed(object)
+            }
+            (EventType::ReportingReportRunSucceeded, EventObject::ReportingReportRun(object)) => {
+                TypedEvent::ReportingReportRunSucceeded(object)
+            }
+            (EventType::ReportingReportTypeUpdated, EventObject::ReportingReportType(object)) => {
+                TypedEvent::ReportingReportTypeUpdated { object, previous_attributes }
+            }
+            (EventType::ReviewClosed, EventObject::Review(object)) => {
+                TypedEvent::ReviewClosed(object)
+            }
+            (EventType::ReviewOpened, EventObject::Review(object)) => {
+                TypedEvent::ReviewOpened(object)
+            }
+            (EventType::SetupIntentCanceled, EventObject::SetupIntent(object)) => {
+                TypedEvent::SetupIntentCanceled(object)
+            }
+            (EventType::SetupIntentCreated, EventObject::SetupIntent(object)) => {
+                TypedEvent::SetupIntentCreated(object)
+            }
+            (EventType::SetupIntentRequiresAction, EventObject::SetupIntent(object)) => {
+                TypedEvent::SetupIntentRequiresAction(object)
+            }
+            (EventType::SetupIntentSetupFailed, EventObject::SetupIntent(object)) => {
+                TypedEvent::SetupIntentSetupFailed(object)
+            }
+            (EventType::SetupIntentSucceeded, EventObject::SetupIntent(object)) => {
+                TypedEvent::SetupIntentSucceeded(object)
+            }
+            (EventType::SigmaScheduledQueryRunCreated, EventObject::ScheduledQueryRun(object)) => {
+                TypedEvent::SigmaScheduledQueryRunCreated(object)
+            }
+            (EventType::SourceCanceled, EventObject::Source(object)) => {
+                TypedEvent::SourceCanceled(object)
+            }
+            (EventType::SourceChargeable, EventObject::Source(object)) => {
+                TypedEvent::SourceChargeable(object)
+            }
+            (EventType::SourceFailed, EventObject::Source(object)) => {
+                TypedEvent::SourceFailed(object)
+            }
+            (EventType::SourceRefundAttributesRequired, EventObject::Source(object)) => {
+                TypedEvent::SourceRefundAttributesRequired(object)
+            }
+            (EventType::SourceTransactionCreated, EventObject::SourceTransaction(object)) => {
+                TypedEvent::SourceTransactionCreated(object)
+            }
+            (EventType::SourceTransactionUpdated, EventObject::SourceTransaction(object)) => {
+                TypedEvent::SourceTransactionUpdated { object, previous_attributes }
+            }
+            (EventType::SubscriptionScheduleAborted, EventObject::SubscriptionSchedule(object)) => {
+                TypedEvent::SubscriptionScheduleAborted(object)
+            }
+            (
+                EventType::SubscriptionScheduleCanceled,
+                EventObject::SubscriptionSchedule(object),
+            ) => TypedEvent::SubscriptionScheduleCanceled(object),
+            (
+                EventType::SubscriptionScheduleCompleted,
+                EventObject::SubscriptionSchedule(object),
+            ) => TypedEvent::SubscriptionScheduleCompleted(object),
+            (EventType::SubscriptionScheduleCreated, EventObject::SubscriptionSchedule(object)) => {
+                TypedEvent::SubscriptionScheduleCreated(object)
+            }
+            (
+                EventType::SubscriptionScheduleExpiring,
+                EventObject::SubscriptionSchedule(object),
+            ) => TypedEvent::SubscriptionScheduleExpiring(object),
+            (
+                EventType::SubscriptionScheduleReleased,
+                EventObject::SubscriptionSchedule(object),
+            ) => TypedEvent::SubscriptionScheduleReleased(object),
+            (EventType::SubscriptionScheduleUpdated, EventObject::SubscriptionSchedule(object)) => {
+                TypedEvent::SubscriptionScheduleUpdated { object, previous_attributes }
+            }
+            (EventType::TaxSettingsUpdated, EventObject::TaxSettings(object)) => {
+                TypedEvent::TaxSettingsUpdated { object, previous_attributes }
+            }
+            (EventType::TaxRateCreated, EventObject::TaxRate(object)) => {
+                TypedEvent::TaxRateCreated(object)
+            }
+            (EventType::TaxRateUpdated, EventObject::TaxRate(object)) => {
+                TypedEvent::TaxRateUpdated { object, previous_attributes }
+            }
+            (EventType::TerminalReaderActionFailed, EventObject::TerminalReader(object)) => {
+                TypedEvent::TerminalReaderActionFailed(object)
+            }
+            (EventType::TerminalReaderActionSucceeded, EventObject::TerminalReader(object)) => {
+                TypedEvent::TerminalReaderActionSucceeded(object)
+            }
+            (
+                EventType::TestHelpersTestClockAdvancing,
+                EventObject::TestHelpersTestClock(object),
+            ) => TypedEvent::TestHelpersTestClockAdvancing(object),
+            (EventType::TestHelpersTestClockCreated, EventObject::TestHelpersTestClock(object)) => {
+                TypedEvent::TestHelpersTestClockCreated(object)
+            }
+            (EventType::TestHelpersTestClockDeleted, EventObject::TestHelpersTestClock(object)) => {
+                TypedEvent::TestHelpersTestClockDeleted(object)
+            }
+            (
+                EventType::TestHelpersTestClockInternalFailure,
+                EventObject::TestHelpersTestClock(object),
+            ) => TypedEvent::TestHelpersTestClockInternalFailure(object),
+            (EventType::TestHelpersTestClockReady, EventObject::TestHelpersTestClock(object)) => {
+                TypedEvent::TestHelpersTestClockReady(object)
+            }
+            (EventType::TopupCanceled, EventObject::Topup(object)) => {
+                TypedEvent::TopupCanceled(object)
+            }
+            (EventType::TopupCreated, EventObject::Topup(object)) => {
+                TypedEvent::TopupCreated(object)
+            }
+            (EventType::TopupFailed, EventObject::Topup(object)) => TypedEvent::TopupFailed(object),
+            (EventType::TopupReversed, EventObject::Topup(object)) => {
+                TypedEvent::TopupReversed(object)
+            }
+            (EventType::TopupSucceeded, EventObject::Topup(object)) => {
+                TypedEvent::TopupSucceeded(object)
+            }
+            (EventType::TransferCreated, EventObject::Transfer(object)) => {
+                TypedEvent::TransferCreated(object)
+            }
+            (EventType::TransferReversed, EventObject::Transfer(object)) => {
+                TypedEvent::TransferReversed(object)
+            }
+            (EventType::TransferUpdated, EventObject::Transfer(object)) => {
+                TypedEvent::TransferUpdated { object, previous_attributes }
+            }
+            (
+                EventType::TreasuryCreditReversalCreated,
+                EventObject::TreasuryCreditReversal(object),
+            ) => TypedEvent::TreasuryCreditReversalCreated(object),
+            (
+                EventType::TreasuryCreditReversalPosted,
+                EventObject::TreasuryCreditReversal(object),
+            ) => TypedEvent::TreasuryCreditReversalPosted(object),
+            (
+                EventType::TreasuryDebitReversalCompleted,
+                EventObject::TreasuryDebitReversal(object),
+            ) => TypedEvent::TreasuryDebitReversalCompleted(object),
+            (
+                EventType::TreasuryDebitReversalCreated,
+                EventObject::TreasuryDebitReversal(object),
+            ) => TypedEvent::TreasuryDebitReversalCreated(object),
+            (
+                EventType::TreasuryDebitReversalInitialCreditGranted,
+                EventObject::TreasuryDebitReversal(object),
+            ) => TypedEvent::TreasuryDebitReversalInitialCreditGranted(object),
+            (
+                EventType::TreasuryFinancialAccountClosed,
+                EventObject::TreasuryFinancialAccount(object),
+            ) => TypedEvent::TreasuryFinancialAccountClosed(object),
+            (
+                EventType::TreasuryFinancialAccountCreated,
+                EventObject::TreasuryFinancialAccount(object),
+            ) => TypedEvent::TreasuryFinancialAccountCreated(object),
+            (
+                EventType::TreasuryFinancialAccountFeaturesStatusUpdated,
+                EventObject::TreasuryFinancialAccount(object),
+            ) => TypedEvent::TreasuryFinancialAccountFeaturesStatusUpdated(object),
+            (
+                EventType::TreasuryInboundTransferCanceled,
+                EventObject::TreasuryInboundTransfer(object),
+            ) => TypedEvent::TreasuryInboundTransferCanceled(object),
+            (
+                EventType::TreasuryInboundTransferCreated,
+                EventObject::TreasuryInboundTransfer(object),
+            ) => TypedEvent::TreasuryInboundTransferCreated(object),
+            (
+                EventType::TreasuryInboundTransferFailed,
+                EventObject::TreasuryInboundTransfer(object),
+            ) => TypedEvent::TreasuryInboundTransferFailed(object),
+            (
+                EventType::TreasuryInboundTransferSucceeded,
+                EventObject::TreasuryInboundTransfer(object),
+            ) => TypedEvent::TreasuryInboundTransferSucceeded(object),
+            (
+                EventType::TreasuryOutboundPaymentCanceled,
+                EventObject::TreasuryOutboundPayment(object),
+            ) => TypedEvent::TreasuryOutboundPaymentCanceled(object),
+            (
+                EventType::TreasuryOutboundPaymentCreated,
+                EventObject::TreasuryOutboundPayment(object),
+            ) => TypedEvent::TreasuryOutboundPaymentCreated(object),
+            (
+                EventType::TreasuryOutboundPaymentExpectedArrivalDateUpdated,
+                EventObject::TreasuryOutboundPayment(object),
+            ) => TypedEvent::TreasuryOutboundPaymentExpectedArrivalDateUpdated(object),
+            (
+                EventType::TreasuryOutboundPaymentFailed,
+                EventObject::TreasuryOutboundPayment(object),
+            ) => TypedEvent::TreasuryOutboundPaymentFailed(object),
+            (
+                EventType::TreasuryOutboundPaymentPosted,
+                EventObject::TreasuryOutboundPayment(object),
+            ) => TypedEvent::TreasuryOutboundPaymentPosted(object),
+            (
+                EventType::TreasuryOutboundPaymentReturned,
+                EventObject::TreasuryOutboundPayment(object),
+            ) => TypedEvent::TreasuryOutboundPaymentReturned(object),
+            (
+                EventType::TreasuryOutboundTransferCanceled,
+                EventObject::TreasuryOutboundTransfer(object),
+            ) => TypedEvent::TreasuryOutboundTransferCanceled(object),
+            (
+                EventType::TreasuryOutboundTransferCreated,
+                EventObject::TreasuryOutboundTransfer(object),
+            ) => TypedEvent::TreasuryOutboundTransferCreated(object),
+            (
+                EventType::TreasuryOutboundTransferExpectedArrivalDateUpdated,
+                EventObject::TreasuryOutboundTransfer(object),
+            ) => TypedEvent::TreasuryOutboundTransferExpectedArrivalDateUpdated(object),
+            (
+                EventType::TreasuryOutboundTransferFailed,
+                EventObject::TreasuryOutboundTransfer(object),
+            ) => TypedEvent::TreasuryOutboundTransferFailed(object),
+            (
+                EventType::TreasuryOutboundTransferPosted,
+                EventObject::TreasuryOutboundTransfer(object),
+            ) => TypedEvent::TreasuryOutboundTransferPosted(object),
+            (
+                EventType::TreasuryOutboundTransferReturned,
+                EventObject::TreasuryOutboundTransfer(object),
+            ) => TypedEvent::TreasuryOutboundTransferReturned(object),
+            (
+                EventType::TreasuryReceivedCreditCreated,
+                EventObject::TreasuryReceivedCredit(object),
+            ) => TypedEvent::TreasuryReceivedCreditCreated(object),
+            (
+                EventType::TreasuryReceivedCreditFailed,
+                EventObject::TreasuryReceivedCredit(object),
+            ) => TypedEvent::TreasuryReceivedCreditFailed(object),
+            (
+                EventType::TreasuryReceivedCreditSucceeded,
+                EventObject::TreasuryReceivedCredit(object),
+            ) => TypedEvent::TreasuryReceivedCreditSucceeded(object),
+            (
+                EventType::TreasuryReceivedDebitCreated,
+                EventObject::TreasuryReceivedDebit(object),
+            ) => TypedEvent::TreasuryReceivedDebitCreated(object),
+            (_, object) => TypedEvent::Unknown(Event {
+                data: NotificationEventData { object, previous_attributes },
+                ..self
+            }),
+        }
+    }
+}
@@ -1,9 +1,16 @@
 use serde::Serialize;
 
-use crate::client::{Client, Response};
-use crate::ids::{CouponId, CustomerId, InvoiceId, PlanId, SubscriptionId, SubscriptionItemId};
-use crate::params::{Metadata, SearchList, Timestamp};
-use crate::resources::{CollectionMethod, Invoice};
+use crate::client::StripeClient;
+use crate::ids::{
+    CouponId, CustomerId, InvoiceId, InvoiceLineItemId, PlanId, SubscriptionId, SubscriptionItemId,
+};
+use crate::params::{Expand, List, Metadata, Object, Paginable, SearchList, Timestamp};
+use crate::resources::{
+    CollectionMethod, CreateInvoiceAutomaticTax, CreateInvoiceCustomFields, CreateInvoiceDiscounts,
+    CreateInvoiceIssuer, CreateInvoicePaymentSettings, CreateInvoiceRendering,
+    CreateInvoiceShippingCost, CreateInvoiceShippingDetails, CreateInvoiceTransferData, Invoice,
+    InvoiceLineItem,
+};
 
 #[deprecated(since = "0.12.0")]
 pub type InvoiceCollectionMethod = CollectionMethod;
@@ -12,32 +19,105 @@ impl Invoice {
     /// Retrieves the details of an upcoming invoice_id
     ///
     /// For more details see <https://stripe.com/docs/api#upcoming_invoice>.
-    pub fn upcoming(client: &Client, params: RetrieveUpcomingInvoice) -> Response<Invoice> {
+    pub fn upcoming<C: StripeClient>(
+        client: &C,
+        params: RetrieveUpcomingInvoice,
+    ) -> C::Response<Invoice> {
         client.get_query("/invoices/upcoming", &params)
     }
 
     /// Finalizes an invoice.
     ///
     /// For more details see <https://stripe.com/docs/api/invoices/finalize.>.
-    pub fn finalize(
-        client: &Client,
+    pub fn finalize<C: StripeClient>(
+        client: &C,
         invoice_id: &InvoiceId,
         params: FinalizeInvoiceParams,
-    ) -> Response<Invoice> {
+    ) -> C::Response<Invoice> {
         client.post_form(&format!("/invoices/{}/finalize", invoice_id), params)
     }
 
     /// Pays an invoice.
     ///
     /// For more details see <https://stripe.com/docs/api#pay_invoice.>.
-    pub fn pay(client: &Client, invoice_id: &InvoiceId) -> Response<Invoice> {
+    pub fn pay<C: StripeClient>(client: &C, invoice_id: &InvoiceId) -> C::Response<Invoice> {
         client.post(&format!("/invoices/{}/pay", invoice_id))
     }
 
+    /// Pays an invoice, either by charging it now or by marking it as paid out of band.
+    ///
+    /// For more details see <https://stripe.com/docs/api#pay_invoice.>.
+    pub fn pay_with_params<C: StripeClient>(
+        client: &C,
+        invoice_id: &InvoiceId,
+        params: PayInvoice<'_>,
+    ) -> C::Response<Invoice> {
+        client.post_form(&format!("/invoices/{}/pay", invoice_id), params)
+    }
+
+    /// Updates a draft invoice, or the fields of a finalized invoice which may still be changed.
+    ///
+    /// For more details see <https://stripe.com/docs/api/invoices/update>.
+    pub fn update<C: StripeClient>(
+        client: &C,
+        invoice_id: &InvoiceId,
+        params: UpdateInvoice<'_>,
+    ) -> C::Response<Invoice> {
+        client.post_form(&format!("/invoices/{}", invoice_id), params)
+    }
+
+    /// Voids a finalized invoice.
+    ///
+    /// For more details see <https://stripe.com/docs/api/invoices/void>.
+    pub fn void<C: StripeClient>(client: &C, invoice_id: &InvoiceId) -> C::Response<Invoice> {
+        client.post(&format!("/invoices/{}/void", invoice_id))
+    }
+
+    /// Sends a finalized invoice to the customer, whatever its collection method.
+    ///
+    /// For more details see <https://stripe.com/docs/api/invoices/send>.
+    pub fn send<C: StripeClient>(client: &C, invoice_id: &InvoiceId) -> C::Response<Invoice> {
+        client.post(&format!("/invoices/{}/send", invoice_id))
+    }
+
+    /// Marks a finalized invoice as uncollectible.
+    ///
+    /// For more details see <https://stripe.com/docs/api/invoices/mark_uncollectible>.
+    pub fn mark_uncollectible<C: StripeClient>(
+        client: &C,
+        invoice_id: &InvoiceId,
+    ) -> C::Response<Invoice> {
+        client.post(&format!("/invoices/{}/mark_uncollectible", invoice_id))
+    }
+
+    /// Lists the line items of an invoice.
+    ///
+    /// For more details see <https://stripe.com/docs/api/invoices/invoice_lines>.
+    pub fn list_lines<C: StripeClient>(
+        client: &C,
+        invoice_id: &InvoiceId,
+        params: &ListInvoiceLines<'_>,
+    ) -> C::Response<List<InvoiceLineItem>> {
+        client.get_query(&format!("/invoices/{}/lines", invoice_id), params)
+    }
+
+    /// Lists the line items of an upcoming invoice.
+    ///
+    /// For more details see <https://stripe.com/docs/api/invoices/upcoming_invoice_lines>.
+    pub fn upcoming_lines<C: StripeClient>(
+        client: &C,
+        params: &RetrieveUpcomingInvoiceLines,
+    ) -> C::Response<List<InvoiceLineItem>> {
+        client.get_query("/invoices/upcoming/lines", params)
+    }
+
     /// Searches for an invoice.
     ///
     /// For more details see <https://stripe.com/docs/api/invoices/search>.
-    pub fn search(client: &Client, params: InvoiceSearchParams) -> Response<SearchList<Invoice>> {
+    pub fn search<C: StripeClient>(
+        client: &C,
+        params: InvoiceSearchParams,
+    ) -> C::Response<SearchList<Invoice>> {
         client.get_query("/invoices/search", params)
     }
 }
@@ -111,3 +191,173 @@ pub struct FinalizeInvoiceParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_advance: Option<bool>,
 }
+
+/// The parameters for `Invoice::pay_with_params`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct PayInvoice<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Whether to pay the invoice with the customer's credit balance, forgiving
+    /// any amount which cannot be paid in full.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forgive: Option<bool>,
+
+    /// ID of the mandate to be used for this invoice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mandate: Option<&'a str>,
+
+    /// Whether the customer is not in your checkout flow, which may affect how
+    /// the payment is authenticated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub off_session: Option<bool>,
+
+    /// Mark the invoice as paid, without charging the customer, because it was
+    /// paid outside of stripe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_out_of_band: Option<bool>,
+
+    /// A PaymentMethod to pay the invoice with, which must belong to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+
+    /// A payment source to pay the invoice with, which must belong to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<&'a str>,
+}
+
+impl<'a> PayInvoice<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `Invoice::update`.
+///
+/// Most fields may only be changed while the invoice is a draft.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateInvoice<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_tax_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<CreateInvoiceAutomaticTax>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<CollectionMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CreateInvoiceCustomFields>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<CreateInvoiceDiscounts>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_at: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<CreateInvoiceIssuer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_settings: Option<CreateInvoicePaymentSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendering: Option<CreateInvoiceRendering>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_cost: Option<CreateInvoiceShippingCost>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_details: Option<CreateInvoiceShippingDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<CreateInvoiceTransferData>,
+}
+
+impl<'a> UpdateInvoice<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `Invoice::list_lines`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListInvoiceLines<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<InvoiceLineItemId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<InvoiceLineItemId>,
+}
+
+impl<'a> ListInvoiceLines<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListInvoiceLines<'_> {
+    type O = InvoiceLineItem;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `Invoice::upcoming_lines`.
+///
+/// Either the customer or the subscription must be set.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct RetrieveUpcomingInvoiceLines {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<CouponId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<InvoiceLineItemId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<InvoiceLineItemId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<SubscriptionId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_items: Option<SubscriptionItemFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_proration_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_trial_end: Option<Timestamp>,
+}
+
+impl RetrieveUpcomingInvoiceLines {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for RetrieveUpcomingInvoiceLines {
+    type O = InvoiceLineItem;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
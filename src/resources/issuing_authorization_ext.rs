@@ -1,5 +1,70 @@
 use serde::{Deserialize, Serialize};
 
+use crate::client::StripeClient;
+use crate::ids::IssuingAuthorizationId;
+use crate::params::{Expand, Metadata};
+use crate::resources::IssuingAuthorization;
+
+impl IssuingAuthorization {
+    /// Approves a pending authorization.
+    ///
+    /// This is used to respond to an `issuing_authorization.request` event when real-time
+    /// authorizations are handled synchronously through the API rather than the webhook response.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/authorizations/approve>.
+    pub fn approve<C: StripeClient>(
+        client: &C,
+        id: &IssuingAuthorizationId,
+        params: ApproveIssuingAuthorization<'_>,
+    ) -> C::Response<IssuingAuthorization> {
+        client.post_form(&format!("/issuing/authorizations/{}/approve", id), params)
+    }
+
+    /// Declines a pending authorization.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/authorizations/decline>.
+    pub fn decline<C: StripeClient>(
+        client: &C,
+        id: &IssuingAuthorizationId,
+        params: DeclineIssuingAuthorization<'_>,
+    ) -> C::Response<IssuingAuthorization> {
+        client.post_form(&format!("/issuing/authorizations/{}/decline", id), params)
+    }
+}
+
+/// The parameters for `IssuingAuthorization::approve`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ApproveIssuingAuthorization<'a> {
+    /// The amount to approve, if less than the requested amount and partial approval is allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> ApproveIssuingAuthorization<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `IssuingAuthorization::decline`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct DeclineIssuingAuthorization<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> DeclineIssuingAuthorization<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
 /// An enum representing the possible values of the `IssuingAuthorizationVerificationData` fields.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -1,5 +1,183 @@
 use serde::{Deserialize, Serialize};
 
+use crate::client::StripeClient;
+use crate::ids::{IssuingCardId, IssuingCardholderId};
+use crate::params::{Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::{
+    Address, Currency, IssuingCard, IssuingCardCancellationReason, IssuingCardReplacementReason,
+    IssuingCardShippingService, IssuingCardSpendingLimit, IssuingCardStatus, MerchantCategory,
+};
+
+impl IssuingCard {
+    /// Returns a list of Issuing cards, sorted by creation date with the most recent first.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/cards/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListIssuingCards<'_>,
+    ) -> C::Response<List<IssuingCard>> {
+        client.get_query("/issuing/cards", params)
+    }
+
+    /// Creates an Issuing card for a cardholder.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/cards/create>.
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateIssuingCard<'_>,
+    ) -> C::Response<IssuingCard> {
+        client.post_form("/issuing/cards", params)
+    }
+
+    /// Retrieves an Issuing card.
+    ///
+    /// The full card number and CVC are only returned when expanded with `number` and `cvc`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/cards/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &IssuingCardId,
+        expand: &[&str],
+    ) -> C::Response<IssuingCard> {
+        client.get_query(&format!("/issuing/cards/{}", id), Expand { expand })
+    }
+
+    /// Updates the specified card, leaving any parameters not provided unchanged.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/cards/update>.
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &IssuingCardId,
+        params: UpdateIssuingCard<'_>,
+    ) -> C::Response<IssuingCard> {
+        client.post_form(&format!("/issuing/cards/{}", id), params)
+    }
+}
+
+/// The parameters for `IssuingCard::create`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateIssuingCard<'a> {
+    /// The cardholder the card is issued to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<IssuingCardholderId>,
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_account: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// The card this card replaces, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_for: Option<IssuingCardId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_reason: Option<IssuingCardReplacementReason>,
+    /// The address and method the card is shipped with, required for physical cards.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<CreateIssuingCardShipping>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<CreateIssuingCardSpendingControls>,
+    /// Whether authorizations can be approved on this card, defaulting to `inactive`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardStatus>,
+    #[serde(rename = "type")]
+    pub type_: IssuingCardType,
+}
+
+impl<'a> CreateIssuingCard<'a> {
+    pub fn new(currency: Currency, type_: IssuingCardType) -> Self {
+        CreateIssuingCard { currency, type_, ..Default::default() }
+    }
+}
+
+/// The parameters for `IssuingCard::update`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateIssuingCard<'a> {
+    /// The reason the card is being canceled, when setting `status` to `canceled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<IssuingCardCancellationReason>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<CreateIssuingCardSpendingControls>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardStatus>,
+}
+
+impl<'a> UpdateIssuingCard<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `IssuingCard::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListIssuingCards<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<IssuingCardholderId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IssuingCardId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<i64>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last4: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IssuingCardId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardStatus>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IssuingCardType>,
+}
+
+impl<'a> ListIssuingCards<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListIssuingCards<'_> {
+    type O = IssuingCard;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateIssuingCardShipping {
+    pub address: Address,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_signature: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<IssuingCardShippingService>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IssuingCardShippingType>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateIssuingCardSpendingControls {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_categories: Option<Vec<MerchantCategory>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_categories: Option<Vec<MerchantCategory>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_limits: Option<Vec<IssuingCardSpendingLimit>>,
+}
+
 /// An enum representing the possible values of an `IssuingCardPin`'s `status` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -0,0 +1,181 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::IssuingCardholderId;
+use crate::params::{Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::{
+    Address, IssuingCardholder, IssuingCardholderAuthorizationControls,
+    IssuingCardholderIndividualDob, IssuingCardholderPreferredLocales, IssuingCardholderStatus,
+    IssuingCardholderType,
+};
+
+impl IssuingCardholder {
+    /// Returns a list of Issuing cardholders, sorted by creation date with the most recent first.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/cardholders/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListIssuingCardholders<'_>,
+    ) -> C::Response<List<IssuingCardholder>> {
+        client.get_query("/issuing/cardholders", params)
+    }
+
+    /// Creates a new cardholder that can be issued cards.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/cardholders/create>.
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateIssuingCardholder<'_>,
+    ) -> C::Response<IssuingCardholder> {
+        client.post_form("/issuing/cardholders", params)
+    }
+
+    /// Retrieves an Issuing cardholder.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/cardholders/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &IssuingCardholderId,
+        expand: &[&str],
+    ) -> C::Response<IssuingCardholder> {
+        client.get_query(&format!("/issuing/cardholders/{}", id), Expand { expand })
+    }
+
+    /// Updates the specified cardholder, leaving any parameters not provided unchanged.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/cardholders/update>.
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &IssuingCardholderId,
+        params: UpdateIssuingCardholder<'_>,
+    ) -> C::Response<IssuingCardholder> {
+        client.post_form(&format!("/issuing/cardholders/{}", id), params)
+    }
+}
+
+/// The parameters for `IssuingCardholder::create`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateIssuingCardholder<'a> {
+    /// The cardholder's billing address.
+    pub billing: CreateIssuingCardholderBilling,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<CreateIssuingCardholderCompany>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub individual: Option<CreateIssuingCardholderIndividual>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// The cardholder's name, which will be printed on cards issued to them.
+    pub name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_locales: Option<Vec<IssuingCardholderPreferredLocales>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<IssuingCardholderAuthorizationControls>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardholderStatus>,
+    /// One of `individual` or `company`, defaulting to `individual`.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IssuingCardholderType>,
+}
+
+impl<'a> CreateIssuingCardholder<'a> {
+    pub fn new(name: &'a str, billing: CreateIssuingCardholderBilling) -> Self {
+        CreateIssuingCardholder { billing, name, ..Default::default() }
+    }
+}
+
+/// The parameters for `IssuingCardholder::update`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateIssuingCardholder<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing: Option<CreateIssuingCardholderBilling>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<CreateIssuingCardholderCompany>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub individual: Option<CreateIssuingCardholderIndividual>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_locales: Option<Vec<IssuingCardholderPreferredLocales>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<IssuingCardholderAuthorizationControls>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardholderStatus>,
+}
+
+impl<'a> UpdateIssuingCardholder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `IssuingCardholder::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListIssuingCardholders<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IssuingCardholderId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IssuingCardholderId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardholderStatus>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IssuingCardholderType>,
+}
+
+impl<'a> ListIssuingCardholders<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListIssuingCardholders<'_> {
+    type O = IssuingCardholder;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateIssuingCardholderBilling {
+    pub address: Address,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateIssuingCardholderCompany {
+    /// The entity's business ID number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateIssuingCardholderIndividual {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<IssuingCardholderIndividualDob>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+}
@@ -1,5 +1,73 @@
 use serde::{Deserialize, Serialize};
 
+use crate::client::StripeClient;
+use crate::ids::{IssuingDisputeId, IssuingTransactionId};
+use crate::params::{Expand, Metadata};
+use crate::resources::{IssuingDispute, IssuingDisputeEvidence};
+
+impl IssuingDispute {
+    /// Creates an Issuing dispute for a transaction.
+    ///
+    /// The dispute stays `unsubmitted` until it is submitted with `IssuingDispute::submit`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/disputes/create>.
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateIssuingDispute<'_>,
+    ) -> C::Response<IssuingDispute> {
+        client.post_form("/issuing/disputes", params)
+    }
+
+    /// Submits an Issuing dispute to the card network, once its evidence is complete.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/disputes/submit>.
+    pub fn submit<C: StripeClient>(
+        client: &C,
+        id: &IssuingDisputeId,
+        params: SubmitIssuingDispute<'_>,
+    ) -> C::Response<IssuingDispute> {
+        client.post_form(&format!("/issuing/disputes/{}/submit", id), params)
+    }
+}
+
+/// The parameters for `IssuingDispute::create`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateIssuingDispute<'a> {
+    /// The amount to dispute, defaulting to the full amount of the transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<IssuingDisputeEvidence>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// The transaction to dispute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<IssuingTransactionId>,
+}
+
+impl<'a> CreateIssuingDispute<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `IssuingDispute::submit`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SubmitIssuingDispute<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl<'a> SubmitIssuingDispute<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
 /// An enum representing the possible values of an `IssuingDispute`'s `reason` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
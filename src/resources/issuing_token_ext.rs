@@ -0,0 +1,84 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::{IssuingCardId, IssuingTokenId};
+use crate::params::{Expand, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::{IssuingToken, IssuingTokenStatus};
+
+impl IssuingToken {
+    /// Lists the Issuing tokens of a card.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/tokens/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListIssuingTokens<'_>,
+    ) -> C::Response<List<IssuingToken>> {
+        client.get_query("/issuing/tokens", params)
+    }
+
+    /// Activates, suspends or deactivates an Issuing token.
+    ///
+    /// For more details see <https://stripe.com/docs/api/issuing/tokens/update>.
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &IssuingTokenId,
+        params: UpdateIssuingToken<'_>,
+    ) -> C::Response<IssuingToken> {
+        client.post_form(&format!("/issuing/tokens/{}", id), params)
+    }
+}
+
+/// The parameters for `IssuingToken::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListIssuingTokens<'a> {
+    /// The card the tokens belong to.
+    pub card: IssuingCardId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IssuingTokenId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IssuingTokenId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingTokenStatus>,
+}
+
+impl<'a> ListIssuingTokens<'a> {
+    pub fn new(card: IssuingCardId) -> Self {
+        ListIssuingTokens {
+            card,
+            created: None,
+            ending_before: None,
+            expand: &[],
+            limit: None,
+            starting_after: None,
+            status: None,
+        }
+    }
+}
+
+impl Paginable for ListIssuingTokens<'_> {
+    type O = IssuingToken;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `IssuingToken::update`.
+#[derive(Clone, Debug, Serialize)]
+pub struct UpdateIssuingToken<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    /// The new status of the token, one of `active`, `deleted` or `suspended`.
+    pub status: IssuingTokenStatus,
+}
+
+impl<'a> UpdateIssuingToken<'a> {
+    pub fn new(status: IssuingTokenStatus) -> Self {
+        UpdateIssuingToken { expand: &[], status }
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerId, InvoiceId};
 use crate::resources::{Currency, InvoiceLineItem};
 
@@ -8,7 +8,10 @@ impl InvoiceLineItem {
     /// Creates an invoice line item.
     ///
     /// For more details see <https://stripe.com/docs/api#invoice_line_item_object>.
-    pub fn create(client: &Client, params: CreateInvoiceLineItem<'_>) -> Response<InvoiceLineItem> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateInvoiceLineItem<'_>,
+    ) -> C::Response<InvoiceLineItem> {
         client.post_form("/invoiceitems", &params)
     }
 }
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::params::Expand;
 use crate::resources::LoginLink;
 use crate::AccountId;
@@ -17,7 +17,11 @@ pub struct CreateLoginLink<'a> {
 }
 
 impl LoginLink {
-    pub fn create(client: &Client, id: &AccountId, redirect_url: &str) -> Response<Self> {
+    pub fn create<C: StripeClient>(
+        client: &C,
+        id: &AccountId,
+        redirect_url: &str,
+    ) -> C::Response<Self> {
         let create_login_link =
             CreateLoginLink { expand: &[], redirect_url: Some(redirect_url.to_string()) };
 
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::params::{Expandable, Metadata, SearchList};
 use crate::resources::{Currency, PaymentSource, Shipping};
 use crate::{PaymentIntent, PaymentIntentCancellationReason};
@@ -9,43 +9,43 @@ impl PaymentIntent {
     /// Confirm that customer intends to pay with current or provided source. Upon confirmation, the PaymentIntent will attempt to initiate a payment.
     ///
     /// For more details see <https://stripe.com/docs/api/payment_intents/confirm>.
-    pub fn confirm(
-        client: &Client,
+    pub fn confirm<C: StripeClient>(
+        client: &C,
         payment_intent_id: &str,
         params: PaymentIntentConfirmParams<'_>,
-    ) -> Response<PaymentIntent> {
+    ) -> C::Response<PaymentIntent> {
         client.post_form(&format!("/payment_intents/{}/confirm", payment_intent_id), params)
     }
 
     /// Capture the funds of an existing uncaptured PaymentIntent where required_action="requires_capture".
     ///
     /// For more details see <https://stripe.com/docs/api/payment_intents/capture>.
-    pub fn capture(
-        client: &Client,
+    pub fn capture<C: StripeClient>(
+        client: &C,
         payment_intent_id: &str,
         params: CapturePaymentIntent,
-    ) -> Response<PaymentIntent> {
+    ) -> C::Response<PaymentIntent> {
         client.post_form(&format!("/payment_intents/{}/capture", payment_intent_id), params)
     }
 
     /// A PaymentIntent object can be canceled when it is in one of these statuses: requires_source, requires_capture, requires_confirmation, requires_source_action.
     ///
     /// For more details see <https://stripe.com/docs/api/payment_intents/cancel>.
-    pub fn cancel(
-        client: &Client,
+    pub fn cancel<C: StripeClient>(
+        client: &C,
         payment_intent_id: &str,
         params: CancelPaymentIntent,
-    ) -> Response<PaymentIntent> {
+    ) -> C::Response<PaymentIntent> {
         client.post_form(&format!("/payment_intents/{}/cancel", payment_intent_id), params)
     }
 
     /// Searches for a payment intent.
     ///
     /// For more details see <https://stripe.com/docs/api/payment_intents/search>.
-    pub fn search(
-        client: &Client,
+    pub fn search<C: StripeClient>(
+        client: &C,
         params: PaymentIntentSearchParams,
-    ) -> Response<SearchList<PaymentIntent>> {
+    ) -> C::Response<SearchList<PaymentIntent>> {
         client.get_query("/payment_intents/search", params)
     }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{CustomerId, PaymentMethodId};
 use crate::resources::PaymentMethod;
 
@@ -16,18 +16,21 @@ impl PaymentMethod {
     /// Attach a payment method to a customer
     ///
     /// For more details see <https://stripe.com/docs/api/payment_methods/attach>.
-    pub fn attach(
-        client: &Client,
+    pub fn attach<C: StripeClient>(
+        client: &C,
         payment_method_id: &PaymentMethodId,
         params: AttachPaymentMethod,
-    ) -> Response<PaymentMethod> {
+    ) -> C::Response<PaymentMethod> {
         client.post_form(&format!("/payment_methods/{}/attach", payment_method_id), params)
     }
 
     /// Detach a PaymentMethod from a Customer
     ///
     /// For more details see <https://stripe.com/docs/api/payment_methods/detach>.
-    pub fn detach(client: &Client, payment_method_id: &PaymentMethodId) -> Response<PaymentMethod> {
+    pub fn detach<C: StripeClient>(
+        client: &C,
+        payment_method_id: &PaymentMethodId,
+    ) -> C::Response<PaymentMethod> {
         client.post(&format!("/payment_methods/{}/detach", payment_method_id))
     }
 }
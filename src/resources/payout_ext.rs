@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::{PayoutDestinationId, PayoutId};
 use crate::params::Object;
 use crate::resources::{BankAccount, Card, Payout};
@@ -9,7 +9,7 @@ impl Payout {
     /// Cancels the payout.
     ///
     /// For more details see <https://stripe.com/docs/api/payouts/cancel>.
-    pub fn cancel(client: &Client, id: &PayoutId) -> Response<Payout> {
+    pub fn cancel<C: StripeClient>(client: &C, id: &PayoutId) -> C::Response<Payout> {
         client.post(&format!("/payouts/{}/cancel", id))
     }
 }
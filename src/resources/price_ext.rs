@@ -1,7 +1,7 @@
 use serde::Serialize;
 
 use crate::params::SearchList;
-use crate::{Client, Price, Response};
+use crate::{Price, StripeClient};
 
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct PriceSearchParams<'a> {
@@ -23,7 +23,10 @@ impl Price {
     /// Searches for a price.
     ///
     /// For more details see <https://stripe.com/docs/api/prices/search>.
-    pub fn search(client: &Client, params: PriceSearchParams) -> Response<SearchList<Price>> {
+    pub fn search<C: StripeClient>(
+        client: &C,
+        params: PriceSearchParams,
+    ) -> C::Response<SearchList<Price>> {
         client.get_query("/prices/search", params)
     }
 }
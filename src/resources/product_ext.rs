@@ -1,7 +1,7 @@
 use serde::Serialize;
 
 use crate::params::SearchList;
-use crate::{Client, Product, Response};
+use crate::{Product, StripeClient};
 
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct ProductSearchParams<'a> {
@@ -23,7 +23,10 @@ impl Product {
     /// Searches for a product.
     ///
     /// For more details see <https://stripe.com/docs/api/products/search>.
-    pub fn search(client: &Client, params: ProductSearchParams) -> Response<SearchList<Product>> {
+    pub fn search<C: StripeClient>(
+        client: &C,
+        params: ProductSearchParams,
+    ) -> C::Response<SearchList<Product>> {
         client.get_query("/products/search", params)
     }
 }
@@ -0,0 +1,318 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::{
+    CheckoutSessionItemId, CouponId, CustomerId, DiscountId, PriceId, ProductId, PromotionCodeId,
+    QuoteId, TestHelpersTestClockId,
+};
+use crate::params::{Expand, List, Metadata, Object, Paginable, Timestamp};
+use crate::resources::{
+    CheckoutSessionItem, Currency, PriceTaxBehavior, Quote, QuoteCollectionMethod,
+    QuotesResourceRecurringInterval,
+};
+
+impl Quote {
+    /// Creates a draft quote.
+    ///
+    /// For more details see <https://stripe.com/docs/api/quotes/create>.
+    pub fn create<C: StripeClient>(client: &C, params: CreateQuote<'_>) -> C::Response<Quote> {
+        client.post_form("/quotes", params)
+    }
+
+    /// Updates a quote.
+    ///
+    /// For more details see <https://stripe.com/docs/api/quotes/update>.
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &QuoteId,
+        params: UpdateQuote<'_>,
+    ) -> C::Response<Quote> {
+        client.post_form(&format!("/quotes/{}", id), params)
+    }
+
+    /// Finalizes a draft quote, so that it may be sent to the customer and accepted.
+    ///
+    /// For more details see <https://stripe.com/docs/api/quotes/finalize>.
+    pub fn finalize_quote<C: StripeClient>(
+        client: &C,
+        id: &QuoteId,
+        params: FinalizeQuote<'_>,
+    ) -> C::Response<Quote> {
+        client.post_form(&format!("/quotes/{}/finalize", id), params)
+    }
+
+    /// Accepts a finalized quote, creating its invoice or subscription.
+    ///
+    /// For more details see <https://stripe.com/docs/api/quotes/accept>.
+    pub fn accept<C: StripeClient>(client: &C, id: &QuoteId) -> C::Response<Quote> {
+        client.post(&format!("/quotes/{}/accept", id))
+    }
+
+    /// Cancels a quote.
+    ///
+    /// For more details see <https://stripe.com/docs/api/quotes/cancel>.
+    pub fn cancel<C: StripeClient>(client: &C, id: &QuoteId) -> C::Response<Quote> {
+        client.post(&format!("/quotes/{}/cancel", id))
+    }
+
+    /// Lists the line items of a quote.
+    ///
+    /// For more details see <https://stripe.com/docs/api/quotes/line_items>.
+    pub fn list_line_items<C: StripeClient>(
+        client: &C,
+        id: &QuoteId,
+        params: &ListQuoteLineItems<'_>,
+    ) -> C::Response<List<CheckoutSessionItem>> {
+        client.get_query(&format!("/quotes/{}/line_items", id), params)
+    }
+
+    /// Lists the line items that will be invoiced up front when a quote with recurring
+    /// line items is accepted.
+    ///
+    /// For more details see <https://stripe.com/docs/api/quotes/line_items>.
+    pub fn list_computed_upfront_line_items<C: StripeClient>(
+        client: &C,
+        id: &QuoteId,
+        params: &ListQuoteLineItems<'_>,
+    ) -> C::Response<List<CheckoutSessionItem>> {
+        client.get_query(&format!("/quotes/{}/computed_upfront_line_items", id), params)
+    }
+
+    /// Downloads the PDF of a finalized quote.
+    ///
+    /// For more details see <https://stripe.com/docs/api/quotes/pdf>.
+    pub fn pdf<C: StripeClient>(client: &C, id: &QuoteId) -> C::Response<Vec<u8>> {
+        client.get_file(&format!("/quotes/{}/pdf", id))
+    }
+}
+
+/// The parameters for `Quote::create`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateQuote<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_percent: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<QuoteAutomaticTaxParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<QuoteCollectionMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<QuoteDiscountParams>>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+    /// Clone an existing quote, or revise it if `is_revision` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_quote: Option<CreateQuoteFromQuote>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_settings: Option<QuoteInvoiceSettingsParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<QuoteLineItemParams>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_data: Option<QuoteSubscriptionDataParams>,
+    /// The test clock to attach the quote to, which must match the customer's.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_clock: Option<TestHelpersTestClockId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<QuoteTransferDataParams>,
+}
+
+impl<'a> CreateQuote<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `Quote::update`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateQuote<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_percent: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automatic_tax: Option<QuoteAutomaticTaxParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_method: Option<QuoteCollectionMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_tax_rates: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<QuoteDiscountParams>>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_settings: Option<QuoteInvoiceSettingsParams>,
+    /// The line items of the quote, which replace the existing ones.
+    ///
+    /// Existing line items are kept by passing their id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<QuoteLineItemParams>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_data: Option<QuoteSubscriptionDataParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<QuoteTransferDataParams>,
+}
+
+impl<'a> UpdateQuote<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `Quote::finalize_quote`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FinalizeQuote<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+}
+
+impl<'a> FinalizeQuote<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `Quote::list_line_items` and `Quote::list_computed_upfront_line_items`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListQuoteLineItems<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<CheckoutSessionItemId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<CheckoutSessionItemId>,
+}
+
+impl<'a> ListQuoteLineItems<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListQuoteLineItems<'_> {
+    type O = CheckoutSessionItem;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QuoteAutomaticTaxParams {
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QuoteDiscountParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<CouponId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discount: Option<DiscountId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub promotion_code: Option<PromotionCodeId>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateQuoteFromQuote {
+    pub quote: QuoteId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_revision: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QuoteInvoiceSettingsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QuoteLineItemParams {
+    /// The id of an existing line item, when updating a quote.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<CheckoutSessionItemId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounts: Option<Vec<QuoteDiscountParams>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<PriceId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_data: Option<QuoteLineItemPriceData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rates: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QuoteLineItemPriceData {
+    pub currency: Currency,
+    pub product: ProductId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<QuoteLineItemPriceDataRecurring>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<PriceTaxBehavior>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount_decimal: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QuoteLineItemPriceDataRecurring {
+    pub interval: QuotesResourceRecurringInterval,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval_count: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QuoteSubscriptionDataParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_period_days: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct QuoteTransferDataParams {
+    pub destination: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_percent: Option<f64>,
+}
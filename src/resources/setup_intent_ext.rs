@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::resources::SetupIntent;
 use crate::{SetupIntentCancellationReason, SetupIntentId};
 
@@ -34,22 +34,22 @@ pub struct CancelSetupIntent {
 }
 
 impl SetupIntent {
-    pub fn confirm(
-        client: &Client,
+    pub fn confirm<C: StripeClient>(
+        client: &C,
         setup_id: &SetupIntentId,
         params: ConfirmSetupIntent,
-    ) -> Response<SetupIntent> {
+    ) -> C::Response<SetupIntent> {
         client.post_form(&format!("/setup_intents/{}/confirm", setup_id), &params)
     }
 
     /// A SetupIntent object can be canceled when it is in one of these statuses: requires_payment_method, requires_confirmation, or requires_action.
     ///
     /// For more details see <https://stripe.com/docs/api/setup_intents/cancel>.
-    pub fn cancel(
-        client: &Client,
+    pub fn cancel<C: StripeClient>(
+        client: &C,
         setup_id: &SetupIntentId,
         params: CancelSetupIntent,
-    ) -> Response<SetupIntent> {
+    ) -> C::Response<SetupIntent> {
         client.post_form(&format!("/setup_intents/{}/cancel", setup_id), params)
     }
 }
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::ids::SubscriptionId;
 use crate::params::SearchList;
 use crate::resources::{CreateSubscriptionItems, Subscription};
@@ -42,20 +42,20 @@ impl Subscription {
     /// Cancels a subscription.
     ///
     /// For more details see <https://stripe.com/docs/api#cancel_subscription>.
-    pub fn cancel(
-        client: &Client,
+    pub fn cancel<C: StripeClient>(
+        client: &C,
         subscription_id: &SubscriptionId,
         params: CancelSubscription,
-    ) -> Response<Subscription> {
+    ) -> C::Response<Subscription> {
         client.delete_query(&format!("/subscriptions/{}", subscription_id), params)
     }
     /// Searches for a subscription.
     ///
     /// For more details see <https://stripe.com/docs/api/subscriptions/search>.
-    pub fn search(
-        client: &Client,
+    pub fn search<C: StripeClient>(
+        client: &C,
         params: SubscriptionSearchParams,
-    ) -> Response<SearchList<Subscription>> {
+    ) -> C::Response<SearchList<Subscription>> {
         client.get_query("/subscriptions/search", params)
     }
 }
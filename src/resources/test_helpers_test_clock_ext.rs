@@ -0,0 +1,180 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::TestHelpersTestClockId;
+use crate::params::{Deleted, Expand, List, Object, Paginable, Timestamp};
+use crate::resources::TestHelpersTestClock;
+
+impl TestHelpersTestClock {
+    /// Returns a list of your test clocks.
+    ///
+    /// For more details see <https://stripe.com/docs/api/test_clocks/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTestHelpersTestClocks<'_>,
+    ) -> C::Response<List<TestHelpersTestClock>> {
+        client.get_query("/test_helpers/test_clocks", params)
+    }
+
+    /// Creates a test clock, frozen at the given time.
+    ///
+    /// For more details see <https://stripe.com/docs/api/test_clocks/create>.
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateTestHelpersTestClock<'_>,
+    ) -> C::Response<TestHelpersTestClock> {
+        client.post_form("/test_helpers/test_clocks", params)
+    }
+
+    /// Retrieves a test clock.
+    ///
+    /// For more details see <https://stripe.com/docs/api/test_clocks/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TestHelpersTestClockId,
+        expand: &[&str],
+    ) -> C::Response<TestHelpersTestClock> {
+        client.get_query(&format!("/test_helpers/test_clocks/{}", id), Expand { expand })
+    }
+
+    /// Deletes a test clock, along with the objects attached to it.
+    ///
+    /// For more details see <https://stripe.com/docs/api/test_clocks/delete>.
+    pub fn delete<C: StripeClient>(
+        client: &C,
+        id: &TestHelpersTestClockId,
+    ) -> C::Response<Deleted<TestHelpersTestClockId>> {
+        client.delete(&format!("/test_helpers/test_clocks/{}", id))
+    }
+
+    /// Starts advancing a test clock to the given time.
+    ///
+    /// The clock's status is `advancing` until the objects attached to it have caught up,
+    /// after which it is `ready`. See [`TestHelpersTestClock::advance_and_wait`] to wait for it.
+    ///
+    /// For more details see <https://stripe.com/docs/api/test_clocks/advance>.
+    pub fn advance<C: StripeClient>(
+        client: &C,
+        id: &TestHelpersTestClockId,
+        frozen_time: Timestamp,
+    ) -> C::Response<TestHelpersTestClock> {
+        client.post_form(
+            &format!("/test_helpers/test_clocks/{}/advance", id),
+            AdvanceTestHelpersTestClock { expand: &[], frozen_time },
+        )
+    }
+
+    /// Advances a test clock to the given time, and polls it every `poll_interval` until it is `ready`.
+    ///
+    /// N.B. This takes the async [`Client`](crate::Client) rather than any [`StripeClient`],
+    /// as it sleeps between polls on the client's runtime, and awaits each response.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`AdvanceTestClockError::Failed`](crate::AdvanceTestClockError::Failed) if
+    /// stripe reports that the clock could not be advanced, with
+    /// [`AdvanceTestClockError::TimedOut`](crate::AdvanceTestClockError::TimedOut) if it is
+    /// still advancing after `timeout`, or with the error of any request.
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # async fn run() -> Result<(), stripe::AdvanceTestClockError> {
+    /// use stripe::{CreateTestHelpersTestClock, TestHelpersTestClock};
+    ///
+    /// let client = stripe::Client::new("sk_test_123");
+    /// let params = CreateTestHelpersTestClock::new(1_700_000_000);
+    /// let clock = TestHelpersTestClock::create(&client, params).await?;
+    ///
+    /// // skip ahead 31 days, to renew the subscriptions of customers on the clock
+    /// let clock = TestHelpersTestClock::advance_and_wait(
+    ///     &client,
+    ///     &clock.id,
+    ///     1_700_000_000 + 31 * 24 * 60 * 60,
+    ///     Duration::from_secs(2),
+    ///     Duration::from_secs(60),
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn advance_and_wait(
+        client: &crate::Client,
+        id: &TestHelpersTestClockId,
+        frozen_time: Timestamp,
+        poll_interval: std::time::Duration,
+        timeout: std::time::Duration,
+    ) -> Result<TestHelpersTestClock, crate::AdvanceTestClockError> {
+        use crate::resources::TestHelpersTestClockStatus;
+        use crate::AdvanceTestClockError;
+
+        let deadline = std::time::Instant::now() + timeout;
+        let mut clock = Self::advance(client, id, frozen_time).await?;
+        loop {
+            match clock.status {
+                Some(TestHelpersTestClockStatus::Ready) => return Ok(clock),
+                Some(TestHelpersTestClockStatus::InternalFailure) => {
+                    return Err(AdvanceTestClockError::Failed(Box::new(clock)))
+                }
+                _ => {
+                    let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+                    if remaining.is_zero() {
+                        return Err(AdvanceTestClockError::TimedOut(Box::new(clock)));
+                    }
+                    crate::client::sleep(poll_interval.min(remaining)).await;
+                    clock = Self::retrieve(client, id, &[]).await?;
+                }
+            }
+        }
+    }
+}
+
+/// The parameters for `TestHelpersTestClock::create`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateTestHelpersTestClock<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    /// The time the clock starts at.
+    pub frozen_time: Timestamp,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+impl<'a> CreateTestHelpersTestClock<'a> {
+    pub fn new(frozen_time: Timestamp) -> Self {
+        CreateTestHelpersTestClock { frozen_time, ..Default::default() }
+    }
+}
+
+/// The parameters for `TestHelpersTestClock::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListTestHelpersTestClocks<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TestHelpersTestClockId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TestHelpersTestClockId>,
+}
+
+impl<'a> ListTestHelpersTestClocks<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListTestHelpersTestClocks<'_> {
+    type O = TestHelpersTestClock;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct AdvanceTestHelpersTestClock<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    expand: &'a [&'a str],
+    frozen_time: Timestamp,
+}
@@ -1,7 +1,7 @@
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::client::{Client, Response};
+use crate::client::StripeClient;
 use crate::params::Metadata;
 use crate::resources::TransferReversal;
 use crate::TransferId;
@@ -25,11 +25,11 @@ impl TransferReversal {
     /// Create Transfer Reversal
     ///
     /// For more details see <https://stripe.com/docs/api/transfer_reversals/create>.
-    pub fn create(
-        client: &Client,
+    pub fn create<C: StripeClient>(
+        client: &C,
         transfer: &TransferId,
         params: CreateTransferReversal,
-    ) -> Response<TransferReversal> {
+    ) -> C::Response<TransferReversal> {
         client.post_form(&format!("/transfers/{}/reversals", transfer), params)
     }
 }
@@ -0,0 +1,99 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::{TreasuryCreditReversalId, TreasuryFinancialAccountId, TreasuryReceivedCreditId};
+use crate::params::{Expand, List, Metadata, Object, Paginable};
+use crate::resources::{TreasuryCreditReversal, TreasuryCreditReversalStatus};
+
+impl TreasuryCreditReversal {
+    /// Returns a list of the credit reversals of a financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/credit_reversals/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTreasuryCreditReversals<'_>,
+    ) -> C::Response<List<TreasuryCreditReversal>> {
+        client.get_query("/treasury/credit_reversals", params)
+    }
+
+    /// Reverses a received credit, returning the money to its sender.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/credit_reversals/create>.
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateTreasuryCreditReversal<'_>,
+    ) -> C::Response<TreasuryCreditReversal> {
+        client.post_form("/treasury/credit_reversals", params)
+    }
+
+    /// Retrieves a credit reversal.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/credit_reversals/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TreasuryCreditReversalId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryCreditReversal> {
+        client.get_query(&format!("/treasury/credit_reversals/{}", id), Expand { expand })
+    }
+}
+
+/// The parameters for `TreasuryCreditReversal::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryCreditReversal<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// The received credit to reverse.
+    pub received_credit: TreasuryReceivedCreditId,
+}
+
+impl<'a> CreateTreasuryCreditReversal<'a> {
+    pub fn new(received_credit: TreasuryReceivedCreditId) -> Self {
+        CreateTreasuryCreditReversal {
+            expand: Default::default(),
+            metadata: Default::default(),
+            received_credit,
+        }
+    }
+}
+
+/// The parameters for `TreasuryCreditReversal::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryCreditReversals<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryCreditReversalId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received_credit: Option<TreasuryReceivedCreditId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryCreditReversalId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryCreditReversalStatus>,
+}
+
+impl<'a> ListTreasuryCreditReversals<'a> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        ListTreasuryCreditReversals {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            received_credit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl Paginable for ListTreasuryCreditReversals<'_> {
+    type O = TreasuryCreditReversal;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
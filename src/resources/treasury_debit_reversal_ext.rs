@@ -0,0 +1,99 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::{TreasuryDebitReversalId, TreasuryFinancialAccountId, TreasuryReceivedDebitId};
+use crate::params::{Expand, List, Metadata, Object, Paginable};
+use crate::resources::{TreasuryDebitReversal, TreasuryDebitReversalStatus};
+
+impl TreasuryDebitReversal {
+    /// Returns a list of the debit reversals of a financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/debit_reversals/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTreasuryDebitReversals<'_>,
+    ) -> C::Response<List<TreasuryDebitReversal>> {
+        client.get_query("/treasury/debit_reversals", params)
+    }
+
+    /// Reverses a received debit, returning the money to the financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/debit_reversals/create>.
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateTreasuryDebitReversal<'_>,
+    ) -> C::Response<TreasuryDebitReversal> {
+        client.post_form("/treasury/debit_reversals", params)
+    }
+
+    /// Retrieves a debit reversal.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/debit_reversals/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TreasuryDebitReversalId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryDebitReversal> {
+        client.get_query(&format!("/treasury/debit_reversals/{}", id), Expand { expand })
+    }
+}
+
+/// The parameters for `TreasuryDebitReversal::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryDebitReversal<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// The received debit to reverse.
+    pub received_debit: TreasuryReceivedDebitId,
+}
+
+impl<'a> CreateTreasuryDebitReversal<'a> {
+    pub fn new(received_debit: TreasuryReceivedDebitId) -> Self {
+        CreateTreasuryDebitReversal {
+            expand: Default::default(),
+            metadata: Default::default(),
+            received_debit,
+        }
+    }
+}
+
+/// The parameters for `TreasuryDebitReversal::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryDebitReversals<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryDebitReversalId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received_debit: Option<TreasuryReceivedDebitId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryDebitReversalId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryDebitReversalStatus>,
+}
+
+impl<'a> ListTreasuryDebitReversals<'a> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        ListTreasuryDebitReversals {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            received_debit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl Paginable for ListTreasuryDebitReversals<'_> {
+    type O = TreasuryDebitReversal;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
@@ -0,0 +1,204 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::TreasuryFinancialAccountId;
+use crate::params::{Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::{
+    Currency, TreasuryFinancialAccount, TreasuryFinancialAccountFeatures,
+    TreasuryFinancialAccountsResourcePlatformRestrictions,
+};
+
+impl TreasuryFinancialAccount {
+    /// Returns a list of financial accounts, sorted by creation date with the most recent first.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/financial_accounts/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTreasuryFinancialAccounts<'_>,
+    ) -> C::Response<List<TreasuryFinancialAccount>> {
+        client.get_query("/treasury/financial_accounts", params)
+    }
+
+    /// Creates a financial account for a connected account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/financial_accounts/create>.
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateTreasuryFinancialAccount<'_>,
+    ) -> C::Response<TreasuryFinancialAccount> {
+        client.post_form("/treasury/financial_accounts", params)
+    }
+
+    /// Retrieves a financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/financial_accounts/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TreasuryFinancialAccountId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryFinancialAccount> {
+        client.get_query(&format!("/treasury/financial_accounts/{}", id), Expand { expand })
+    }
+
+    /// Updates the specified financial account, leaving any parameters not provided unchanged.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/financial_accounts/update>.
+    pub fn update<C: StripeClient>(
+        client: &C,
+        id: &TreasuryFinancialAccountId,
+        params: UpdateTreasuryFinancialAccount<'_>,
+    ) -> C::Response<TreasuryFinancialAccount> {
+        client.post_form(&format!("/treasury/financial_accounts/{}", id), params)
+    }
+
+    /// Retrieves the features of a financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/financial_account_features/retrieve>.
+    pub fn retrieve_features<C: StripeClient>(
+        client: &C,
+        id: &TreasuryFinancialAccountId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryFinancialAccountFeatures> {
+        client
+            .get_query(&format!("/treasury/financial_accounts/{}/features", id), Expand { expand })
+    }
+
+    /// Requests or removes features of a financial account.
+    ///
+    /// Features which are not passed are left unchanged.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/financial_account_features/update>.
+    pub fn update_features<C: StripeClient>(
+        client: &C,
+        id: &TreasuryFinancialAccountId,
+        params: CreateTreasuryFinancialAccountFeatures,
+    ) -> C::Response<TreasuryFinancialAccountFeatures> {
+        client.post_form(&format!("/treasury/financial_accounts/{}/features", id), params)
+    }
+}
+
+/// The parameters for `TreasuryFinancialAccount::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryFinancialAccount<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    /// The features to request for the financial account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<CreateTreasuryFinancialAccountFeatures>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform_restrictions: Option<TreasuryFinancialAccountsResourcePlatformRestrictions>,
+    /// The currencies the financial account can hold a balance in.
+    pub supported_currencies: Vec<Currency>,
+}
+
+impl<'a> CreateTreasuryFinancialAccount<'a> {
+    pub fn new(supported_currencies: Vec<Currency>) -> Self {
+        CreateTreasuryFinancialAccount {
+            expand: Default::default(),
+            features: Default::default(),
+            metadata: Default::default(),
+            platform_restrictions: Default::default(),
+            supported_currencies,
+        }
+    }
+}
+
+/// The parameters for `TreasuryFinancialAccount::update`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateTreasuryFinancialAccount<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<CreateTreasuryFinancialAccountFeatures>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform_restrictions: Option<TreasuryFinancialAccountsResourcePlatformRestrictions>,
+}
+
+impl<'a> UpdateTreasuryFinancialAccount<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `TreasuryFinancialAccount::list`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ListTreasuryFinancialAccounts<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryFinancialAccountId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryFinancialAccountId>,
+}
+
+impl<'a> ListTreasuryFinancialAccounts<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListTreasuryFinancialAccounts<'_> {
+    type O = TreasuryFinancialAccount;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The features of a financial account to request or remove.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateTreasuryFinancialAccountFeatures {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_issuing: Option<TreasuryFeatureAccess>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deposit_insurance: Option<TreasuryFeatureAccess>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_addresses: Option<TreasuryFinancialAddressesFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_transfers: Option<TreasuryInboundTransfersFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intra_stripe_flows: Option<TreasuryFeatureAccess>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_payments: Option<TreasuryOutboundFlowsFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_transfers: Option<TreasuryOutboundFlowsFeature>,
+}
+
+impl CreateTreasuryFinancialAccountFeatures {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Whether a feature of a financial account is requested.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct TreasuryFeatureAccess {
+    pub requested: bool,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct TreasuryFinancialAddressesFeature {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aba: Option<TreasuryFeatureAccess>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct TreasuryInboundTransfersFeature {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach: Option<TreasuryFeatureAccess>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct TreasuryOutboundFlowsFeature {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach: Option<TreasuryFeatureAccess>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_domestic_wire: Option<TreasuryFeatureAccess>,
+}
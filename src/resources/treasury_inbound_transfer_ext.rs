@@ -0,0 +1,189 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::{TreasuryFinancialAccountId, TreasuryInboundTransferId};
+use crate::params::{Expand, List, Metadata, Object, Paginable};
+use crate::resources::{
+    Currency, TreasuryInboundTransfer, TreasuryInboundTransferStatus,
+    TreasuryInboundTransfersResourceFailureDetailsCode,
+};
+
+impl TreasuryInboundTransfer {
+    /// Returns a list of the inbound transfers of a financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/inbound_transfers/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTreasuryInboundTransfers<'_>,
+    ) -> C::Response<List<TreasuryInboundTransfer>> {
+        client.get_query("/treasury/inbound_transfers", params)
+    }
+
+    /// Creates an inbound transfer, pulling money into a financial account from another
+    /// account owned by the same user.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/inbound_transfers/create>.
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateTreasuryInboundTransfer<'_>,
+    ) -> C::Response<TreasuryInboundTransfer> {
+        client.post_form("/treasury/inbound_transfers", params)
+    }
+
+    /// Retrieves an inbound transfer.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/inbound_transfers/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TreasuryInboundTransferId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryInboundTransfer> {
+        client.get_query(&format!("/treasury/inbound_transfers/{}", id), Expand { expand })
+    }
+
+    /// Cancels an inbound transfer, which is only possible while it is `processing`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/inbound_transfers/cancel>.
+    pub fn cancel<C: StripeClient>(
+        client: &C,
+        id: &TreasuryInboundTransferId,
+    ) -> C::Response<TreasuryInboundTransfer> {
+        client.post(&format!("/treasury/inbound_transfers/{}/cancel", id))
+    }
+
+    /// Transitions a test mode inbound transfer from `processing` to `failed`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/inbound_transfers/test_mode_fail>.
+    pub fn test_helpers_fail<C: StripeClient>(
+        client: &C,
+        id: &TreasuryInboundTransferId,
+        params: FailTreasuryInboundTransfer<'_>,
+    ) -> C::Response<TreasuryInboundTransfer> {
+        client.post_form(&format!("/test_helpers/treasury/inbound_transfers/{}/fail", id), params)
+    }
+
+    /// Transitions a test mode inbound transfer from `processing` to `succeeded`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/inbound_transfers/test_mode_succeed>.
+    pub fn test_helpers_succeed<C: StripeClient>(
+        client: &C,
+        id: &TreasuryInboundTransferId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryInboundTransfer> {
+        client.post_form(
+            &format!("/test_helpers/treasury/inbound_transfers/{}/succeed", id),
+            Expand { expand },
+        )
+    }
+
+    /// Marks a succeeded test mode inbound transfer as returned.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/inbound_transfers/test_mode_return>.
+    pub fn test_helpers_return<C: StripeClient>(
+        client: &C,
+        id: &TreasuryInboundTransferId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryInboundTransfer> {
+        client.post_form(
+            &format!("/test_helpers/treasury/inbound_transfers/{}/return", id),
+            Expand { expand },
+        )
+    }
+}
+
+/// The parameters for `TreasuryInboundTransfer::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryInboundTransfer<'a> {
+    pub amount: i64,
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    /// The financial account to send the money to.
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// The payment method to pull the money from.
+    pub origin_payment_method: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+impl<'a> CreateTreasuryInboundTransfer<'a> {
+    pub fn new(
+        amount: i64,
+        currency: Currency,
+        financial_account: TreasuryFinancialAccountId,
+        origin_payment_method: &'a str,
+    ) -> Self {
+        CreateTreasuryInboundTransfer {
+            amount,
+            currency,
+            description: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            metadata: Default::default(),
+            origin_payment_method,
+            statement_descriptor: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TreasuryInboundTransfer::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryInboundTransfers<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryInboundTransferId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryInboundTransferId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryInboundTransferStatus>,
+}
+
+impl<'a> ListTreasuryInboundTransfers<'a> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        ListTreasuryInboundTransfers {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl Paginable for ListTreasuryInboundTransfers<'_> {
+    type O = TreasuryInboundTransfer;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryInboundTransfer::test_helpers_fail`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FailTreasuryInboundTransfer<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_details: Option<FailTreasuryInboundTransferFailureDetails>,
+}
+
+impl<'a> FailTreasuryInboundTransfer<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct FailTreasuryInboundTransferFailureDetails {
+    /// The reason the transfer failed, defaulting to `could_not_process`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<TreasuryInboundTransfersResourceFailureDetailsCode>,
+}
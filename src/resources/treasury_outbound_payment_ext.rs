@@ -0,0 +1,204 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::{CustomerId, TreasuryFinancialAccountId, TreasuryOutboundPaymentId};
+use crate::params::{Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::{
+    Currency, TreasuryOutboundPayment, TreasuryOutboundPaymentStatus,
+    TreasuryOutboundPaymentsResourceOutboundPaymentResourceEndUserDetails,
+    TreasuryOutboundPaymentsResourceReturnedStatusCode,
+};
+
+impl TreasuryOutboundPayment {
+    /// Returns a list of the outbound payments of a financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_payments/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTreasuryOutboundPayments<'_>,
+    ) -> C::Response<List<TreasuryOutboundPayment>> {
+        client.get_query("/treasury/outbound_payments", params)
+    }
+
+    /// Creates an outbound payment, sending money from a financial account to a third party.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_payments/create>.
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateTreasuryOutboundPayment<'_>,
+    ) -> C::Response<TreasuryOutboundPayment> {
+        client.post_form("/treasury/outbound_payments", params)
+    }
+
+    /// Retrieves an outbound payment.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_payments/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TreasuryOutboundPaymentId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryOutboundPayment> {
+        client.get_query(&format!("/treasury/outbound_payments/{}", id), Expand { expand })
+    }
+
+    /// Cancels an outbound payment, which is only possible while it is `processing`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_payments/cancel>.
+    pub fn cancel<C: StripeClient>(
+        client: &C,
+        id: &TreasuryOutboundPaymentId,
+    ) -> C::Response<TreasuryOutboundPayment> {
+        client.post(&format!("/treasury/outbound_payments/{}/cancel", id))
+    }
+
+    /// Transitions a test mode outbound payment from `processing` to `failed`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_payments/test_mode_fail>.
+    pub fn test_helpers_fail<C: StripeClient>(
+        client: &C,
+        id: &TreasuryOutboundPaymentId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryOutboundPayment> {
+        client.post_form(
+            &format!("/test_helpers/treasury/outbound_payments/{}/fail", id),
+            Expand { expand },
+        )
+    }
+
+    /// Transitions a test mode outbound payment from `processing` to `posted`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_payments/test_mode_post>.
+    pub fn test_helpers_post<C: StripeClient>(
+        client: &C,
+        id: &TreasuryOutboundPaymentId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryOutboundPayment> {
+        client.post_form(
+            &format!("/test_helpers/treasury/outbound_payments/{}/post", id),
+            Expand { expand },
+        )
+    }
+
+    /// Transitions a test mode outbound payment from `processing` to `returned`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_payments/test_mode_return>.
+    pub fn test_helpers_return<C: StripeClient>(
+        client: &C,
+        id: &TreasuryOutboundPaymentId,
+        params: ReturnTreasuryOutboundPayment<'_>,
+    ) -> C::Response<TreasuryOutboundPayment> {
+        client.post_form(&format!("/test_helpers/treasury/outbound_payments/{}/return", id), params)
+    }
+}
+
+/// The parameters for `TreasuryOutboundPayment::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryOutboundPayment<'a> {
+    pub amount: i64,
+    pub currency: Currency,
+    /// The customer the destination payment method belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    /// The payment method to send the money to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_payment_method: Option<&'a str>,
+    /// Details about the end user, required for some networks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_user_details:
+        Option<TreasuryOutboundPaymentsResourceOutboundPaymentResourceEndUserDetails>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    /// The financial account to send the money from.
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+impl<'a> CreateTreasuryOutboundPayment<'a> {
+    pub fn new(
+        amount: i64,
+        currency: Currency,
+        financial_account: TreasuryFinancialAccountId,
+    ) -> Self {
+        CreateTreasuryOutboundPayment {
+            amount,
+            currency,
+            customer: Default::default(),
+            description: Default::default(),
+            destination_payment_method: Default::default(),
+            end_user_details: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            metadata: Default::default(),
+            statement_descriptor: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TreasuryOutboundPayment::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryOutboundPayments<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryOutboundPaymentId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryOutboundPaymentId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryOutboundPaymentStatus>,
+}
+
+impl<'a> ListTreasuryOutboundPayments<'a> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        ListTreasuryOutboundPayments {
+            created: Default::default(),
+            customer: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl Paginable for ListTreasuryOutboundPayments<'_> {
+    type O = TreasuryOutboundPayment;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryOutboundPayment::test_helpers_return`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ReturnTreasuryOutboundPayment<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub returned_details: Option<ReturnTreasuryOutboundPaymentReturnedDetails>,
+}
+
+impl<'a> ReturnTreasuryOutboundPayment<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ReturnTreasuryOutboundPaymentReturnedDetails {
+    /// The reason the payment was returned, defaulting to `declined`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<TreasuryOutboundPaymentsResourceReturnedStatusCode>,
+}
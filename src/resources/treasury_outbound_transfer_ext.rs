@@ -0,0 +1,193 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::{TreasuryFinancialAccountId, TreasuryOutboundTransferId};
+use crate::params::{Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::{
+    Currency, TreasuryOutboundTransfer, TreasuryOutboundTransferStatus,
+    TreasuryOutboundTransfersResourceReturnedDetailsCode,
+};
+
+impl TreasuryOutboundTransfer {
+    /// Returns a list of the outbound transfers of a financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_transfers/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTreasuryOutboundTransfers<'_>,
+    ) -> C::Response<List<TreasuryOutboundTransfer>> {
+        client.get_query("/treasury/outbound_transfers", params)
+    }
+
+    /// Creates an outbound transfer, sending money from a financial account to
+    /// another account owned by the same user.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_transfers/create>.
+    pub fn create<C: StripeClient>(
+        client: &C,
+        params: CreateTreasuryOutboundTransfer<'_>,
+    ) -> C::Response<TreasuryOutboundTransfer> {
+        client.post_form("/treasury/outbound_transfers", params)
+    }
+
+    /// Retrieves an outbound transfer.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_transfers/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TreasuryOutboundTransferId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryOutboundTransfer> {
+        client.get_query(&format!("/treasury/outbound_transfers/{}", id), Expand { expand })
+    }
+
+    /// Cancels an outbound transfer, which is only possible while it is `processing`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_transfers/cancel>.
+    pub fn cancel<C: StripeClient>(
+        client: &C,
+        id: &TreasuryOutboundTransferId,
+    ) -> C::Response<TreasuryOutboundTransfer> {
+        client.post(&format!("/treasury/outbound_transfers/{}/cancel", id))
+    }
+
+    /// Transitions a test mode outbound transfer from `processing` to `failed`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_transfers/test_mode_fail>.
+    pub fn test_helpers_fail<C: StripeClient>(
+        client: &C,
+        id: &TreasuryOutboundTransferId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryOutboundTransfer> {
+        client.post_form(
+            &format!("/test_helpers/treasury/outbound_transfers/{}/fail", id),
+            Expand { expand },
+        )
+    }
+
+    /// Transitions a test mode outbound transfer from `processing` to `posted`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_transfers/test_mode_post>.
+    pub fn test_helpers_post<C: StripeClient>(
+        client: &C,
+        id: &TreasuryOutboundTransferId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryOutboundTransfer> {
+        client.post_form(
+            &format!("/test_helpers/treasury/outbound_transfers/{}/post", id),
+            Expand { expand },
+        )
+    }
+
+    /// Transitions a test mode outbound transfer from `processing` to `returned`.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/outbound_transfers/test_mode_return>.
+    pub fn test_helpers_return<C: StripeClient>(
+        client: &C,
+        id: &TreasuryOutboundTransferId,
+        params: ReturnTreasuryOutboundTransfer<'_>,
+    ) -> C::Response<TreasuryOutboundTransfer> {
+        client
+            .post_form(&format!("/test_helpers/treasury/outbound_transfers/{}/return", id), params)
+    }
+}
+
+/// The parameters for `TreasuryOutboundTransfer::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryOutboundTransfer<'a> {
+    pub amount: i64,
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    /// The payment method to send the money to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    /// The financial account to send the money from.
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+impl<'a> CreateTreasuryOutboundTransfer<'a> {
+    pub fn new(
+        amount: i64,
+        currency: Currency,
+        financial_account: TreasuryFinancialAccountId,
+    ) -> Self {
+        CreateTreasuryOutboundTransfer {
+            amount,
+            currency,
+            description: Default::default(),
+            destination_payment_method: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            metadata: Default::default(),
+            statement_descriptor: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TreasuryOutboundTransfer::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryOutboundTransfers<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryOutboundTransferId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryOutboundTransferId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryOutboundTransferStatus>,
+}
+
+impl<'a> ListTreasuryOutboundTransfers<'a> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        ListTreasuryOutboundTransfers {
+            created: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl Paginable for ListTreasuryOutboundTransfers<'_> {
+    type O = TreasuryOutboundTransfer;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryOutboundTransfer::test_helpers_return`.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ReturnTreasuryOutboundTransfer<'a> {
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub returned_details: Option<ReturnTreasuryOutboundTransferReturnedDetails>,
+}
+
+impl<'a> ReturnTreasuryOutboundTransfer<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ReturnTreasuryOutboundTransferReturnedDetails {
+    /// The reason the transfer was returned, defaulting to `declined`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<TreasuryOutboundTransfersResourceReturnedDetailsCode>,
+}
@@ -0,0 +1,111 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::{TreasuryFinancialAccountId, TreasuryReceivedCreditId};
+use crate::params::{Expand, List, Object, Paginable};
+use crate::resources::{
+    Currency, TreasuryReceivedCredit, TreasuryReceivedCreditNetwork, TreasuryReceivedCreditStatus,
+};
+
+impl TreasuryReceivedCredit {
+    /// Returns a list of the received credits of a financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/received_credits/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTreasuryReceivedCredits<'_>,
+    ) -> C::Response<List<TreasuryReceivedCredit>> {
+        client.get_query("/treasury/received_credits", params)
+    }
+
+    /// Retrieves a received credit.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/received_credits/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TreasuryReceivedCreditId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryReceivedCredit> {
+        client.get_query(&format!("/treasury/received_credits/{}", id), Expand { expand })
+    }
+
+    /// Creates a test mode received credit, as if money had been sent to the financial account
+    /// from an external account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/received_credits/test_mode_create>.
+    pub fn test_helpers_create<C: StripeClient>(
+        client: &C,
+        params: CreateTreasuryReceivedCredit<'_>,
+    ) -> C::Response<TreasuryReceivedCredit> {
+        client.post_form("/test_helpers/treasury/received_credits", params)
+    }
+}
+
+/// The parameters for `TreasuryReceivedCredit::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryReceivedCredits<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryReceivedCreditId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryReceivedCreditId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryReceivedCreditStatus>,
+}
+
+impl<'a> ListTreasuryReceivedCredits<'a> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        ListTreasuryReceivedCredits {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl Paginable for ListTreasuryReceivedCredits<'_> {
+    type O = TreasuryReceivedCredit;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryReceivedCredit::test_helpers_create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryReceivedCredit<'a> {
+    pub amount: i64,
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    /// The financial account the money is sent to.
+    pub financial_account: TreasuryFinancialAccountId,
+    /// The rails the money is sent over, either `ach` or `us_domestic_wire`.
+    pub network: TreasuryReceivedCreditNetwork,
+}
+
+impl<'a> CreateTreasuryReceivedCredit<'a> {
+    pub fn new(
+        amount: i64,
+        currency: Currency,
+        financial_account: TreasuryFinancialAccountId,
+        network: TreasuryReceivedCreditNetwork,
+    ) -> Self {
+        CreateTreasuryReceivedCredit {
+            amount,
+            currency,
+            description: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            network,
+        }
+    }
+}
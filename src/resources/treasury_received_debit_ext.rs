@@ -0,0 +1,111 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::{TreasuryFinancialAccountId, TreasuryReceivedDebitId};
+use crate::params::{Expand, List, Object, Paginable};
+use crate::resources::{
+    Currency, TreasuryReceivedDebit, TreasuryReceivedDebitNetwork, TreasuryReceivedDebitStatus,
+};
+
+impl TreasuryReceivedDebit {
+    /// Returns a list of the received debits of a financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/received_debits/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTreasuryReceivedDebits<'_>,
+    ) -> C::Response<List<TreasuryReceivedDebit>> {
+        client.get_query("/treasury/received_debits", params)
+    }
+
+    /// Retrieves a received debit.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/received_debits/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TreasuryReceivedDebitId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryReceivedDebit> {
+        client.get_query(&format!("/treasury/received_debits/{}", id), Expand { expand })
+    }
+
+    /// Creates a test mode received debit, as if money had been pulled from the financial account
+    /// by an external account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/received_debits/test_mode_create>.
+    pub fn test_helpers_create<C: StripeClient>(
+        client: &C,
+        params: CreateTreasuryReceivedDebit<'_>,
+    ) -> C::Response<TreasuryReceivedDebit> {
+        client.post_form("/test_helpers/treasury/received_debits", params)
+    }
+}
+
+/// The parameters for `TreasuryReceivedDebit::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryReceivedDebits<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryReceivedDebitId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryReceivedDebitId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryReceivedDebitStatus>,
+}
+
+impl<'a> ListTreasuryReceivedDebits<'a> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        ListTreasuryReceivedDebits {
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl Paginable for ListTreasuryReceivedDebits<'_> {
+    type O = TreasuryReceivedDebit;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryReceivedDebit::test_helpers_create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryReceivedDebit<'a> {
+    pub amount: i64,
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    /// The financial account the money is pulled from.
+    pub financial_account: TreasuryFinancialAccountId,
+    /// The rails the money is pulled over, which must be `ach`.
+    pub network: TreasuryReceivedDebitNetwork,
+}
+
+impl<'a> CreateTreasuryReceivedDebit<'a> {
+    pub fn new(
+        amount: i64,
+        currency: Currency,
+        financial_account: TreasuryFinancialAccountId,
+        network: TreasuryReceivedDebitNetwork,
+    ) -> Self {
+        CreateTreasuryReceivedDebit {
+            amount,
+            currency,
+            description: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            network,
+        }
+    }
+}
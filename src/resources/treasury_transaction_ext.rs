@@ -0,0 +1,134 @@
+use serde::Serialize;
+
+use crate::client::StripeClient;
+use crate::ids::{TreasuryFinancialAccountId, TreasuryTransactionEntryId, TreasuryTransactionId};
+use crate::params::{Expand, List, Object, Paginable, RangeQuery, Timestamp};
+use crate::resources::{TreasuryTransaction, TreasuryTransactionEntry, TreasuryTransactionStatus};
+
+impl TreasuryTransaction {
+    /// Returns a list of the transactions of a financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/transactions/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTreasuryTransactions<'_>,
+    ) -> C::Response<List<TreasuryTransaction>> {
+        client.get_query("/treasury/transactions", params)
+    }
+
+    /// Retrieves a transaction.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/transactions/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TreasuryTransactionId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryTransaction> {
+        client.get_query(&format!("/treasury/transactions/{}", id), Expand { expand })
+    }
+}
+
+impl TreasuryTransactionEntry {
+    /// Returns a list of the transaction entries of a financial account.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/transaction_entries/list>.
+    pub fn list<C: StripeClient>(
+        client: &C,
+        params: &ListTreasuryTransactionEntries<'_>,
+    ) -> C::Response<List<TreasuryTransactionEntry>> {
+        client.get_query("/treasury/transaction_entries", params)
+    }
+
+    /// Retrieves a transaction entry.
+    ///
+    /// For more details see <https://stripe.com/docs/api/treasury/transaction_entries/retrieve>.
+    pub fn retrieve<C: StripeClient>(
+        client: &C,
+        id: &TreasuryTransactionEntryId,
+        expand: &[&str],
+    ) -> C::Response<TreasuryTransactionEntry> {
+        client.get_query(&format!("/treasury/transaction_entries/{}", id), Expand { expand })
+    }
+}
+
+/// The parameters for `TreasuryTransaction::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryTransactions<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryTransactionId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryTransactionId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryTransactionStatus>,
+}
+
+impl<'a> ListTreasuryTransactions<'a> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        ListTreasuryTransactions {
+            created: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            starting_after: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl Paginable for ListTreasuryTransactions<'_> {
+    type O = TreasuryTransaction;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryTransactionEntry::list`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTreasuryTransactionEntries<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_at: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryTransactionEntryId>,
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+    pub financial_account: TreasuryFinancialAccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryTransactionEntryId>,
+    /// Only return the entries of this transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<TreasuryTransactionId>,
+}
+
+impl<'a> ListTreasuryTransactionEntries<'a> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        ListTreasuryTransactionEntries {
+            created: Default::default(),
+            effective_at: Default::default(),
+            ending_before: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            limit: Default::default(),
+            starting_after: Default::default(),
+            transaction: Default::default(),
+        }
+    }
+}
+
+impl Paginable for ListTreasuryTransactionEntries<'_> {
+    type O = TreasuryTransactionEntry;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
@@ -1,13 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{Client, Response, SubscriptionItemId, Timestamp, UsageRecord};
+use crate::{StripeClient, SubscriptionItemId, Timestamp, UsageRecord};
 
 impl UsageRecord {
-    pub fn create(
-        client: &Client,
+    pub fn create<C: StripeClient>(
+        client: &C,
         subscription_item_id: &SubscriptionItemId,
         params: CreateUsageRecord,
-    ) -> Response<UsageRecord> {
+    ) -> C::Response<UsageRecord> {
         client.post_form(
             &format!("/subscription_items/{}/usage_records", subscription_item_id),
             &params,
@@ -1,16 +1,47 @@
 use std::collections::HashMap;
+#[cfg(feature = "webhook-events")]
+use std::sync::Arc;
+#[cfg(feature = "webhook-events")]
+use std::time::Duration;
 
 use chrono::Utc;
 #[cfg(feature = "webhook-events")]
 use hmac::{Hmac, Mac};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 #[cfg(feature = "webhook-events")]
 use sha2::Sha256;
 use smart_default::SmartDefault;
 
 use crate::error::WebhookError;
+#[cfg(feature = "webhook-events")]
+use crate::params::Timestamp;
+#[cfg(feature = "webhook-events")]
+use crate::resources::generated::core::version::VERSION;
 use crate::resources::*;
+#[cfg(feature = "webhook-events")]
+use crate::EventId;
+
+#[cfg(feature = "actix-web")]
+pub mod actix_web;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "webhook-events")]
+mod dedup;
+#[cfg(all(
+    test,
+    any(feature = "actix-web", feature = "axum", feature = "rocket", feature = "tower")
+))]
+mod fixture;
+#[cfg(feature = "rocket")]
+pub mod rocket;
+mod router;
+#[cfg(feature = "tower")]
+pub mod tower;
+
+#[cfg(feature = "webhook-events")]
+pub use dedup::{DedupVerifier, InMemoryEventStore, ProcessedEventStore};
+pub use router::{DispatchReport, EventRoute, EventRouter};
 
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Hash, SmartDefault)]
 pub enum EventType {
@@ -38,6 +69,8 @@ pub enum EventType {
     BillingPortalConfigurationCreated,
     #[serde(rename = "billing_portal.configuration.updated")]
     BillingPortalConfigurationUpdated,
+    #[serde(rename = "billing_portal.session.created")]
+    BillingPortalSessionCreated,
     #[serde(rename = "capability.updated")]
     CapabilityUpdated,
     #[serde(rename = "cash_balance.funds_available")]
@@ -76,6 +109,20 @@ pub enum EventType {
     CheckoutSessionCompleted,
     #[serde(rename = "checkout.session.expired")]
     CheckoutSessionExpired,
+    #[serde(rename = "climate.order.canceled")]
+    ClimateOrderCanceled,
+    #[serde(rename = "climate.order.created")]
+    ClimateOrderCreated,
+    #[serde(rename = "climate.order.delayed")]
+    ClimateOrderDelayed,
+    #[serde(rename = "climate.order.delivered")]
+    ClimateOrderDelivered,
+    #[serde(rename = "climate.order.product_substituted")]
+    ClimateOrderProductSubstituted,
+    #[serde(rename = "climate.product.created")]
+    ClimateProductCreated,
+    #[serde(rename = "climate.product.pricing_updated")]
+    ClimateProductPricingUpdated,
     #[serde(rename = "coupon.created")]
     CouponCreated,
     #[serde(rename = "coupon.deleted")]
@@ -130,8 +177,22 @@ pub enum EventType {
     CustomerTaxIdUpdated,
     #[serde(rename = "customer.updated")]
     CustomerUpdated,
+    #[serde(rename = "customer_cash_balance_transaction.created")]
+    CustomerCashBalanceTransactionCreated,
     #[serde(rename = "file.created")]
     FileCreated,
+    #[serde(rename = "financial_connections.account.created")]
+    FinancialConnectionsAccountCreated,
+    #[serde(rename = "financial_connections.account.deactivated")]
+    FinancialConnectionsAccountDeactivated,
+    #[serde(rename = "financial_connections.account.disconnected")]
+    FinancialConnectionsAccountDisconnected,
+    #[serde(rename = "financial_connections.account.reactivated")]
+    FinancialConnectionsAccountReactivated,
+    #[serde(rename = "financial_connections.account.refreshed_balance")]
+    FinancialConnectionsAccountRefreshedBalance,
+    #[serde(rename = "financial_connections.account.refreshed_transactions")]
+    FinancialConnectionsAccountRefreshedTransactions,
     #[serde(rename = "identity.verification_session.canceled")]
     IdentityVerificationSessionCanceled,
     #[serde(rename = "identity.verification_session.created")]
@@ -200,6 +261,10 @@ pub enum EventType {
     IssuingDisputeSubmitted,
     #[serde(rename = "issuing_dispute.updated")]
     IssuingDisputeUpdated,
+    #[serde(rename = "issuing_token.created")]
+    IssuingTokenCreated,
+    #[serde(rename = "issuing_token.updated")]
+    IssuingTokenUpdated,
     #[serde(rename = "issuing_transaction.created")]
     IssuingTransactionCreated,
     #[serde(rename = "issuing_transaction.updated")]
@@ -256,6 +321,8 @@ pub enum EventType {
     PayoutFailed,
     #[serde(rename = "payout.paid")]
     PayoutPaid,
+    #[serde(rename = "payout.reconciliation_completed")]
+    PayoutReconciliationCompleted,
     #[serde(rename = "payout.updated")]
     PayoutUpdated,
     #[serde(rename = "person.created")]
@@ -304,6 +371,10 @@ pub enum EventType {
     RecipientDeleted,
     #[serde(rename = "recipient.updated")]
     RecipientUpdated,
+    #[serde(rename = "refund.created")]
+    RefundCreated,
+    #[serde(rename = "refund.updated")]
+    RefundUpdated,
     #[serde(rename = "reporting.report_run.failed")]
     ReportingReportRunFailed,
     #[serde(rename = "reporting.report_run.succeeded")]
@@ -360,6 +431,8 @@ pub enum EventType {
     SubscriptionScheduleReleased,
     #[serde(rename = "subscription_schedule.updated")]
     SubscriptionScheduleUpdated,
+    #[serde(rename = "tax.settings.updated")]
+    TaxSettingsUpdated,
     #[serde(rename = "tax_rate.created")]
     TaxRateCreated,
     #[serde(rename = "tax_rate.updated")]
@@ -398,6 +471,62 @@ pub enum EventType {
     TransferReversed,
     #[serde(rename = "transfer.updated")]
     TransferUpdated,
+    #[serde(rename = "treasury.credit_reversal.created")]
+    TreasuryCreditReversalCreated,
+    #[serde(rename = "treasury.credit_reversal.posted")]
+    TreasuryCreditReversalPosted,
+    #[serde(rename = "treasury.debit_reversal.completed")]
+    TreasuryDebitReversalCompleted,
+    #[serde(rename = "treasury.debit_reversal.created")]
+    TreasuryDebitReversalCreated,
+    #[serde(rename = "treasury.debit_reversal.initial_credit_granted")]
+    TreasuryDebitReversalInitialCreditGranted,
+    #[serde(rename = "treasury.financial_account.closed")]
+    TreasuryFinancialAccountClosed,
+    #[serde(rename = "treasury.financial_account.created")]
+    TreasuryFinancialAccountCreated,
+    #[serde(rename = "treasury.financial_account.features_status_updated")]
+    TreasuryFinancialAccountFeaturesStatusUpdated,
+    #[serde(rename = "treasury.inbound_transfer.canceled")]
+    TreasuryInboundTransferCanceled,
+    #[serde(rename = "treasury.inbound_transfer.created")]
+    TreasuryInboundTransferCreated,
+    #[serde(rename = "treasury.inbound_transfer.failed")]
+    TreasuryInboundTransferFailed,
+    #[serde(rename = "treasury.inbound_transfer.succeeded")]
+    TreasuryInboundTransferSucceeded,
+    #[serde(rename = "treasury.outbound_payment.canceled")]
+    TreasuryOutboundPaymentCanceled,
+    #[serde(rename = "treasury.outbound_payment.created")]
+    TreasuryOutboundPaymentCreated,
+    #[serde(rename = "treasury.outbound_payment.expected_arrival_date_updated")]
+    TreasuryOutboundPaymentExpectedArrivalDateUpdated,
+    #[serde(rename = "treasury.outbound_payment.failed")]
+    TreasuryOutboundPaymentFailed,
+    #[serde(rename = "treasury.outbound_payment.posted")]
+    TreasuryOutboundPaymentPosted,
+    #[serde(rename = "treasury.outbound_payment.returned")]
+    TreasuryOutboundPaymentReturned,
+    #[serde(rename = "treasury.outbound_transfer.canceled")]
+    TreasuryOutboundTransferCanceled,
+    #[serde(rename = "treasury.outbound_transfer.created")]
+    TreasuryOutboundTransferCreated,
+    #[serde(rename = "treasury.outbound_transfer.expected_arrival_date_updated")]
+    TreasuryOutboundTransferExpectedArrivalDateUpdated,
+    #[serde(rename = "treasury.outbound_transfer.failed")]
+    TreasuryOutboundTransferFailed,
+    #[serde(rename = "treasury.outbound_transfer.posted")]
+    TreasuryOutboundTransferPosted,
+    #[serde(rename = "treasury.outbound_transfer.returned")]
+    TreasuryOutboundTransferReturned,
+    #[serde(rename = "treasury.received_credit.created")]
+    TreasuryReceivedCreditCreated,
+    #[serde(rename = "treasury.received_credit.failed")]
+    TreasuryReceivedCreditFailed,
+    #[serde(rename = "treasury.received_credit.succeeded")]
+    TreasuryReceivedCreditSucceeded,
+    #[serde(rename = "treasury.received_debit.created")]
+    TreasuryReceivedDebitCreated,
     #[serde(other)]
     #[default]
     Unknown,
@@ -420,81 +549,64 @@ pub struct NotificationEventData {
     pub previous_attributes: Option<HashMap<String, Value>>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(tag = "object", rename_all = "snake_case")]
-pub enum EventObject {
-    Account(Account),
-    #[serde(rename = "capability")]
-    AccountCapabilities(AccountCapabilities),
-    Application(Application),
-    ApplicationFee(ApplicationFee),
-    #[serde(rename = "fee_refund")]
-    ApplicationFeeRefund(ApplicationFeeRefund),
-    Balance(Balance),
-    BankAccount(BankAccount),
-    #[serde(rename = "billing_portal.configuration")]
-    BillingPortalConfiguration(BillingPortalConfiguration),
-    Card(Card),
-    Charge(Charge),
-    #[serde(rename = "checkout.session")]
-    CheckoutSession(CheckoutSession),
-    Coupon(Coupon),
-    Customer(Customer),
-    Discount(Discount),
-    Dispute(Dispute),
-    File(File),
-    Invoice(Invoice),
-    #[serde(rename = "invoiceitem")]
-    InvoiceItem(InvoiceItem),
-    #[serde(rename = "issuing.authorization")]
-    IssuingAuthorization(IssuingAuthorization),
-    #[serde(rename = "issuing.card")]
-    IssuingCard(IssuingCard),
-    #[serde(rename = "issuing.cardholder")]
-    IssuingCardholder(IssuingCardholder),
-    #[serde(rename = "issuing.dispute")]
-    IssuingDispute(IssuingDispute),
-    #[serde(rename = "issuing.transaction")]
-    IssuingTransaction(IssuingTransaction),
-    Mandate(Mandate),
-    PaymentIntent(PaymentIntent),
-    PaymentLink(PaymentLink),
-    PaymentMethod(PaymentMethod),
-    Payout(Payout),
-    Person(Person),
-    Plan(Plan),
-    Price(Price),
-    Product(Product),
-    PromotionCode(PromotionCode),
-    Quote(Quote),
-    Refund(Refund),
-    Review(Review),
-    SetupIntent(SetupIntent),
-    Subscription(Subscription),
-    SubscriptionSchedule(SubscriptionSchedule),
-    TaxId(TaxId),
-    TaxRate(TaxRate),
-    #[serde(rename = "test_helpers.test_clock")]
-    TestHelpersTestClock(TestHelpersTestClock),
-    Topup(Topup),
-    Transfer(Transfer),
-}
-
 impl Default for EventObject {
     fn default() -> Self {
         EventObject::Account(Account::default())
     }
 }
 
-#[cfg(feature = "webhook-events")]
-pub struct Webhook {
-    current_timestamp: i64,
+impl<'de> Deserialize<'de> for EventObject {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        // N.B. Only objects of an unknown type are kept as they are, so
+        //      that a known object which doesn't match its type is an error.
+        match value.get("object").and_then(Value::as_str) {
+            Some(object) if EventObject::OBJECT_TYPES.contains(&object) => {
+                EventObject::deserialize(&value).map_err(serde::de::Error::custom)
+            }
+            _ => Ok(EventObject::Unknown(value)),
+        }
+    }
+}
+
+impl Serialize for EventObject {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            EventObject::Unknown(value) => value.serialize(serializer),
+            object => EventObject::serialize(object, serializer),
+        }
+    }
 }
 
+#[cfg(feature = "webhook-events")]
+pub struct Webhook;
+
 #[cfg(feature = "webhook-events")]
 impl Webhook {
     /// Construct an event from a webhook payload and signature.
     ///
+    /// Use a [`WebhookVerifier`] to configure the tolerance, or to
+    /// verify the payload without deserializing it.
+    ///
+    /// The event may be converted into a [`TypedEvent`], to match on its type and object together:
+    ///
+    /// ```no_run
+    /// # fn handle(payload: &str, sig: &str) -> Result<(), stripe::WebhookError> {
+    /// use stripe::{TypedEvent, Webhook};
+    ///
+    /// let event = Webhook::construct_event(payload, sig, "whsec_xxxxx")?;
+    /// let id = event.id.clone();
+    /// match event.into_typed() {
+    ///     TypedEvent::ChargeSucceeded(charge) => println!("{}: charge {} succeeded", id, charge.id),
+    ///     TypedEvent::InvoiceUpdated { object, previous_attributes } => {
+    ///         println!("{}: invoice {} updated from {:?}", id, object.id, previous_attributes)
+    ///     }
+    ///     event => println!("{}: unhandled {}", id, event.event_type()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// # Errors
     ///
     /// This function will return a WebhookError if:
@@ -502,7 +614,31 @@ impl Webhook {
     ///  - the provided secret is invalid
     ///  - the signature timestamp is older than 5 minutes
     pub fn construct_event(payload: &str, sig: &str, secret: &str) -> Result<Event, WebhookError> {
-        Self { current_timestamp: Utc::now().timestamp() }.do_construct_event(payload, sig, secret)
+        WebhookVerifier::new(secret).construct_event(payload, sig)
+    }
+
+    /// Construct an event from a webhook payload and signature, accepting a signature
+    /// made with any of the given secrets.
+    ///
+    /// This allows an endpoint's signing secret to be rolled without downtime, by
+    /// accepting both the old and new secrets until the old one expires.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if:
+    ///  - the provided signature is invalid for every secret
+    ///  - the provided secret is invalid
+    ///  - the signature timestamp is older than 5 minutes
+    pub fn construct_event_with_secrets(
+        payload: &str,
+        sig: &str,
+        secrets: &[&str],
+    ) -> Result<Event, WebhookError> {
+        let mut verifier = WebhookVerifier::default();
+        for secret in secrets {
+            verifier = verifier.with_secret(*secret);
+        }
+        verifier.construct_event(payload, sig)
     }
 
     /// Construct an event from a webhook payload and signature, verifying its signature
@@ -517,41 +653,277 @@ impl Webhook {
     /// - the provided signature is invalid
     /// - the provided secret is invalid
     /// - the signature timestamp is older than 5 minutes from the provided timestamp
+    #[deprecated(note = "use `WebhookVerifier::with_clock` instead")]
     pub fn construct_event_with_timestamp(
         payload: &str,
         sig: &str,
         secret: &str,
         timestamp: i64,
     ) -> Result<Event, WebhookError> {
-        Self { current_timestamp: timestamp }.do_construct_event(payload, sig, secret)
+        WebhookVerifier::new(secret).with_clock(move || timestamp).construct_event(payload, sig)
     }
 
-    fn do_construct_event(
-        self,
+    /// Generate a `Stripe-Signature` header for the payload, signed with the given secret
+    /// at the given timestamp, in the same way that stripe signs webhooks.
+    ///
+    /// This is helpful for testing webhook handlers. See [`TestWebhook`] to also
+    /// generate the payload.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if the provided secret is invalid.
+    pub fn generate_test_header(
         payload: &str,
-        sig: &str,
         secret: &str,
-    ) -> Result<Event, WebhookError> {
+        timestamp: Timestamp,
+    ) -> Result<String, WebhookError> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|_| WebhookError::BadKey)?;
+        mac.update(format!("{}.{}", timestamp, payload).as_bytes());
+        Ok(format!("t={},v1={}", timestamp, hex::encode(mac.finalize().into_bytes())))
+    }
+}
+
+/// Builds signed webhook payloads, to test webhook handlers offline.
+///
+/// ```
+/// # fn run() -> Result<(), stripe::WebhookError> {
+/// use stripe::{Balance, EventObject, EventType, TestWebhook, Webhook};
+///
+/// let webhook =
+///     TestWebhook::new(EventType::BalanceAvailable, EventObject::Balance(Balance::default()));
+/// let (payload, signature) = webhook.sign("whsec_xxxxx")?;
+///
+/// let event = Webhook::construct_event(&payload, &signature, "whsec_xxxxx")?;
+/// assert_eq!(event.id, webhook.event().id);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "webhook-events")]
+#[derive(Clone, Debug)]
+pub struct TestWebhook {
+    event: Event,
+}
+
+#[cfg(feature = "webhook-events")]
+impl TestWebhook {
+    /// Create an event of the given type, about the given object.
+    ///
+    /// The event has a random test id, and is created at the current time.
+    pub fn new(event_type: EventType, object: EventObject) -> Self {
+        let suffix = std::iter::repeat_with(fastrand::alphanumeric).take(24).collect::<String>();
+        TestWebhook {
+            event: Event {
+                // N.B. this always has the `evt_` prefix, so parses successfully
+                id: format!("evt_test_{}", suffix).parse().unwrap_or_default(),
+                api_version: Some(VERSION.as_str().to_owned()),
+                created: Utc::now().timestamp(),
+                data: NotificationEventData { object, previous_attributes: None },
+                livemode: false,
+                pending_webhooks: 1,
+                type_: event_type,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Use the given id for the event.
+    pub fn with_id(mut self, id: EventId) -> Self {
+        self.event.id = id;
+        self
+    }
+
+    /// Create the event, and sign it, at the given time.
+    pub fn with_created(mut self, created: Timestamp) -> Self {
+        self.event.created = created;
+        self
+    }
+
+    /// Include the previous values of the attributes changed by an `*.updated` event.
+    pub fn with_previous_attributes(mut self, previous_attributes: HashMap<String, Value>) -> Self {
+        self.event.data.previous_attributes = Some(previous_attributes);
+        self
+    }
+
+    /// The event that will be sent.
+    pub fn event(&self) -> &Event {
+        &self.event
+    }
+
+    /// The json payload of the event.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if the event could not be serialized.
+    pub fn payload(&self) -> Result<String, WebhookError> {
+        Ok(serde_json::to_string(&self.event)?)
+    }
+
+    /// The json payload of the event, and a `Stripe-Signature` header signing it
+    /// with the given secret at the time the event was created.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if the event could not be serialized,
+    /// or the provided secret is invalid.
+    pub fn sign(&self, secret: &str) -> Result<(String, String), WebhookError> {
+        let payload = self.payload()?;
+        let signature = Webhook::generate_test_header(&payload, secret, self.event.created)?;
+        Ok((payload, signature))
+    }
+}
+
+/// Verifies the signatures of webhook payloads sent by stripe.
+///
+/// The payload must be the raw body of the request, exactly as it was received.
+///
+/// ```
+/// # fn handle(payload: &str, signature: &str) -> Result<(), stripe::WebhookError> {
+/// use std::time::Duration;
+///
+/// use stripe::WebhookVerifier;
+///
+/// let verifier = WebhookVerifier::new("whsec_new")
+///     .with_secret("whsec_old")
+///     .with_tolerance(Duration::from_secs(60));
+///
+/// // check the signature, and keep the payload before parsing it
+/// let payload = verifier.verify(payload, signature)?;
+/// let event: stripe::Event = serde_json::from_str(payload)?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "webhook-events")]
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    secrets: Vec<String>,
+    tolerance: Duration,
+    future_skew: Option<Duration>,
+    clock: Arc<dyn Fn() -> Timestamp + Send + Sync>,
+}
+
+#[cfg(feature = "webhook-events")]
+impl Default for WebhookVerifier {
+    fn default() -> Self {
+        WebhookVerifier {
+            secrets: Vec::new(),
+            tolerance: Duration::from_secs(300),
+            future_skew: None,
+            clock: Arc::new(|| Utc::now().timestamp()),
+        }
+    }
+}
+
+#[cfg(feature = "webhook-events")]
+impl WebhookVerifier {
+    /// Create a verifier for payloads signed with the given endpoint secret.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self::default().with_secret(secret)
+    }
+
+    /// Also accept payloads signed with the given secret.
+    ///
+    /// This allows an endpoint's signing secret to be rolled without downtime, by
+    /// accepting both the old and new secrets until the old one expires.
+    pub fn with_secret(mut self, secret: impl Into<String>) -> Self {
+        self.secrets.push(secret.into());
+        self
+    }
+
+    /// Reject payloads signed more than this long before or after the current time.
+    ///
+    /// The default is 5 minutes. Use [`WebhookVerifier::with_future_skew`] to
+    /// allow a different skew for payloads signed after the current time.
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Reject payloads signed more than this long after the current time, rather
+    /// than the tolerance.
+    ///
+    /// This allows for a receiver whose clock is behind stripe's.
+    pub fn with_future_skew(mut self, skew: Duration) -> Self {
+        self.future_skew = Some(skew);
+        self
+    }
+
+    /// Use the given function to get the current unix timestamp, rather than the system clock.
+    ///
+    /// This is helpful for replaying requests in tests.
+    pub fn with_clock(mut self, clock: impl Fn() -> Timestamp + Send + Sync + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Verify the signature of a payload, returning the payload if it is valid.
+    ///
+    /// # Errors
+    ///
+    /// This function will return a WebhookError if:
+    ///  - the provided signature is invalid for every secret
+    ///  - the provided secret is invalid
+    ///  - the signature timestamp is outside of the tolerance
+    pub fn verify<'a>(&self, payload: &'a str, sig: &str) -> Result<&'a str, WebhookError> {
         // Get Stripe signature from header
         let signature = Signature::parse(sig)?;
         let signed_payload = format!("{}.{}", signature.t, payload);
 
-        // Compute HMAC with the SHA256 hash function, using endpoing secret as key
-        // and signed_payload string as the message.
-        let mut mac =
-            Hmac::<Sha256>::new_from_slice(secret.as_bytes()).map_err(|_| WebhookError::BadKey)?;
-        mac.update(signed_payload.as_bytes());
+        // Stripe sends a `v1` signature for each active secret while one is being rolled,
+        // so the payload is valid if any of them matches any of our secrets.
+        let signatures =
+            signature.v1.iter().filter_map(|sig| hex::decode(sig).ok()).collect::<Vec<_>>();
 
-        let sig = hex::decode(signature.v1).map_err(|_| WebhookError::BadSignature)?;
+        let mut valid = false;
+        for secret in &self.secrets {
+            // Compute HMAC with the SHA256 hash function, using endpoing secret as key
+            // and signed_payload string as the message.
+            let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+                .map_err(|_| WebhookError::BadKey)?;
+            mac.update(signed_payload.as_bytes());
 
-        mac.verify_slice(sig.as_slice()).map_err(|_| WebhookError::BadSignature)?;
+            // N.B. Every signature is checked, without short-circuiting, and each
+            //      comparison is constant-time.
+            for sig in &signatures {
+                valid |= mac.clone().verify_slice(sig.as_slice()).is_ok();
+            }
+        }
+
+        if !valid {
+            return Err(WebhookError::BadSignature);
+        }
 
         // Get current timestamp to compare to signature timestamp
-        if (self.current_timestamp - signature.t).abs() > 300 {
+        let tolerance = i64::try_from(self.tolerance.as_secs()).unwrap_or(i64::MAX);
+        let future_skew = self
+            .future_skew
+            .map_or(tolerance, |skew| i64::try_from(skew.as_secs()).unwrap_or(i64::MAX));
+        let age = (self.clock)().saturating_sub(signature.t);
+        if age > tolerance || age < -future_skew {
             return Err(WebhookError::BadTimestamp(signature.t));
         }
 
-        Ok(serde_json::from_str(payload)?)
+        Ok(payload)
+    }
+
+    /// Verify the signature of a payload, and parse it as json without
+    /// deserializing it into an [`Event`].
+    ///
+    /// # Errors
+    ///
+    /// As with [`WebhookVerifier::verify`], or if the payload is not valid json.
+    pub fn verify_value(&self, payload: &str, sig: &str) -> Result<Value, WebhookError> {
+        Ok(serde_json::from_str(self.verify(payload, sig)?)?)
+    }
+
+    /// Verify the signature of a payload, and deserialize it into an [`Event`].
+    ///
+    /// # Errors
+    ///
+    /// As with [`WebhookVerifier::verify`], or if the payload is not a valid event.
+    pub fn construct_event(&self, payload: &str, sig: &str) -> Result<Event, WebhookError> {
+        Ok(serde_json::from_str(self.verify(payload, sig)?)?)
     }
 }
 
@@ -559,13 +931,13 @@ impl Webhook {
 #[derive(Debug)]
 struct Signature<'r> {
     t: i64,
-    v1: &'r str,
+    v1: Vec<&'r str>,
 }
 
 #[cfg(feature = "webhook-events")]
 impl<'r> Signature<'r> {
     fn parse(raw: &'r str) -> Result<Signature<'r>, WebhookError> {
-        let headers: HashMap<&str, &str> = raw
+        let headers: Vec<(&str, &str)> = raw
             .split(',')
             .map(|header| {
                 let mut key_and_value = header.split('=');
@@ -578,8 +950,15 @@ impl<'r> Signature<'r> {
                 _ => None,
             })
             .collect();
-        let t = headers.get("t").ok_or(WebhookError::BadSignature)?;
-        let v1 = headers.get("v1").ok_or(WebhookError::BadSignature)?;
+        let t = headers
+            .iter()
+            .find_map(|(key, value)| (*key == "t").then_some(*value))
+            .ok_or(WebhookError::BadSignature)?;
+        let v1: Vec<&str> =
+            headers.iter().filter_map(|(key, value)| (*key == "v1").then_some(*value)).collect();
+        if v1.is_empty() {
+            return Err(WebhookError::BadSignature);
+        }
         Ok(Signature { t: t.parse::<i64>().map_err(WebhookError::BadHeader)?, v1 })
     }
 }
@@ -597,7 +976,7 @@ mod tests {
         assert_eq!(signature.t, 1492774577);
         assert_eq!(
             signature.v1,
-            "5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"
+            vec!["5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"]
         );
 
         let raw_signature_with_test_mode = "t=1492774577,v1=5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd,v0=6ffbb59b2300aae63f272406069a9788598b792a944a07aba816edb039989a39";
@@ -605,14 +984,30 @@ mod tests {
         assert_eq!(signature.t, 1492774577);
         assert_eq!(
             signature.v1,
-            "5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"
+            vec!["5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd"]
         );
     }
 
     #[cfg(feature = "webhook-events")]
     #[test]
-    fn test_webhook_construct_event() {
-        let payload = r#"{
+    fn test_signature_parse_multiple() {
+        use super::Signature;
+
+        let raw_signature = "t=1492774577,v1=5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd,v1=6ffbb59b2300aae63f272406069a9788598b792a944a07aba816edb039989a39";
+        let signature = Signature::parse(raw_signature).unwrap();
+        assert_eq!(
+            signature.v1,
+            vec![
+                "5257a869e7ecebeda32affa62cdca3fa51cad7e77a0e56ff536d0ce8e108d8bd",
+                "6ffbb59b2300aae63f272406069a9788598b792a944a07aba816edb039989a39"
+            ]
+        );
+
+        assert!(Signature::parse("t=1492774577,v0=6ffbb59b").is_err());
+    }
+
+    #[cfg(feature = "webhook-events")]
+    const PAYLOAD: &str = r#"{
   "id": "evt_123",
   "object": "event",
   "account": "acct_123",
@@ -648,19 +1043,299 @@ mod tests {
   "type": "invoiceitem.created"
 }
 "#;
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_webhook_construct_event() {
+        let payload = PAYLOAD;
         let event_timestamp = 1533204620;
         let secret = "webhook_secret".to_string();
         let signature = format!("t={},v1=82216eca827bcb7b34b8055eb2d2d9e6bc13b9ac39ded14a61e69f70c565f53a,v0=63f3a72374a733066c4be69ed7f8e5ac85c22c9f0a6a612ab9a025a9e4ee7eef", event_timestamp);
 
-        let webhook = super::Webhook { current_timestamp: event_timestamp };
+        let verifier = super::WebhookVerifier::new(secret).with_clock(move || event_timestamp);
 
-        let event = webhook
-            .do_construct_event(payload, &signature, &secret)
-            .expect("Failed to construct event");
+        let event =
+            verifier.construct_event(payload, &signature).expect("Failed to construct event");
 
         assert_eq!(event.type_, super::EventType::InvoiceItemCreated);
         assert_eq!(event.id, "evt_123".parse::<crate::EventId>().unwrap());
         assert_eq!(event.account, "acct_123".parse().ok());
         assert_eq!(event.created, 1533204620);
     }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_webhook_rolling_secrets() {
+        use super::WebhookVerifier;
+        use crate::WebhookError;
+
+        let event_timestamp = 1533204620;
+        let verifier =
+            |secret: &str| WebhookVerifier::new(secret).with_clock(move || event_timestamp);
+        let valid = "82216eca827bcb7b34b8055eb2d2d9e6bc13b9ac39ded14a61e69f70c565f53a";
+        let other = "63f3a72374a733066c4be69ed7f8e5ac85c22c9f0a6a612ab9a025a9e4ee7eef";
+
+        // any of the v1 signatures may match
+        let signature = format!("t={},v1={},v1={}", event_timestamp, other, valid);
+        verifier("webhook_secret").construct_event(PAYLOAD, &signature).unwrap();
+
+        // as may any of the secrets
+        let signature = format!("t={},v1={}", event_timestamp, valid);
+        verifier("old_secret")
+            .with_secret("webhook_secret")
+            .construct_event(PAYLOAD, &signature)
+            .unwrap();
+
+        let signature = format!("t={},v1={},v1={}", event_timestamp, other, valid);
+        assert!(matches!(
+            verifier("old_secret").construct_event(PAYLOAD, &signature),
+            Err(WebhookError::BadSignature)
+        ));
+    }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_generate_test_header() {
+        use super::Webhook;
+
+        let signature = Webhook::generate_test_header(PAYLOAD, "webhook_secret", 1533204620);
+        assert_eq!(
+            signature.unwrap(),
+            "t=1533204620,v1=82216eca827bcb7b34b8055eb2d2d9e6bc13b9ac39ded14a61e69f70c565f53a"
+        );
+    }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_test_webhook() {
+        use std::collections::HashMap;
+
+        use super::{EventObject, EventType, TestWebhook, WebhookVerifier};
+        use crate::{Customer, WebhookError};
+
+        let customer = Customer { id: "cus_123".parse().unwrap(), ..Default::default() };
+        let webhook = TestWebhook::new(EventType::CustomerUpdated, EventObject::Customer(customer))
+            .with_id("evt_123".parse().unwrap())
+            .with_created(1533204620)
+            .with_previous_attributes(HashMap::from([("email".into(), "old@example.com".into())]));
+        let (payload, signature) = webhook.sign("webhook_secret").unwrap();
+
+        let verifier = WebhookVerifier::new("webhook_secret").with_clock(|| 1533204620);
+        let event = verifier.construct_event(&payload, &signature).unwrap();
+        assert_eq!(event.id.as_str(), "evt_123");
+        assert_eq!(event.type_, EventType::CustomerUpdated);
+        assert_eq!(event.created, 1533204620);
+        match event.data.object {
+            EventObject::Customer(customer) => assert_eq!(customer.id.as_str(), "cus_123"),
+            object => panic!("expected a customer, got {:?}", object),
+        }
+        assert_eq!(event.data.previous_attributes.unwrap()["email"], "old@example.com");
+
+        assert!(matches!(
+            WebhookVerifier::new("other_secret").construct_event(&payload, &signature),
+            Err(WebhookError::BadSignature)
+        ));
+    }
+
+    #[cfg(feature = "webhook-events")]
+    #[test]
+    fn test_webhook_verify() {
+        use std::time::Duration;
+
+        use super::WebhookVerifier;
+        use crate::WebhookError;
+
+        let event_timestamp = 1533204620;
+        let signature = format!(
+            "t={},v1=82216eca827bcb7b34b8055eb2d2d9e6bc13b9ac39ded14a61e69f70c565f53a",
+            event_timestamp
+        );
+        let verifier = |now: i64| WebhookVerifier::new("webhook_secret").with_clock(move || now);
+
+        // the raw payload is returned as is
+        assert_eq!(verifier(event_timestamp).verify(PAYLOAD, &signature).unwrap(), PAYLOAD);
+        let value = verifier(event_timestamp).verify_value(PAYLOAD, &signature).unwrap();
+        assert_eq!(value["type"], "invoiceitem.created");
+
+        // the default tolerance is 5 minutes
+        assert!(verifier(event_timestamp + 300).verify(PAYLOAD, &signature).is_ok());
+        assert!(matches!(
+            verifier(event_timestamp + 301).verify(PAYLOAD, &signature),
+            Err(WebhookError::BadTimestamp(t)) if t == event_timestamp
+        ));
+
+        // payloads signed in the future are rejected too
+        assert!(verifier(event_timestamp - 300).verify(PAYLOAD, &signature).is_ok());
+        assert!(matches!(
+            verifier(event_timestamp - 301).verify(PAYLOAD, &signature),
+            Err(WebhookError::BadTimestamp(t)) if t == event_timestamp
+        ));
+
+        // unless the receiver allows for its clock being behind the sender's
+        let skewed = verifier(event_timestamp - 600).with_future_skew(Duration::from_secs(600));
+        assert!(skewed.verify(PAYLOAD, &signature).is_ok());
+        assert!(verifier(event_timestamp + 301)
+            .with_future_skew(Duration::from_secs(600))
+            .verify(PAYLOAD, &signature)
+            .is_err());
+
+        let verifier = verifier(event_timestamp + 600).with_tolerance(Duration::from_secs(600));
+        assert!(verifier.verify(PAYLOAD, &signature).is_ok());
+
+        // a modified payload fails, even if it is still valid json
+        let modified = PAYLOAD.replace("1000", "1");
+        assert!(matches!(verifier.verify(&modified, &signature), Err(WebhookError::BadSignature)));
+    }
+
+    #[test]
+    fn test_unknown_event_object() {
+        use super::EventObject;
+        use crate::Event;
+
+        let payload = r#"{
+  "id": "evt_123",
+  "object": "event",
+  "api_version": "2017-05-25",
+  "created": 1533204620,
+  "data": {
+    "object": {
+      "id": "xyz_123",
+      "object": "some_future.object",
+      "amount": 1000
+    }
+  },
+  "livemode": false,
+  "pending_webhooks": 1,
+  "type": "some_future.object.created"
+}"#;
+        let event: Event = serde_json::from_str(payload).unwrap();
+        let object = match &event.data.object {
+            EventObject::Unknown(object) => object,
+            object => panic!("expected an unknown object, got {:?}", object),
+        };
+        assert_eq!(object["object"], "some_future.object");
+        assert_eq!(object["amount"], 1000);
+
+        // the raw object is preserved when serialized again
+        let json = serde_json::to_value(&event.data.object).unwrap();
+        assert_eq!(&json, object);
+
+        let known: EventObject = serde_json::from_str(
+            r#"{"object": "balance", "available": [], "livemode": false, "pending": []}"#,
+        )
+        .unwrap();
+        assert!(matches!(known, EventObject::Balance(_)));
+        assert_eq!(serde_json::to_value(&known).unwrap()["object"], "balance");
+    }
+
+    #[test]
+    fn test_malformed_event_object() {
+        use super::EventObject;
+        use crate::Event;
+
+        let payload = r#"{
+  "id": "evt_123",
+  "object": "event",
+  "api_version": "2017-05-25",
+  "created": 1533204620,
+  "data": {
+    "object": {
+      "id": "in_123",
+      "object": "invoice",
+      "amount_due": "not a number"
+    }
+  },
+  "livemode": false,
+  "pending_webhooks": 1,
+  "type": "invoice.created"
+}"#;
+        // a known object which fails to deserialize is an error, rather than unknown
+        let error = serde_json::from_str::<Event>(payload).unwrap_err();
+        assert!(error.to_string().contains("invalid type"), "{}", error);
+
+        let error = serde_json::from_str::<EventObject>(r#"{"object": "balance"}"#).unwrap_err();
+        assert!(error.to_string().contains("available"), "{}", error);
+    }
+
+    #[test]
+    fn test_typed_event() {
+        use super::{EventObject, EventType, TypedEvent};
+        use crate::Event;
+
+        let payload = |type_: &str, object: &str| {
+            format!(
+                r#"{{
+  "id": "evt_123",
+  "object": "event",
+  "api_version": "2017-05-25",
+  "created": 1533204620,
+  "data": {{
+    "object": {},
+    "previous_attributes": {{ "status": "open" }}
+  }},
+  "livemode": false,
+  "pending_webhooks": 1,
+  "type": "{}"
+}}"#,
+                object, type_
+            )
+        };
+        let balance = r#"{"object": "balance", "available": [], "livemode": false, "pending": []}"#;
+
+        let event: Event = serde_json::from_str(&payload("balance.available", balance)).unwrap();
+        match event.into_typed() {
+            TypedEvent::BalanceAvailable(balance) => assert!(!balance.livemode),
+            event => panic!("expected a balance.available event, got {:?}", event),
+        }
+
+        let card = r#"{"id": "card_123", "object": "card"}"#;
+        let event: Event = serde_json::from_str(&payload("customer.source.updated", card)).unwrap();
+        let typed = event.into_typed();
+        assert_eq!(typed.event_type(), EventType::CustomerSourceUpdated);
+        match typed {
+            TypedEvent::CustomerSourceUpdated { object, previous_attributes } => {
+                assert!(matches!(object, EventObject::Card(_)));
+                assert_eq!(previous_attributes.unwrap()["status"], "open");
+            }
+            event => panic!("expected a customer.source.updated event, got {:?}", event),
+        }
+
+        // an object which doesn't match the type of the event is kept as is
+        let event: Event = serde_json::from_str(&payload("charge.succeeded", balance)).unwrap();
+        let typed = event.into_typed();
+        assert_eq!(typed.event_type(), EventType::ChargeSucceeded);
+        match typed {
+            TypedEvent::Unknown(event) => {
+                assert_eq!(event.id.as_str(), "evt_123");
+                assert!(matches!(event.data.object, EventObject::Balance(_)));
+                assert!(event.data.previous_attributes.is_some());
+            }
+            event => panic!("expected an unknown event, got {:?}", event),
+        }
+    }
+
+    #[test]
+    fn test_namespaced_event_object() {
+        use super::EventObject;
+
+        let object: EventObject = serde_json::from_str(
+            r#"{
+  "id": "balance.summary.1",
+  "object": "reporting.report_type",
+  "data_available_end": 1533204620,
+  "data_available_start": 1533204620,
+  "livemode": false,
+  "name": "Balance summary",
+  "updated": 1533204620,
+  "version": 1
+}"#,
+        )
+        .unwrap();
+        let report_type = match &object {
+            EventObject::ReportingReportType(report_type) => report_type,
+            object => panic!("expected a report type, got {:?}", object),
+        };
+        assert_eq!(report_type.id.as_str(), "balance.summary.1");
+        assert_eq!(serde_json::to_value(&object).unwrap()["object"], "reporting.report_type");
+    }
 }
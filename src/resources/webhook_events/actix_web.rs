@@ -0,0 +1,144 @@
+//! Verify webhooks in [actix-web](https://docs.rs/actix-web) handlers.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::{web, FromRequest, HttpRequest, HttpResponse, ResponseError};
+
+use crate::{Event, WebhookRejection, WebhookVerifier};
+
+/// An [`Event`] whose signature has been verified.
+///
+/// The [`WebhookVerifier`] is taken from the app data, either directly or wrapped
+/// in [`web::Data`], and the size of the body is limited by actix's [`web::PayloadConfig`].
+///
+/// ```no_run
+/// use actix_web::{post, web, App, HttpResponse};
+/// use stripe::{actix_web::StripeEvent, WebhookVerifier};
+///
+/// #[post("/stripe_webhooks")]
+/// async fn handle_webhook(StripeEvent(event): StripeEvent) -> HttpResponse {
+///     println!("received {:?}", event.type_);
+///     HttpResponse::Ok().finish()
+/// }
+///
+/// let app = App::new()
+///     .app_data(web::Data::new(WebhookVerifier::new("whsec_xxxxx")))
+///     .service(handle_webhook);
+/// ```
+#[derive(Debug)]
+pub struct StripeEvent(pub Event);
+
+impl FromRequest for StripeEvent {
+    type Error = WebhookRejection;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let verifier = req
+            .app_data::<web::Data<WebhookVerifier>>()
+            .map(|data| data.get_ref())
+            .or_else(|| req.app_data::<WebhookVerifier>())
+            .cloned();
+        let signature = req
+            .headers()
+            .get("stripe-signature")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let body = web::Bytes::from_request(req, payload);
+
+        Box::pin(async move {
+            let verifier = verifier.ok_or(WebhookRejection::MissingVerifier)?;
+            let signature = signature.ok_or(WebhookRejection::MissingSignature)?;
+
+            let body = body.await.map_err(|err| {
+                if err.as_response_error().status_code() == StatusCode::PAYLOAD_TOO_LARGE {
+                    WebhookRejection::PayloadTooLarge
+                } else {
+                    WebhookRejection::BadBody(err.to_string())
+                }
+            })?;
+            let payload = std::str::from_utf8(&body)
+                .map_err(|err| WebhookRejection::BadBody(err.to_string()))?;
+
+            Ok(StripeEvent(verifier.construct_event(payload, &signature)?))
+        })
+    }
+}
+
+impl ResponseError for WebhookRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(WebhookRejection::status_code(self))
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(ResponseError::status_code(self)).body(self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web, App, HttpResponse};
+
+    use super::StripeEvent;
+    use crate::resources::webhook_events::fixture::{
+        payload, signature, verifier, EVENT_TYPE, TIMESTAMP,
+    };
+
+    async fn handler(StripeEvent(event): StripeEvent) -> HttpResponse {
+        HttpResponse::Ok().body(event.type_.to_string())
+    }
+
+    #[actix_web::test]
+    async fn extracts_verified_event() {
+        let app = test::init_service(
+            App::new().app_data(web::Data::new(verifier())).route("/", web::post().to(handler)),
+        )
+        .await;
+
+        let payload = payload();
+        let request = test::TestRequest::post()
+            .uri("/")
+            .insert_header(("stripe-signature", signature(&payload, TIMESTAMP)))
+            .set_payload(payload)
+            .to_request();
+        let body = test::call_and_read_body(&app, request).await;
+        assert_eq!(body, EVENT_TYPE.to_string());
+    }
+
+    #[actix_web::test]
+    async fn rejects_bad_requests() {
+        let app = test::init_service(
+            App::new()
+                .app_data(verifier())
+                .app_data(web::PayloadConfig::new(1024))
+                .route("/", web::post().to(handler)),
+        )
+        .await;
+
+        let payload = payload();
+        let missing = test::TestRequest::post().uri("/").set_payload(payload.clone()).to_request();
+        assert_eq!(test::call_service(&app, missing).await.status(), StatusCode::BAD_REQUEST);
+
+        let invalid = test::TestRequest::post()
+            .uri("/")
+            .insert_header(("stripe-signature", signature(&payload, TIMESTAMP)))
+            .set_payload(payload.replace("123", "456"))
+            .to_request();
+        assert_eq!(test::call_service(&app, invalid).await.status(), StatusCode::BAD_REQUEST);
+
+        let large = " ".repeat(2048) + &payload;
+        let too_large = test::TestRequest::post()
+            .uri("/")
+            .insert_header(("stripe-signature", signature(&large, TIMESTAMP)))
+            .set_payload(large)
+            .to_request();
+        assert_eq!(
+            test::call_service(&app, too_large).await.status(),
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Verify webhooks in [axum](https://docs.rs/axum) handlers.
+
+use axum::async_trait;
+use axum::body::Bytes;
+use axum::extract::{FromRef, FromRequest, Request};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+use crate::{Event, WebhookRejection, WebhookVerifier};
+
+/// An [`Event`] whose signature has been verified.
+///
+/// The [`WebhookVerifier`] is taken from the router's state, and the size of
+/// the body is limited by axum's [`DefaultBodyLimit`](axum::extract::DefaultBodyLimit).
+///
+/// ```no_run
+/// use axum::{routing::post, Router};
+/// use stripe::{axum::StripeEvent, WebhookVerifier};
+///
+/// async fn handle_webhook(StripeEvent(event): StripeEvent) {
+///     println!("received {:?}", event.type_);
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/stripe_webhooks", post(handle_webhook))
+///     .with_state(WebhookVerifier::new("whsec_xxxxx"));
+/// ```
+#[derive(Debug)]
+pub struct StripeEvent(pub Event);
+
+#[async_trait]
+impl<S> FromRequest<S> for StripeEvent
+where
+    S: Send + Sync,
+    WebhookVerifier: FromRef<S>,
+{
+    type Rejection = WebhookRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let signature = req
+            .headers()
+            .get("stripe-signature")
+            .and_then(|value| value.to_str().ok())
+            .ok_or(WebhookRejection::MissingSignature)?
+            .to_owned();
+
+        let body = Bytes::from_request(req, state).await.map_err(|err| {
+            if err.status() == StatusCode::PAYLOAD_TOO_LARGE {
+                WebhookRejection::PayloadTooLarge
+            } else {
+                WebhookRejection::BadBody(err.body_text())
+            }
+        })?;
+        let payload =
+            std::str::from_utf8(&body).map_err(|err| WebhookRejection::BadBody(err.to_string()))?;
+
+        let verifier = WebhookVerifier::from_ref(state);
+        Ok(StripeEvent(verifier.construct_event(payload, &signature)?))
+    }
+}
+
+impl IntoResponse for WebhookRejection {
+    fn into_response(self) -> Response {
+        let status =
+            StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (status, self.to_string()).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::extract::DefaultBodyLimit;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    use super::StripeEvent;
+    use crate::resources::webhook_events::fixture::{
+        payload, signature, verifier, EVENT_TYPE, TIMESTAMP,
+    };
+
+    fn app() -> Router {
+        async fn handler(StripeEvent(event): StripeEvent) -> String {
+            event.type_.to_string()
+        }
+
+        Router::new()
+            .route("/", post(handler))
+            .layer(DefaultBodyLimit::max(1024))
+            .with_state(verifier())
+    }
+
+    fn request(signature: Option<String>, body: String) -> Request<Body> {
+        let mut request = Request::post("/");
+        if let Some(signature) = signature {
+            request = request.header("stripe-signature", signature);
+        }
+        request.body(Body::from(body)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn extracts_verified_event() {
+        let payload = payload();
+        let response = app().oneshot(request(Some(signature(&payload, TIMESTAMP)), payload)).await;
+        let response = response.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body, EVENT_TYPE.to_string());
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_requests() {
+        let payload = payload();
+        let missing = app().oneshot(request(None, payload.clone())).await.unwrap();
+        assert_eq!(missing.status(), StatusCode::BAD_REQUEST);
+
+        let invalid = app()
+            .oneshot(request(Some(signature(&payload, TIMESTAMP)), payload.replace("123", "456")))
+            .await
+            .unwrap();
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+
+        let large = " ".repeat(2048) + &payload;
+        let too_large =
+            app().oneshot(request(Some(signature(&large, TIMESTAMP)), large)).await.unwrap();
+        assert_eq!(too_large.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
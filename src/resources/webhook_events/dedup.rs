@@ -0,0 +1,252 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use crate::params::Timestamp;
+use crate::{Event, EventId, WebhookError, WebhookVerifier};
+
+/// Records which events have been processed, so that redeliveries can be skipped.
+///
+/// Stripe delivers each event at least once, and retries them for up to three days.
+pub trait ProcessedEventStore {
+    /// The `created` timestamp of the event, if it has been processed.
+    fn get(&self, id: &EventId) -> Option<Timestamp>;
+
+    /// Record that the event has been processed, unless it already was.
+    ///
+    /// Returns whether the event was recorded. Checking and recording must be a
+    /// single atomic operation, so that concurrent deliveries of an event are
+    /// only claimed once.
+    fn insert_if_absent(&self, id: EventId, created: Timestamp) -> bool;
+
+    /// Forget the event, so that it is processed again when it is redelivered.
+    fn remove(&self, id: &EventId);
+}
+
+/// A [`ProcessedEventStore`] which keeps events in memory.
+///
+/// Events created more than the retention period before the latest processed
+/// event are forgotten. They are pruned in batches, at most once every tenth of
+/// the retention period, so an expired event may be remembered for a while longer.
+#[derive(Debug)]
+pub struct InMemoryEventStore {
+    events: Mutex<Events>,
+    retention: Duration,
+}
+
+#[derive(Debug)]
+struct Events {
+    processed: HashMap<EventId, Timestamp>,
+    pruned_at: Timestamp,
+}
+
+impl Default for InMemoryEventStore {
+    fn default() -> Self {
+        InMemoryEventStore {
+            events: Mutex::new(Events { processed: HashMap::new(), pruned_at: Timestamp::MIN }),
+            retention: Duration::from_secs(3 * 24 * 60 * 60),
+        }
+    }
+}
+
+impl InMemoryEventStore {
+    /// Create a store which remembers events for three days.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember events for this long.
+    pub fn with_retention(mut self, retention: Duration) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// The number of events that are remembered.
+    pub fn len(&self) -> usize {
+        self.events.lock().unwrap_or_else(PoisonError::into_inner).processed.len()
+    }
+
+    /// Whether no events are remembered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl ProcessedEventStore for InMemoryEventStore {
+    fn get(&self, id: &EventId) -> Option<Timestamp> {
+        self.events.lock().unwrap_or_else(PoisonError::into_inner).processed.get(id).copied()
+    }
+
+    fn insert_if_absent(&self, id: EventId, created: Timestamp) -> bool {
+        let mut events = self.events.lock().unwrap_or_else(PoisonError::into_inner);
+        let retention = i64::try_from(self.retention.as_secs()).unwrap_or(i64::MAX);
+        if created.saturating_sub(events.pruned_at) >= retention / 10 {
+            let cutoff = created.saturating_sub(retention);
+            events.processed.retain(|_, processed| *processed >= cutoff);
+            events.pruned_at = created;
+        }
+
+        match events.processed.entry(id) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(created);
+                true
+            }
+        }
+    }
+
+    fn remove(&self, id: &EventId) {
+        self.events.lock().unwrap_or_else(PoisonError::into_inner).processed.remove(id);
+    }
+}
+
+impl<T: ProcessedEventStore + ?Sized> ProcessedEventStore for std::sync::Arc<T> {
+    fn get(&self, id: &EventId) -> Option<Timestamp> {
+        (**self).get(id)
+    }
+
+    fn insert_if_absent(&self, id: EventId, created: Timestamp) -> bool {
+        (**self).insert_if_absent(id, created)
+    }
+
+    fn remove(&self, id: &EventId) {
+        (**self).remove(id)
+    }
+}
+
+/// Verifies webhooks, skipping events which have already been processed.
+///
+/// An event is recorded as it is returned, so that a concurrent redelivery of
+/// it is skipped. Pass an event whose handling fails to [`DedupVerifier::release`],
+/// so that it is processed again when stripe retries it.
+///
+/// Since events are recorded before they are handled, an event is lost if the
+/// process exits after it is returned but before it is handled or released:
+/// its redelivery is skipped, as it is already recorded. This only matters for a
+/// store which outlives the process, unlike the [`InMemoryEventStore`], and such a
+/// store should be paired with handlers which can recover unfinished work.
+///
+/// ```
+/// use stripe::{DedupVerifier, Event, InMemoryEventStore, WebhookVerifier};
+///
+/// # fn handle(event: &Event) -> Result<(), Box<dyn std::error::Error>> {
+/// #     println!("handling {} created at {}", event.id, event.created);
+/// #     Ok(())
+/// # }
+/// fn webhook(
+///     verifier: &DedupVerifier<InMemoryEventStore>,
+///     payload: &str,
+///     signature: &str,
+/// ) -> Result<(), Box<dyn std::error::Error>> {
+///     let Some(event) = verifier.construct_event(payload, signature)? else {
+///         // a redelivery of an event which has been handled
+///         return Ok(());
+///     };
+///     if let Err(err) = handle(&event) {
+///         // process the event again when stripe retries it
+///         verifier.release(&event);
+///         return Err(err);
+///     }
+///     Ok(())
+/// }
+///
+/// let verifier = DedupVerifier::new(WebhookVerifier::new("whsec_xxxxx"), InMemoryEventStore::new());
+/// ```
+pub struct DedupVerifier<S> {
+    verifier: WebhookVerifier,
+    store: S,
+}
+
+impl<S: ProcessedEventStore> DedupVerifier<S> {
+    /// Verify webhooks with the verifier, recording processed events in the store.
+    pub fn new(verifier: WebhookVerifier, store: S) -> Self {
+        DedupVerifier { verifier, store }
+    }
+
+    /// Verify the signature of a payload, and deserialize it into an [`Event`],
+    /// or `None` if the event has already been processed.
+    ///
+    /// The returned event is recorded as processed, and must be passed to
+    /// [`DedupVerifier::release`] if it cannot be handled.
+    ///
+    /// # Errors
+    ///
+    /// As with [`WebhookVerifier::construct_event`].
+    pub fn construct_event(&self, payload: &str, sig: &str) -> Result<Option<Event>, WebhookError> {
+        let event = self.verifier.construct_event(payload, sig)?;
+        Ok(self.store.insert_if_absent(event.id.clone(), event.created).then_some(event))
+    }
+
+    /// Forget an event whose handling failed, so that it is processed again when
+    /// stripe retries it.
+    pub fn release(&self, event: &Event) {
+        self.store.remove(&event.id);
+    }
+
+    /// The store of processed events.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DedupVerifier, InMemoryEventStore, ProcessedEventStore};
+    use crate::{EventObject, EventType, TestWebhook, WebhookVerifier};
+
+    fn webhook(id: &str, created: i64) -> TestWebhook {
+        TestWebhook::new(EventType::BalanceAvailable, EventObject::Balance(Default::default()))
+            .with_id(id.parse().unwrap())
+            .with_created(created)
+    }
+
+    #[test]
+    fn skips_processed_events() {
+        let verifier = DedupVerifier::new(
+            WebhookVerifier::new("webhook_secret").with_clock(|| 1533204620),
+            InMemoryEventStore::new(),
+        );
+        let (payload, signature) = webhook("evt_123", 1533204620).sign("webhook_secret").unwrap();
+
+        let event = verifier.construct_event(&payload, &signature).unwrap().unwrap();
+        assert_eq!(verifier.store().get(&event.id), Some(1533204620));
+        assert!(verifier.construct_event(&payload, &signature).unwrap().is_none());
+
+        // a released event is processed again
+        verifier.release(&event);
+        assert!(verifier.construct_event(&payload, &signature).unwrap().is_some());
+        assert!(verifier.construct_event(&payload, &signature).unwrap().is_none());
+    }
+
+    #[test]
+    fn claims_events_once() {
+        let store = InMemoryEventStore::new();
+        let claimed = std::thread::scope(|scope| {
+            let claims: Vec<_> = (0..8)
+                .map(|_| scope.spawn(|| store.insert_if_absent("evt_1".parse().unwrap(), 1000)))
+                .collect();
+            claims.into_iter().map(|claim| claim.join().unwrap()).filter(|&claimed| claimed).count()
+        });
+        assert_eq!(claimed, 1);
+    }
+
+    #[test]
+    fn forgets_old_events() {
+        let store = InMemoryEventStore::new().with_retention(Duration::from_secs(60));
+        assert!(store.insert_if_absent("evt_1".parse().unwrap(), 1000));
+        assert!(store.insert_if_absent("evt_2".parse().unwrap(), 1058));
+        assert_eq!(store.len(), 2);
+
+        // events are pruned at most once every 6 seconds
+        assert!(store.insert_if_absent("evt_3".parse().unwrap(), 1061));
+        assert_eq!(store.get(&"evt_1".parse().unwrap()), Some(1000));
+
+        assert!(store.insert_if_absent("evt_4".parse().unwrap(), 1064));
+        assert_eq!(store.get(&"evt_1".parse().unwrap()), None);
+        assert_eq!(store.get(&"evt_2".parse().unwrap()), Some(1058));
+        assert_eq!(store.len(), 3);
+    }
+}
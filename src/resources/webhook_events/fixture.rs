@@ -0,0 +1,32 @@
+//! A signed webhook shared by the tests of the framework integrations.
+
+use crate::params::Timestamp;
+use crate::{EventObject, EventType, TestWebhook, Webhook, WebhookVerifier};
+
+/// The secret the webhook is signed with.
+pub(crate) const SECRET: &str = "webhook_secret";
+
+/// The time the webhook is created and signed at.
+pub(crate) const TIMESTAMP: Timestamp = 1533204620;
+
+/// A verifier for [`SECRET`], whose clock reads [`TIMESTAMP`].
+pub(crate) fn verifier() -> WebhookVerifier {
+    WebhookVerifier::new(SECRET).with_clock(|| TIMESTAMP)
+}
+
+/// The type of the event in [`payload`].
+pub(crate) const EVENT_TYPE: EventType = EventType::BalanceAvailable;
+
+/// The payload of an event with the id `evt_123`, created at [`TIMESTAMP`].
+pub(crate) fn payload() -> String {
+    TestWebhook::new(EVENT_TYPE, EventObject::Balance(Default::default()))
+        .with_id("evt_123".parse().unwrap())
+        .with_created(TIMESTAMP)
+        .payload()
+        .unwrap()
+}
+
+/// A `Stripe-Signature` header for any payload, signed with [`SECRET`] at the given time.
+pub(crate) fn signature(payload: &str, timestamp: Timestamp) -> String {
+    Webhook::generate_test_header(payload, SECRET, timestamp).unwrap()
+}
@@ -0,0 +1,145 @@
+//! Verify webhooks in [rocket](https://docs.rs/rocket) handlers.
+
+use rocket::data::{self, Data, FromData, Limits};
+use rocket::http::Status;
+use rocket::outcome::Outcome;
+use rocket::Request;
+
+use crate::{Event, WebhookRejection, WebhookVerifier};
+
+/// An [`Event`] whose signature has been verified.
+///
+/// The [`WebhookVerifier`] is taken from the managed state, and the size of
+/// the body is limited by rocket's `bytes` [limit](rocket::data::Limits).
+///
+/// ```no_run
+/// use rocket::{post, routes};
+/// use stripe::{rocket::StripeEvent, WebhookVerifier};
+///
+/// #[post("/stripe_webhooks", data = "<event>")]
+/// fn handle_webhook(event: StripeEvent) {
+///     println!("received {:?}", event.0.type_);
+/// }
+///
+/// let rocket = rocket::build()
+///     .manage(WebhookVerifier::new("whsec_xxxxx"))
+///     .mount("/", routes![handle_webhook]);
+/// ```
+#[derive(Debug)]
+pub struct StripeEvent(pub Event);
+
+#[rocket::async_trait]
+impl<'r> FromData<'r> for StripeEvent {
+    type Error = WebhookRejection;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        match verify(req, data).await {
+            Ok(event) => Outcome::Success(StripeEvent(event)),
+            Err(rejection) => {
+                let status = Status::from_code(rejection.status_code())
+                    .unwrap_or(Status::InternalServerError);
+                Outcome::Error((status, rejection))
+            }
+        }
+    }
+}
+
+async fn verify(req: &Request<'_>, data: Data<'_>) -> Result<Event, WebhookRejection> {
+    let verifier =
+        req.rocket().state::<WebhookVerifier>().ok_or(WebhookRejection::MissingVerifier)?;
+    let signature =
+        req.headers().get_one("stripe-signature").ok_or(WebhookRejection::MissingSignature)?;
+
+    let limit = req.limits().get("bytes").unwrap_or(Limits::BYTES);
+    let payload = data
+        .open(limit)
+        .into_string()
+        .await
+        .map_err(|err| WebhookRejection::BadBody(err.to_string()))?;
+    if !payload.is_complete() {
+        return Err(WebhookRejection::PayloadTooLarge);
+    }
+
+    Ok(verifier.construct_event(&payload, signature)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use rocket::data::{Limits, ToByteUnit};
+    use rocket::http::{Header, Status};
+    use rocket::local::asynchronous::Client;
+    use rocket::{post, routes, Config};
+
+    use super::StripeEvent;
+    use crate::resources::webhook_events::fixture::{
+        payload, signature, verifier, EVENT_TYPE, TIMESTAMP,
+    };
+
+    #[post("/", data = "<event>")]
+    fn handler(event: StripeEvent) -> String {
+        event.0.type_.to_string()
+    }
+
+    async fn client() -> Client {
+        let config = Config {
+            limits: Limits::default().limit("bytes", 1024.bytes()),
+            ..Config::debug_default()
+        };
+        let rocket = rocket::custom(config).manage(verifier()).mount("/", routes![handler]);
+        Client::tracked(rocket).await.unwrap()
+    }
+
+    #[rocket::async_test]
+    async fn extracts_verified_event() {
+        let client = client().await;
+        let payload = payload();
+        let response = client
+            .post("/")
+            .header(Header::new("stripe-signature", signature(&payload, TIMESTAMP)))
+            .body(payload)
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().await.unwrap(), EVENT_TYPE.to_string());
+    }
+
+    #[rocket::async_test]
+    async fn rejects_bad_requests() {
+        let client = client().await;
+        let payload = payload();
+
+        let missing = client.post("/").body(payload.clone()).dispatch().await;
+        assert_eq!(missing.status(), Status::BadRequest);
+
+        let invalid = client
+            .post("/")
+            .header(Header::new("stripe-signature", signature(&payload, TIMESTAMP)))
+            .body(payload.replace("123", "456"))
+            .dispatch()
+            .await;
+        assert_eq!(invalid.status(), Status::BadRequest);
+
+        let large = " ".repeat(2048) + &payload;
+        let too_large = client
+            .post("/")
+            .header(Header::new("stripe-signature", signature(&large, TIMESTAMP)))
+            .body(large)
+            .dispatch()
+            .await;
+        assert_eq!(too_large.status(), Status::PayloadTooLarge);
+    }
+
+    #[rocket::async_test]
+    async fn requires_managed_verifier() {
+        let rocket = rocket::custom(Config::debug_default()).mount("/", routes![handler]);
+        let client = Client::tracked(rocket).await.unwrap();
+        let payload = payload();
+        let response = client
+            .post("/")
+            .header(Header::new("stripe-signature", signature(&payload, TIMESTAMP)))
+            .body(payload)
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::InternalServerError);
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::{Event, EventId, EventType};
+
+type Handler<E> =
+    Box<dyn Fn(Event) -> Pin<Box<dyn Future<Output = Result<(), E>> + Send>> + Send + Sync>;
+
+/// Dispatches events to async handlers registered for their [`EventType`].
+///
+/// Every handler registered for the type of an event is run, in the order they
+/// were registered. If there are none, the fallback is run instead.
+///
+/// ```
+/// # async fn run(event: stripe::Event) {
+/// use stripe::{EventObject, EventRouter, EventType};
+///
+/// let router: EventRouter = EventRouter::new()
+///     .on(EventType::CheckoutSessionCompleted, |event| async move {
+///         if let EventObject::CheckoutSession(session) = event.data.object {
+///             println!("checkout session {} completed", session.id);
+///         }
+///         Ok(())
+///     })
+///     .fallback(|event| async move {
+///         println!("unhandled event {:?}", event.type_);
+///         Ok(())
+///     });
+///
+/// let report = router.dispatch(event).await;
+/// for err in report.errors() {
+///     eprintln!("failed to handle {}: {}", report.event_id, err);
+/// }
+/// # }
+/// ```
+pub struct EventRouter<E = Box<dyn std::error::Error + Send + Sync>> {
+    handlers: HashMap<EventType, Vec<(usize, Handler<E>)>>,
+    registered: usize,
+    fallback: Option<Handler<E>>,
+}
+
+impl<E> Default for EventRouter<E> {
+    fn default() -> Self {
+        EventRouter { handlers: HashMap::new(), registered: 0, fallback: None }
+    }
+}
+
+impl<E> EventRouter<E> {
+    /// Create a router with no handlers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run the handler for events of the given type.
+    ///
+    /// Handlers are numbered from zero in the order they are registered, across
+    /// all event types, and are reported by that index in the [`EventRoute`].
+    pub fn on<F, Fut>(mut self, event_type: EventType, handler: F) -> Self
+    where
+        F: Fn(Event) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+    {
+        self.handlers.entry(event_type).or_default().push((self.registered, boxed(handler)));
+        self.registered += 1;
+        self
+    }
+
+    /// Run the handler for events of any type without handlers of their own.
+    pub fn fallback<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Event) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+    {
+        self.fallback = Some(boxed(handler));
+        self
+    }
+
+    /// Run the handlers for the event, such as one returned by
+    /// [`Webhook::construct_event`](crate::Webhook::construct_event).
+    ///
+    /// Handler errors do not stop the remaining handlers from running, and
+    /// are collected in the report.
+    pub async fn dispatch(&self, event: Event) -> DispatchReport<E> {
+        let mut report =
+            DispatchReport { event_id: event.id.clone(), event_type: event.type_, ran: Vec::new() };
+
+        match self.handlers.get(&event.type_) {
+            Some(handlers) => {
+                for (index, handler) in handlers {
+                    let result = handler(event.clone()).await;
+                    let route = EventRoute::Type { event_type: event.type_, index: *index };
+                    report.ran.push((route, result));
+                }
+            }
+            None => {
+                if let Some(fallback) = &self.fallback {
+                    report.ran.push((EventRoute::Fallback, fallback(event).await));
+                }
+            }
+        }
+
+        report
+    }
+}
+
+fn boxed<E, F, Fut>(handler: F) -> Handler<E>
+where
+    F: Fn(Event) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+{
+    Box::new(move |event| Box::pin(handler(event)))
+}
+
+/// The handler that an event was dispatched to.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EventRoute {
+    /// A handler registered for the event's type.
+    Type {
+        /// The type the handler was registered for.
+        event_type: EventType,
+        /// The index of the handler, in the order handlers were registered with [`EventRouter::on`].
+        index: usize,
+    },
+    /// The fallback handler.
+    Fallback,
+}
+
+/// The handlers that ran for an event, and their results.
+#[derive(Debug)]
+pub struct DispatchReport<E> {
+    /// The id of the dispatched event.
+    pub event_id: EventId,
+    /// The type of the dispatched event.
+    pub event_type: EventType,
+    /// The handlers that ran, in order.
+    pub ran: Vec<(EventRoute, Result<(), E>)>,
+}
+
+impl<E> DispatchReport<E> {
+    /// Whether any handler, including the fallback, ran for the event.
+    pub fn is_handled(&self) -> bool {
+        !self.ran.is_empty()
+    }
+
+    /// Whether every handler that ran succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.ran.iter().all(|(_, result)| result.is_ok())
+    }
+
+    /// The errors returned by handlers.
+    pub fn errors(&self) -> impl Iterator<Item = &E> {
+        self.ran.iter().filter_map(|(_, result)| result.as_ref().err())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::{EventRoute, EventRouter};
+    use crate::{Event, EventType};
+
+    fn event(event_type: EventType) -> Event {
+        Event { id: "evt_123".parse().unwrap(), type_: event_type, ..Default::default() }
+    }
+
+    #[tokio::test]
+    async fn dispatches_by_event_type() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let router = EventRouter::<&'static str>::new()
+            .on(EventType::ChargeFailed, |_| async { Ok(()) })
+            .on(EventType::ChargeSucceeded, move |_| {
+                let counter = counter.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                }
+            })
+            .on(EventType::ChargeSucceeded, |_| async { Err("boom") })
+            .fallback(|_| async { Ok(()) });
+
+        let report = router.dispatch(event(EventType::ChargeSucceeded)).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            report.ran,
+            vec![
+                (EventRoute::Type { event_type: EventType::ChargeSucceeded, index: 1 }, Ok(())),
+                (
+                    EventRoute::Type { event_type: EventType::ChargeSucceeded, index: 2 },
+                    Err("boom")
+                )
+            ]
+        );
+        assert!(!report.is_ok());
+        assert_eq!(report.errors().collect::<Vec<_>>(), vec![&"boom"]);
+
+        let report = router.dispatch(event(EventType::ChargeFailed)).await;
+        assert_eq!(
+            report.ran,
+            vec![(EventRoute::Type { event_type: EventType::ChargeFailed, index: 0 }, Ok(()))]
+        );
+
+        let report = router.dispatch(event(EventType::ChargeRefunded)).await;
+        assert_eq!(report.ran, vec![(EventRoute::Fallback, Ok(()))]);
+        assert!(report.is_ok());
+    }
+
+    #[tokio::test]
+    async fn reports_unhandled_events() {
+        let router = EventRouter::<&'static str>::new();
+        let report = router.dispatch(event(EventType::ChargeSucceeded)).await;
+        assert!(!report.is_handled());
+        assert_eq!(report.event_type, EventType::ChargeSucceeded);
+    }
+}
@@ -0,0 +1,203 @@
+//! Verify webhooks in [tower](https://docs.rs/tower) services.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use http::{Request, Response, StatusCode};
+use http_body::Body;
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use tower::{Layer, Service};
+
+use crate::{WebhookRejection, WebhookVerifier};
+
+/// A [`Layer`] that verifies the signature of webhook requests.
+///
+/// Verified requests are passed to the inner service with the raw payload as
+/// their body, and the [`Event`](crate::Event) in their extensions. Any other
+/// request is rejected with the status code of its [`WebhookRejection`], and an
+/// empty body.
+///
+/// ```no_run
+/// # async fn run() {
+/// use std::convert::Infallible;
+///
+/// use bytes::Bytes;
+/// use http::{Request, Response};
+/// use http_body_util::Full;
+/// use stripe::{tower::StripeWebhookLayer, Event, WebhookVerifier};
+/// use tower::{service_fn, ServiceBuilder};
+///
+/// let service = ServiceBuilder::new()
+///     .layer(StripeWebhookLayer::new(WebhookVerifier::new("whsec_xxxxx")))
+///     .service(service_fn(|req: Request<Full<Bytes>>| async move {
+///         let event = req.extensions().get::<Event>();
+///         Ok::<_, Infallible>(Response::new(Full::<Bytes>::default()))
+///     }));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct StripeWebhookLayer {
+    verifier: WebhookVerifier,
+    limit: usize,
+}
+
+impl StripeWebhookLayer {
+    /// Verify requests with the given verifier, accepting bodies of up to 2MB.
+    pub fn new(verifier: WebhookVerifier) -> Self {
+        StripeWebhookLayer { verifier, limit: 2 * 1024 * 1024 }
+    }
+
+    /// Reject bodies over this many bytes with a `413 Payload Too Large`.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+impl<S> Layer<S> for StripeWebhookLayer {
+    type Service = StripeWebhook<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        StripeWebhook { inner, verifier: self.verifier.clone(), limit: self.limit }
+    }
+}
+
+/// The [`Service`] created by a [`StripeWebhookLayer`].
+#[derive(Clone)]
+pub struct StripeWebhook<S> {
+    inner: S,
+    verifier: WebhookVerifier,
+    limit: usize,
+}
+
+impl<S, B, ResBody> Service<Request<B>> for StripeWebhook<S>
+where
+    S: Service<Request<Full<Bytes>>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send,
+    B: Body + Send + 'static,
+    B::Data: Send,
+    B::Error: std::error::Error + Send + Sync + 'static,
+    ResBody: Default,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        // N.B. The service that was polled ready is the one that must be called,
+        //      so keep it and leave the clone in its place.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let verifier = self.verifier.clone();
+        let limit = self.limit;
+
+        Box::pin(async move {
+            match verify(req, &verifier, limit).await {
+                Ok(req) => inner.call(req).await,
+                Err(rejection) => {
+                    let mut res = Response::new(ResBody::default());
+                    *res.status_mut() = StatusCode::from_u16(rejection.status_code())
+                        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+                    Ok(res)
+                }
+            }
+        })
+    }
+}
+
+async fn verify<B>(
+    req: Request<B>,
+    verifier: &WebhookVerifier,
+    limit: usize,
+) -> Result<Request<Full<Bytes>>, WebhookRejection>
+where
+    B: Body,
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    let (mut parts, body) = req.into_parts();
+    let signature = parts
+        .headers
+        .get("stripe-signature")
+        .and_then(|value| value.to_str().ok())
+        .ok_or(WebhookRejection::MissingSignature)?;
+
+    let body = Limited::new(body, limit).collect().await.map_err(|err| {
+        if err.is::<LengthLimitError>() {
+            WebhookRejection::PayloadTooLarge
+        } else {
+            WebhookRejection::BadBody(err.to_string())
+        }
+    })?;
+    let body = body.to_bytes();
+    let payload =
+        std::str::from_utf8(&body).map_err(|err| WebhookRejection::BadBody(err.to_string()))?;
+
+    let event = verifier.construct_event(payload, signature)?;
+    parts.extensions.insert(event);
+    Ok(Request::from_parts(parts, Full::new(body)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use bytes::Bytes;
+    use http::{Request, Response, StatusCode};
+    use http_body_util::{BodyExt, Full};
+    use tower::{service_fn, Layer, ServiceExt};
+
+    use super::StripeWebhookLayer;
+    use crate::resources::webhook_events::fixture::{
+        payload, signature, verifier, EVENT_TYPE, TIMESTAMP,
+    };
+    use crate::Event;
+
+    async fn call(signature: Option<String>, body: String) -> Response<Full<Bytes>> {
+        let layer = StripeWebhookLayer::new(verifier()).with_limit(1024);
+        let service = layer.layer(service_fn(|req: Request<Full<Bytes>>| async move {
+            let event = req.extensions().get::<Event>().unwrap();
+            assert_eq!(event.type_, EVENT_TYPE);
+            let body = req.into_body().collect().await.unwrap().to_bytes();
+            Ok::<_, Infallible>(Response::new(Full::new(body)))
+        }));
+
+        let mut request = Request::post("/");
+        if let Some(signature) = signature {
+            request = request.header("stripe-signature", signature);
+        }
+        service.oneshot(request.body(Full::new(Bytes::from(body))).unwrap()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn passes_verified_event() {
+        let payload = payload();
+        let response = call(Some(signature(&payload, TIMESTAMP)), payload.clone()).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(body, payload);
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_requests() {
+        let payload = payload();
+        let missing = call(None, payload.clone()).await;
+        assert_eq!(missing.status(), StatusCode::BAD_REQUEST);
+
+        let invalid =
+            call(Some(signature(&payload, TIMESTAMP)), payload.replace("123", "456")).await;
+        assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+
+        let stale = call(Some(signature(&payload, TIMESTAMP - 4620)), payload.clone()).await;
+        assert_eq!(stale.status(), StatusCode::BAD_REQUEST);
+
+        let large = " ".repeat(2048) + &payload;
+        let too_large = call(Some(signature(&large, TIMESTAMP)), large).await;
+        assert_eq!(too_large.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
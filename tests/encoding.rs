@@ -62,6 +62,25 @@ fn serialize_range_query() {
     assert_eq!(encoded, "created[gte]=1501598702&created[lt]=1504233902&limit=3");
 }
 
+#[test]
+#[cfg(feature = "treasury")]
+fn serialize_treasury_features() {
+    use stripe::{
+        CreateTreasuryFinancialAccountFeatures, TreasuryFeatureAccess, TreasuryOutboundFlowsFeature,
+    };
+
+    let mut params = CreateTreasuryFinancialAccountFeatures::new();
+    params.card_issuing = Some(TreasuryFeatureAccess { requested: true });
+    params.outbound_payments = Some(TreasuryOutboundFlowsFeature {
+        ach: Some(TreasuryFeatureAccess { requested: false }),
+        us_domestic_wire: None,
+    });
+    assert_eq!(
+        urldecode(serde_qs::to_string(&params).unwrap()),
+        "card_issuing[requested]=true&outbound_payments[ach][requested]=false"
+    );
+}
+
 fn urldecode(input: String) -> String {
     input.replace("%5B", "[").replace("%5D", "]")
 }
@@ -0,0 +1,79 @@
+mod mock;
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_invoice_updatable() {
+    mock::with_client(|client| {
+        let id = "in_123".parse().unwrap();
+        let mut params = stripe::UpdateInvoice::new();
+        params.description = Some("updated");
+        params.auto_advance = Some(false);
+        let result = stripe::Invoice::update(client, &id, params);
+        let invoice = match result {
+            Err(err) => panic!("{}", err),
+            Ok(ok) => ok,
+        };
+        assert_eq!(invoice.id, "in_123");
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_invoice_payable() {
+    mock::with_client(|client| {
+        let id = "in_123".parse().unwrap();
+        let mut params = stripe::PayInvoice::new();
+        params.paid_out_of_band = Some(true);
+        for result in [
+            stripe::Invoice::pay(client, &id),
+            stripe::Invoice::pay_with_params(client, &id, params),
+        ] {
+            let invoice = match result {
+                Err(err) => panic!("{}", err),
+                Ok(ok) => ok,
+            };
+            assert_eq!(invoice.id, "in_123");
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_invoice_transitionable() {
+    mock::with_client(|client| {
+        let id = "in_123".parse().unwrap();
+        for result in [
+            stripe::Invoice::send(client, &id),
+            stripe::Invoice::mark_uncollectible(client, &id),
+            stripe::Invoice::void(client, &id),
+        ] {
+            let invoice = match result {
+                Err(err) => panic!("{}", err),
+                Ok(ok) => ok,
+            };
+            assert_eq!(invoice.id, "in_123");
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_invoice_lines_listable() {
+    mock::with_client(|client| {
+        let id = "in_123".parse().unwrap();
+        let params = stripe::ListInvoiceLines { limit: Some(3), ..Default::default() };
+        let result = stripe::Invoice::list_lines(client, &id, &params);
+        if let Err(err) = result {
+            panic!("{}", err)
+        }
+
+        let params = stripe::RetrieveUpcomingInvoiceLines {
+            customer: Some("cus_123".parse().unwrap()),
+            ..Default::default()
+        };
+        let result = stripe::Invoice::upcoming_lines(client, &params);
+        if let Err(err) = result {
+            panic!("{}", err)
+        }
+    });
+}
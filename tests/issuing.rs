@@ -0,0 +1,107 @@
+mod mock;
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_cardholder_creatable() {
+    mock::with_client(|client| {
+        let billing = stripe::CreateIssuingCardholderBilling {
+            address: stripe::Address {
+                city: Some("San Francisco".to_string()),
+                country: Some("US".to_string()),
+                line1: Some("1234 Main Street".to_string()),
+                postal_code: Some("94111".to_string()),
+                state: Some("CA".to_string()),
+                ..Default::default()
+            },
+        };
+        let mut params = stripe::CreateIssuingCardholder::new("Jenny Rosen", billing);
+        params.type_ = Some(stripe::IssuingCardholderType::Individual);
+        let result = stripe::IssuingCardholder::create(client, params);
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_card_creatable() {
+    mock::with_client(|client| {
+        let mut params =
+            stripe::CreateIssuingCard::new(stripe::Currency::USD, stripe::IssuingCardType::Virtual);
+        params.cardholder = Some("ich_123".parse().unwrap());
+        let result = stripe::IssuingCard::create(client, params);
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_card_updatable() {
+    mock::with_client(|client| {
+        let id = "ic_123".parse().unwrap();
+        let mut params = stripe::UpdateIssuingCard::new();
+        params.status = Some(stripe::IssuingCardStatus::Inactive);
+        let result = stripe::IssuingCard::update(client, &id, params);
+        let card = match result {
+            Err(err) => panic!("{}", err),
+            Ok(ok) => ok,
+        };
+        assert_eq!(card.id, "ic_123");
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_authorization_approvable() {
+    mock::with_client(|client| {
+        let id = "iauth_123".parse().unwrap();
+        for result in [
+            stripe::IssuingAuthorization::approve(
+                client,
+                &id,
+                stripe::ApproveIssuingAuthorization::new(),
+            ),
+            stripe::IssuingAuthorization::decline(
+                client,
+                &id,
+                stripe::DeclineIssuingAuthorization::new(),
+            ),
+        ] {
+            let authorization = match result {
+                Err(err) => panic!("{}", err),
+                Ok(ok) => ok,
+            };
+            assert_eq!(authorization.id, "iauth_123");
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_dispute_submittable() {
+    mock::with_client(|client| {
+        let id = "idp_123".parse().unwrap();
+        let result =
+            stripe::IssuingDispute::submit(client, &id, stripe::SubmitIssuingDispute::new());
+        let dispute = match result {
+            Err(err) => panic!("{}", err),
+            Ok(ok) => ok,
+        };
+        assert_eq!(dispute.id, "idp_123");
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_token_listable() {
+    mock::with_client(|client| {
+        let params = stripe::ListIssuingTokens::new("ic_123".parse().unwrap());
+        let result = stripe::IssuingToken::list(client, &params);
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    });
+}
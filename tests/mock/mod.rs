@@ -1,12 +1,13 @@
 //! Setup and teardown for the stripe mock service.
 
 #[allow(dead_code)]
+#[cfg(feature = "blocking")]
 pub fn with_client<T>(test: T) -> ()
 where
-    T: FnOnce(&stripe::Client) -> () + std::panic::UnwindSafe,
+    T: FnOnce(&stripe::blocking::Client) -> () + std::panic::UnwindSafe,
 {
     let result = std::panic::catch_unwind(|| {
-        let client = stripe::Client::from_url("http://localhost:12111", "sk_test_123");
+        let client = stripe::blocking::Client::from_url("http://localhost:12111", "sk_test_123");
         test(&client)
     });
 
@@ -0,0 +1,51 @@
+mod mock;
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_quote_creatable() {
+    mock::with_client(|client| {
+        let mut params = stripe::CreateQuote::new();
+        params.customer = Some("cus_123".parse().unwrap());
+        params.line_items = Some(vec![stripe::QuoteLineItemParams {
+            price: Some("price_123".parse().unwrap()),
+            quantity: Some(2),
+            ..Default::default()
+        }]);
+        let result = stripe::Quote::create(client, params);
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_quote_transitionable() {
+    mock::with_client(|client| {
+        let id = "qt_123".parse().unwrap();
+        for result in [
+            stripe::Quote::finalize_quote(client, &id, stripe::FinalizeQuote::new()),
+            stripe::Quote::accept(client, &id),
+            stripe::Quote::cancel(client, &id),
+        ] {
+            let quote = match result {
+                Err(err) => panic!("{}", err),
+                Ok(ok) => ok,
+            };
+            assert_eq!(quote.id, "qt_123");
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_quote_line_items_listable() {
+    mock::with_client(|client| {
+        let id = "qt_123".parse().unwrap();
+        let params = stripe::ListQuoteLineItems { limit: Some(3), ..Default::default() };
+        let result = stripe::Quote::list_line_items(client, &id, &params);
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    });
+}
@@ -0,0 +1,117 @@
+mod mock;
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_test_clock_creatable() {
+    mock::with_client(|client| {
+        let params = stripe::CreateTestHelpersTestClock::new(1_700_000_000);
+        let result = stripe::TestHelpersTestClock::create(client, params);
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_test_clock_advanceable() {
+    mock::with_client(|client| {
+        let id = "clock_123".parse().unwrap();
+        let result = stripe::TestHelpersTestClock::advance(client, &id, 1_700_086_400);
+        let clock = match result {
+            Err(err) => panic!("{}", err),
+            Ok(ok) => ok,
+        };
+        assert_eq!(clock.id, "clock_123");
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_test_clock_deletable() {
+    mock::with_client(|client| {
+        let id = "clock_123".parse().unwrap();
+        let result = stripe::TestHelpersTestClock::delete(client, &id);
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    });
+}
+
+#[cfg(feature = "runtime-tokio-hyper")]
+mod advance_and_wait {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use http_types::{Request, Response, StatusCode};
+    use stripe::{AdvanceTestClockError, HttpTransport, TestHelpersTestClock, TransportFuture};
+
+    /// Replies to each request with a clock in the next of the given statuses.
+    struct Statuses(Mutex<Vec<&'static str>>);
+
+    impl HttpTransport for Statuses {
+        fn send(&self, _request: Request) -> TransportFuture {
+            let status = self.0.lock().unwrap().remove(0);
+            let mut response = Response::new(StatusCode::Ok);
+            response.set_body(format!(
+                r#"{{"id": "clock_123", "object": "test_helpers.test_clock", "status": "{}"}}"#,
+                status
+            ));
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    fn client(statuses: Vec<&'static str>) -> stripe::Client {
+        stripe::Client::new("sk_test_123").with_transport(Statuses(Mutex::new(statuses)))
+    }
+
+    #[tokio::test]
+    async fn polls_until_ready() {
+        let client = client(vec!["advancing", "advancing", "ready"]);
+        let id = "clock_123".parse().unwrap();
+        let clock = TestHelpersTestClock::advance_and_wait(
+            &client,
+            &id,
+            0,
+            Duration::from_millis(1),
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+        assert_eq!(clock.status, Some(stripe::TestHelpersTestClockStatus::Ready));
+    }
+
+    #[tokio::test]
+    async fn fails_on_internal_failure() {
+        let client = client(vec!["advancing", "internal_failure"]);
+        let id = "clock_123".parse().unwrap();
+        let result = TestHelpersTestClock::advance_and_wait(
+            &client,
+            &id,
+            0,
+            Duration::from_millis(1),
+            Duration::from_secs(10),
+        )
+        .await;
+        assert!(
+            matches!(result, Err(AdvanceTestClockError::Failed(clock)) if clock.id == "clock_123")
+        );
+    }
+
+    #[tokio::test]
+    async fn times_out_while_advancing() {
+        let client = client(vec!["advancing"; 100]);
+        let id = "clock_123".parse().unwrap();
+        let result = TestHelpersTestClock::advance_and_wait(
+            &client,
+            &id,
+            0,
+            Duration::from_millis(10),
+            Duration::from_millis(25),
+        )
+        .await;
+        assert!(
+            matches!(result, Err(AdvanceTestClockError::TimedOut(clock)) if clock.id == "clock_123")
+        );
+    }
+}
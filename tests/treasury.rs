@@ -0,0 +1,93 @@
+mod mock;
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_financial_account_creatable() {
+    mock::with_client(|client| {
+        let params = stripe::CreateTreasuryFinancialAccount::new(vec![stripe::Currency::USD]);
+        let result = stripe::TreasuryFinancialAccount::create(client, params);
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_financial_account_features_updatable() {
+    mock::with_client(|client| {
+        let id = "fa_123".parse().unwrap();
+        let mut params = stripe::CreateTreasuryFinancialAccountFeatures::new();
+        params.deposit_insurance = Some(stripe::TreasuryFeatureAccess { requested: true });
+        let result = stripe::TreasuryFinancialAccount::update_features(client, &id, params);
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_outbound_payment_cancelable() {
+    mock::with_client(|client| {
+        let id = "obp_123".parse().unwrap();
+        let result = stripe::TreasuryOutboundPayment::cancel(client, &id);
+        let payment = match result {
+            Err(err) => panic!("{}", err),
+            Ok(ok) => ok,
+        };
+        assert_eq!(payment.id, "obp_123");
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_outbound_transfer_transitionable() {
+    mock::with_client(|client| {
+        let id = "obt_123".parse().unwrap();
+        for result in [
+            stripe::TreasuryOutboundTransfer::test_helpers_post(client, &id, &[]),
+            stripe::TreasuryOutboundTransfer::test_helpers_fail(client, &id, &[]),
+            stripe::TreasuryOutboundTransfer::test_helpers_return(
+                client,
+                &id,
+                stripe::ReturnTreasuryOutboundTransfer::new(),
+            ),
+        ] {
+            let transfer = match result {
+                Err(err) => panic!("{}", err),
+                Ok(ok) => ok,
+            };
+            assert_eq!(transfer.id, "obt_123");
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_received_credit_creatable() {
+    mock::with_client(|client| {
+        let params = stripe::CreateTreasuryReceivedCredit::new(
+            1000,
+            stripe::Currency::USD,
+            "fa_123".parse().unwrap(),
+            stripe::TreasuryReceivedCreditNetwork::Ach,
+        );
+        let result = stripe::TreasuryReceivedCredit::test_helpers_create(client, params);
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn is_transaction_listable() {
+    mock::with_client(|client| {
+        let params = stripe::ListTreasuryTransactions::new("fa_123".parse().unwrap());
+        let result = stripe::TreasuryTransaction::list(client, &params);
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    });
+}